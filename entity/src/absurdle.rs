@@ -0,0 +1,106 @@
+//! The adversarial game without a fixed solution.
+
+use crate::{Matches, Pattern, PuzzleSolution, SubmitHistory, SubmitWord};
+
+use std::{cmp::Reverse, collections::BTreeMap};
+
+/// An adversarial game where, after each guess, the tint keeping the most candidate solutions is
+/// picked, until only one candidate is left and guessed.
+///
+/// The candidates are never stored: replaying the tinted guesses against the answers recomputes
+/// them deterministically.
+#[derive(Debug, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct Absurdle;
+
+impl Absurdle {
+    /// Returns the answers consistent with every tinted guess of the history, regardless of the
+    /// case of their letters.
+    pub fn candidates<const N: usize, const MAX: usize>(
+        answers: &[PuzzleSolution<N>],
+        history: &SubmitHistory<N, MAX>,
+    ) -> Vec<PuzzleSolution<N>> {
+        answers
+            .iter()
+            .filter(|answer| {
+                let answer = Self::uppercase(answer);
+                history.0.iter().all(|word| {
+                    let guess = PuzzleSolution(word.0.map(|letter| letter.letter));
+                    SubmitWord::tint(&guess, &answer).matches() == word.matches()
+                })
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Tints a guess against the candidates and returns the tint keeping the most of them. Ties
+    /// prefer fewer [`Matches::Yes`], then fewer [`Matches::Partially`], then the lowest
+    /// [`Pattern`]. Letters are compared regardless of their case. Returns [`None`] if there are
+    /// no candidates.
+    pub fn tint<const N: usize>(
+        guess: &PuzzleSolution<N>,
+        candidates: &[PuzzleSolution<N>],
+    ) -> Option<(SubmitWord<N>, usize)> {
+        let guess = Self::uppercase(guess);
+        let mut buckets: BTreeMap<Pattern, (SubmitWord<N>, usize)> = BTreeMap::new();
+        for candidate in candidates {
+            let word = SubmitWord::tint(&guess, &Self::uppercase(candidate));
+            buckets
+                .entry(Pattern::from_matches(&word.matches()))
+                .or_insert((word, 0))
+                .1 += 1;
+        }
+
+        buckets
+            .into_iter()
+            .max_by_key(|(pattern, (word, kept))| {
+                let count = |matches: Matches| {
+                    word.matches()
+                        .iter()
+                        .filter(|&&other| other == matches)
+                        .count()
+                };
+                (
+                    *kept,
+                    Reverse(count(Matches::Yes)),
+                    Reverse(count(Matches::Partially)),
+                    Reverse(*pattern),
+                )
+            })
+            .map(|(_, bucket)| bucket)
+    }
+
+    /// Uppercases a word, as the letters of a [`SubmitWord`] are.
+    fn uppercase<const N: usize>(word: &PuzzleSolution<N>) -> PuzzleSolution<N> {
+        PuzzleSolution(word.0.map(|letter| letter.to_ascii_uppercase()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Absurdle, GameState, PuzzleSolution, SubmitHistory};
+
+    #[test]
+    fn keeps_the_most_candidates() {
+        let answers: Vec<PuzzleSolution> = ["crane", "slate", "light", "might", "night"]
+            .into_iter()
+            .map(|word| PuzzleSolution::try_from(word).unwrap())
+            .collect();
+        let mut history: SubmitHistory<5, { usize::MAX }> = SubmitHistory::new();
+
+        for (guess, kept) in [("CRANE", 2), ("LIGHT", 1), ("MIGHT", 1)] {
+            let candidates = Absurdle::candidates(&answers, &history);
+            let guess = PuzzleSolution::try_from(guess).unwrap();
+            let (word, remaining) = Absurdle::tint(&guess, &candidates).unwrap();
+            assert_eq!(remaining, kept);
+            history.submit(word).unwrap();
+        }
+
+        // `CRANE` keeps `LIGHT` and `MIGHT`, then `LIGHT` is dodged in favour of `MIGHT`
+        assert_eq!(history.game_state(), GameState::Won { tries: 3 });
+        assert_eq!(
+            Absurdle::candidates(&answers, &history),
+            vec![PuzzleSolution::try_from("might").unwrap()]
+        );
+    }
+}
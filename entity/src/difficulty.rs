@@ -0,0 +1,100 @@
+//! The difficulty rating of puzzles.
+//!
+//! Difficulties are measured in tries, so a rating predicted from the solution can be blended with
+//! the mean tries observed from play data.
+
+use crate::{GameState, HISTORY_MAX_TRIES};
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// The tries added for a solution made of the rarest letters.
+const RARITY_WEIGHT: f64 = 1.0;
+/// The tries added per repeated letter.
+const REPEAT_WEIGHT: f64 = 0.25;
+/// The tries added per near-neighbour word.
+const NEIGHBOUR_WEIGHT: f64 = 0.1;
+/// The number of near-neighbour words beyond which the difficulty no longer grows.
+const MAX_NEIGHBOURS: usize = 10;
+/// The number of plays the predicted difficulty weighs as much as when blended.
+const PRIOR_PLAYS: f64 = 20.0;
+
+/// The factors predicting the difficulty of a solution.
+///
+/// See: [`Solver::difficulty`](crate::Solver::difficulty)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct DifficultyFactors {
+    /// How rare the letters of the solution are across the dictionary, from `0` when every word
+    /// contains them to `1` when no word does.
+    pub letter_rarity: f64,
+    /// The number of letters repeating an earlier letter of the solution.
+    pub repeated_letters: usize,
+    /// The number of words differing from the solution at exactly one index, such as the other
+    /// words of the `_IGHT` family.
+    pub neighbours: usize,
+    /// The number of guesses the solver takes to find the solution.
+    pub solver_guesses: usize,
+}
+
+impl DifficultyFactors {
+    /// Returns the predicted difficulty in tries.
+    pub fn score(&self) -> f64 {
+        let neighbours = self.neighbours.min(MAX_NEIGHBOURS) as f64;
+        NEIGHBOUR_WEIGHT.mul_add(
+            neighbours,
+            REPEAT_WEIGHT.mul_add(
+                self.repeated_letters as f64,
+                RARITY_WEIGHT.mul_add(self.letter_rarity, self.solver_guesses as f64),
+            ),
+        )
+    }
+}
+
+/// Returns the mean tries of the finished games together with their number, or [`None`] if no
+/// game has finished. A lost game counts as one try more than [`HISTORY_MAX_TRIES`].
+pub fn observed_difficulty<I>(states: I) -> Option<(f64, usize)>
+where
+    I: IntoIterator<Item = GameState>,
+{
+    let (total, plays) = states
+        .into_iter()
+        .filter_map(|state| match state {
+            GameState::InProgress => None,
+            GameState::Won { tries } => Some(tries),
+            GameState::Lost => Some(HISTORY_MAX_TRIES + 1),
+        })
+        .fold((0, 0), |(total, plays), tries| (total + tries, plays + 1));
+
+    (plays > 0).then(|| (total as f64 / plays as f64, plays))
+}
+
+/// Blends a predicted difficulty with the mean tries observed over a number of plays. The more
+/// plays, the more the observation outweighs the prediction.
+pub fn blend_difficulty(predicted: f64, observed: f64, plays: usize) -> f64 {
+    let weight = plays as f64 / (plays as f64 + PRIOR_PLAYS);
+    predicted.mul_add(1.0 - weight, observed * weight)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{GameState, blend_difficulty, observed_difficulty};
+
+    #[test]
+    fn blends_observed_plays() {
+        assert_eq!(observed_difficulty([GameState::InProgress]), None);
+
+        let (observed, plays) = observed_difficulty([
+            GameState::Won { tries: 3 },
+            GameState::Won { tries: 4 },
+            GameState::Lost,
+            GameState::InProgress,
+        ])
+        .unwrap();
+        assert_eq!(plays, 3);
+        assert!((observed - 14.0 / 3.0).abs() < 1e-9);
+
+        assert!((blend_difficulty(4.0, 6.0, 0) - 4.0).abs() < 1e-9);
+        assert!((blend_difficulty(4.0, 6.0, 20) - 5.0).abs() < 1e-9);
+        assert!(blend_difficulty(4.0, 6.0, 10_000) > 5.99);
+    }
+}
@@ -4,8 +4,12 @@
 
 use std::fmt::Display;
 
-use crate::{HISTORY_MAX_TRIES, PUZZLE_LETTERS_COUNT, PuzzleDate, PuzzleSolution, SubmitHistory};
+use crate::{
+    CheatFlags, GameState, HISTORY_MAX_TRIES, Hints, PUZZLE_LETTERS_COUNT, PuzzleDate,
+    PuzzleSolution, SubmitHistory, Timing,
+};
 
+use chrono::Utc;
 use sea_orm::entity::prelude::*;
 use serde::{Deserialize, Serialize};
 
@@ -24,8 +28,28 @@ pub struct Model {
     pub submit_history: Option<SubmitHistory>,
     /// The solution submitted.
     pub solution: PuzzleSolution,
-    /// Whether the puzzle has been completed.
+    /// Whether the puzzle has been solved. A lost game is over but not completed.
+    ///
+    /// See: [`GameState::is_won`]
     pub is_completed: bool,
+    /// The game state in JSON format, which is missing for histories uploaded before it was
+    /// tracked.
+    #[sea_orm(column_type = "JsonBinary", nullable)]
+    pub game_state: Option<GameState>,
+    /// The hints used in JSON format.
+    #[sea_orm(column_type = "JsonBinary", nullable)]
+    pub hints: Option<Hints>,
+    /// The server-side timing in JSON format, which is missing for histories started before it
+    /// was tracked.
+    #[sea_orm(column_type = "JsonBinary", nullable)]
+    pub timing: Option<Timing>,
+    /// The keyed hash of the IP address of the latest submission, which is missing for histories
+    /// submitted before it was tracked.
+    pub address: Option<String>,
+    /// The cheat flags in JSON format, which are missing unless the game is suspected of
+    /// cheating. Flagged games are excluded from the aggregate results.
+    #[sea_orm(column_type = "JsonBinary", nullable)]
+    pub flags: Option<CheatFlags>,
     /// The timestamp when this history was uploaded.
     pub uploaded_at: DateTime,
 }
@@ -36,6 +60,51 @@ impl Model {
         PUZZLE_LETTERS_COUNT
     }
 
+    /// Returns the game state now.
+    ///
+    /// See: [`Self::game_state_at`]
+    pub fn game_state(&self) -> GameState {
+        self.game_state_at(Utc::now())
+    }
+
+    /// Returns the game state at a given time, deriving it from the submit history and charging the
+    /// hints if it is not tracked. A game in progress past the deadline of its time attack is lost.
+    pub fn game_state_at(&self, at: chrono::DateTime<Utc>) -> GameState {
+        if self.is_overdue(at) {
+            GameState::Lost
+        } else {
+            self.tracked_game_state()
+        }
+    }
+
+    /// Returns whether the game is still tracked as in progress at a given time although it has
+    /// missed the deadline of its time attack, so it is lost without being recorded as such.
+    pub fn is_overdue(&self, at: chrono::DateTime<Utc>) -> bool {
+        self.timing
+            .as_ref()
+            .is_some_and(|timing| timing.is_late(at))
+            && !self.tracked_game_state().is_over()
+    }
+
+    fn tracked_game_state(&self) -> GameState {
+        self.game_state.unwrap_or_else(|| {
+            let state = self
+                .submit_history
+                .as_ref()
+                .map(SubmitHistory::game_state)
+                .unwrap_or_default();
+            self.hints
+                .clone()
+                .unwrap_or_default()
+                .charge(state, HISTORY_MAX_TRIES)
+        })
+    }
+
+    /// Returns whether the game is suspected of cheating.
+    pub fn is_flagged(&self) -> bool {
+        self.flags.as_ref().is_some_and(|flags| !flags.is_empty())
+    }
+
     /// Returns the number of remaining tries.
     pub fn remaining_tries(&self) -> usize {
         match &self.submit_history {
@@ -2,12 +2,35 @@
 
 pub mod prelude;
 
+pub mod absurdles;
+pub mod board_histories;
+pub mod board_puzzles;
+pub mod custom_histories;
+pub mod custom_puzzles;
+pub mod first_guess_counts;
+pub mod group_members;
+pub mod groups;
 pub mod histories;
+pub mod practices;
 pub mod puzzles;
+pub mod result_counts;
 pub mod sessions;
+pub mod versus_matches;
 
 /// The number of letters in the puzzle.
 pub const PUZZLE_LETTERS_COUNT: usize = 5;
 
 /// The maximum number of tries allowed for a puzzle.
 pub const HISTORY_MAX_TRIES: usize = 6;
+
+/// The maximum number of hints allowed for a puzzle.
+pub const HISTORY_MAX_HINTS: usize = 2;
+
+/// The number of tries each hint adds to the score of a won puzzle.
+pub const HINT_COST: usize = 1;
+
+/// The maximum number of tries allowed for an Absurdle game, which is unlimited.
+pub const ABSURDLE_MAX_TRIES: usize = usize::MAX;
+
+/// The maximum number of tries a custom puzzle can allow.
+pub const CUSTOM_MAX_TRIES: usize = 12;
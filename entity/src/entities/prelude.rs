@@ -1,5 +1,16 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.14
 
+pub use super::absurdles::Entity as Absurdles;
+pub use super::board_histories::Entity as BoardHistories;
+pub use super::board_puzzles::Entity as BoardPuzzles;
+pub use super::custom_histories::Entity as CustomHistories;
+pub use super::custom_puzzles::Entity as CustomPuzzles;
+pub use super::first_guess_counts::Entity as FirstGuessCounts;
+pub use super::group_members::Entity as GroupMembers;
+pub use super::groups::Entity as Groups;
 pub use super::histories::Entity as Histories;
+pub use super::practices::Entity as Practices;
 pub use super::puzzles::Entity as Puzzles;
+pub use super::result_counts::Entity as ResultCounts;
 pub use super::sessions::Entity as Sessions;
+pub use super::versus_matches::Entity as VersusMatches;
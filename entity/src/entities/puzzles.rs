@@ -8,9 +8,13 @@ use std::fmt::Display;
 
 use sea_orm::entity::prelude::*;
 use serde::{Deserialize, Serialize};
+use utoipa::{
+    PartialSchema, ToSchema,
+    openapi::{KnownFormat, ObjectBuilder, Ref, RefOr, Schema, SchemaFormat, Type},
+};
 
 /// The `puzzles` table model.
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, DeriveEntityModel)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, DeriveEntityModel)]
 #[sea_orm(table_name = "puzzles")]
 pub struct Model {
     /// The puzzle date.
@@ -18,6 +22,15 @@ pub struct Model {
     pub date: PuzzleDate,
     /// The puzzle solution.
     pub solution: PuzzleSolution,
+    /// The difficulty of the puzzle in tries, if rated.
+    ///
+    /// See: [`DifficultyFactors::score`](crate::DifficultyFactors::score)
+    #[sea_orm(column_type = "Double", nullable)]
+    pub difficulty: Option<f64>,
+    /// Whether the difficulty has been blended with the play data after the day ended.
+    ///
+    /// See: [`blend_difficulty`](crate::blend_difficulty)
+    pub is_difficulty_blended: bool,
 }
 
 impl Display for Model {
@@ -33,13 +46,29 @@ impl Model {
     }
 }
 
-/// A puzzle result containing the date and solution.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+/// A puzzle result containing the date, number, solution and difficulty.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ResultPuzzle<const N: usize = PUZZLE_LETTERS_COUNT> {
     /// The puzzle date.
     pub date: PuzzleDate,
+    /// The sequential puzzle number, if numbered.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub number: Option<u64>,
     /// The puzzle solution.
     pub solution: PuzzleSolution<N>,
+    /// The difficulty of the puzzle in tries, if rated.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub difficulty: Option<f64>,
+}
+
+impl<const N: usize> ResultPuzzle<N> {
+    /// Numbers the puzzle in days from an epoch.
+    ///
+    /// See: [`PuzzleDate::number_from`]
+    pub fn with_number_from(mut self, epoch: &PuzzleDate) -> Self {
+        self.number = self.date.number_from(epoch);
+        self
+    }
 }
 
 impl<const N: usize> Display for ResultPuzzle<N> {
@@ -48,18 +77,84 @@ impl<const N: usize> Display for ResultPuzzle<N> {
     }
 }
 
+impl<const N: usize> PartialSchema for ResultPuzzle<N> {
+    fn schema() -> RefOr<Schema> {
+        ObjectBuilder::new()
+            .description(Some(
+                "A puzzle result containing the date, number, solution and difficulty.",
+            ))
+            .property("date", Ref::from_schema_name(PuzzleDate::name()))
+            .property(
+                "number",
+                ObjectBuilder::new()
+                    .schema_type(Type::Integer)
+                    .format(Some(SchemaFormat::KnownFormat(KnownFormat::Int64)))
+                    .description(Some("The sequential puzzle number, if numbered.")),
+            )
+            .property(
+                "solution",
+                Ref::from_schema_name(PuzzleSolution::<N>::name()),
+            )
+            .property(
+                "difficulty",
+                ObjectBuilder::new()
+                    .schema_type(Type::Number)
+                    .format(Some(SchemaFormat::KnownFormat(KnownFormat::Double)))
+                    .description(Some("The difficulty of the puzzle in tries, if rated.")),
+            )
+            .required("date")
+            .required("solution")
+            .into()
+    }
+}
+
+impl<const N: usize> ToSchema for ResultPuzzle<N> {
+    fn schemas(schemas: &mut Vec<(String, RefOr<Schema>)>) {
+        schemas.push((PuzzleDate::name().into(), PuzzleDate::schema()));
+        schemas.push((
+            PuzzleSolution::<N>::name().into(),
+            PuzzleSolution::<N>::schema(),
+        ));
+        <PuzzleDate as ToSchema>::schemas(schemas);
+    }
+}
+
 impl From<Model> for ResultPuzzle<PUZZLE_LETTERS_COUNT> {
-    fn from(Model { date, solution, .. }: Model) -> Self {
-        Self { date, solution }
+    fn from(
+        Model {
+            date,
+            solution,
+            difficulty,
+            ..
+        }: Model,
+    ) -> Self {
+        Self {
+            date,
+            number: None,
+            solution,
+            difficulty,
+        }
     }
 }
 
 /// The relations of the `puzzles` table.
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    /// The relation to the `first_guess_counts` table.
+    #[sea_orm(has_many = "super::first_guess_counts::Entity")]
+    FirstGuessCounts,
     /// The relation to the `histories` table.
     #[sea_orm(has_many = "super::histories::Entity")]
     Histories,
+    /// The relation to the `result_counts` table.
+    #[sea_orm(has_many = "super::result_counts::Entity")]
+    ResultCounts,
+}
+
+impl Related<super::first_guess_counts::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::FirstGuessCounts.def()
+    }
 }
 
 impl Related<super::histories::Entity> for Entity {
@@ -68,6 +163,12 @@ impl Related<super::histories::Entity> for Entity {
     }
 }
 
+impl Related<super::result_counts::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ResultCounts.def()
+    }
+}
+
 impl Related<super::sessions::Entity> for Entity {
     fn to() -> RelationDef {
         super::histories::Relation::Sessions.def()
@@ -21,9 +21,66 @@ pub struct Model {
 /// The relations of the `sessions` table.
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    /// The relation to the `absurdles` table.
+    #[sea_orm(has_many = "super::absurdles::Entity")]
+    Absurdles,
+    /// The relation to the `board_histories` table.
+    #[sea_orm(has_many = "super::board_histories::Entity")]
+    BoardHistories,
+    /// The relation to the `custom_histories` table.
+    #[sea_orm(has_many = "super::custom_histories::Entity")]
+    CustomHistories,
+    /// The relation to the `custom_puzzles` table.
+    #[sea_orm(has_many = "super::custom_puzzles::Entity")]
+    CustomPuzzles,
+    /// The relation to the `group_members` table.
+    #[sea_orm(has_many = "super::group_members::Entity")]
+    GroupMembers,
+    /// The relation to the `groups` table.
+    #[sea_orm(has_many = "super::groups::Entity")]
+    Groups,
     /// The relation to the `histories` table.
     #[sea_orm(has_many = "super::histories::Entity")]
     Histories,
+    /// The relation to the `practices` table.
+    #[sea_orm(has_many = "super::practices::Entity")]
+    Practices,
+}
+
+impl Related<super::absurdles::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Absurdles.def()
+    }
+}
+
+impl Related<super::board_histories::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::BoardHistories.def()
+    }
+}
+
+impl Related<super::custom_histories::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::CustomHistories.def()
+    }
+}
+
+impl Related<super::custom_puzzles::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::CustomPuzzles.def()
+    }
+}
+
+impl Related<super::group_members::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::GroupMembers.def()
+    }
+}
+
+impl Related<super::groups::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Groups.def()
+    }
 }
 
 impl Related<super::histories::Entity> for Entity {
@@ -32,6 +89,12 @@ impl Related<super::histories::Entity> for Entity {
     }
 }
 
+impl Related<super::practices::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Practices.def()
+    }
+}
+
 impl Related<super::puzzles::Entity> for Entity {
     fn to() -> RelationDef {
         super::histories::Relation::Puzzles.def()
@@ -1,7 +1,13 @@
 //! The entity module.
 
+mod absurdle;
+mod difficulty;
 mod entities;
+mod solver;
 mod types;
 
+pub use absurdle::*;
+pub use difficulty::*;
 pub use entities::*;
+pub use solver::*;
 pub use types::*;
@@ -0,0 +1,474 @@
+//! The solver analyzing guesses against a dictionary.
+
+use crate::{
+    DifficultyFactors, Matches, PUZZLE_LETTERS_COUNT, PuzzleSolution, SubmitHistory, tint_letters,
+};
+
+use std::collections::BTreeSet;
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// The tint of a guess encoded as a base-3 number, with one digit per letter.
+///
+/// See: [`Pattern::from_matches`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Pattern(pub u32);
+
+impl Pattern {
+    /// Encodes the match statuses of a tinted word.
+    pub fn from_matches<const N: usize>(matches: &[Matches; N]) -> Self {
+        Self(matches.iter().fold(0, |pattern, matches| {
+            pattern * 3
+                + match matches {
+                    Matches::No => 0,
+                    Matches::Partially => 1,
+                    Matches::Yes => 2,
+                }
+        }))
+    }
+
+    /// The number of distinct patterns for words of `N` letters.
+    pub const fn count<const N: usize>() -> usize {
+        3usize.pow(N as u32)
+    }
+}
+
+/// The analysis of a submitted guess.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct GuessAnalysis {
+    /// The submitted guess.
+    pub guess: String,
+    /// The number of candidate solutions before the guess.
+    pub candidates_before: usize,
+    /// The number of candidate solutions after the guess.
+    pub candidates_after: usize,
+    /// The information gained by the guess in bits.
+    pub information: f64,
+    /// The expected information of the guess in bits, before its tint was known.
+    pub expected_information: f64,
+    /// The guess maximizing the expected information.
+    pub best_guess: String,
+    /// The expected information of the best guess in bits.
+    pub best_expected_information: f64,
+}
+
+/// The candidate solutions left after some guesses.
+#[derive(Debug, Clone)]
+struct Candidates<const N: usize> {
+    /// The sorted indices of the candidates in the dictionary.
+    indices: Vec<usize>,
+    /// The solution, if it is missing from the dictionary and has not been ruled out.
+    outsider: Option<[u8; N]>,
+}
+
+impl<const N: usize> Candidates<N> {
+    fn len(&self) -> usize {
+        self.indices.len() + usize::from(self.outsider.is_some())
+    }
+}
+
+/// A dictionary of words encoded for fast tinting and partitioning.
+///
+/// The [`Pattern`] of every guess against every word and the best opening guess are computed once
+/// on creation, which takes a byte for every pair of words.
+#[derive(Debug)]
+pub struct Solver<const N: usize = PUZZLE_LETTERS_COUNT> {
+    words: Vec<[u8; N]>,
+    /// The patterns of the guesses against the solutions, indexed by `guess * len + solution`.
+    patterns: Vec<u8>,
+    opening: Option<(usize, f64)>,
+}
+
+impl<const N: usize> Solver<N> {
+    const PATTERNS_FIT: () = assert!(
+        Pattern::count::<N>() <= u8::MAX as usize + 1,
+        "the patterns do not fit in a byte"
+    );
+
+    /// Creates a solver from the allowed guesses, precomputing the patterns between every pair of
+    /// words and the best opening guess. Words that are not made of exactly `N` ASCII letters are
+    /// skipped.
+    pub fn new<I, S>(words: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let () = Self::PATTERNS_FIT;
+
+        let mut words: Vec<[u8; N]> = words
+            .into_iter()
+            .filter_map(|word| Self::encode(word.as_ref()))
+            .collect();
+        words.sort_unstable();
+        words.dedup();
+
+        let patterns = words
+            .iter()
+            .flat_map(|guess| {
+                words
+                    .iter()
+                    .map(move |solution| Self::pattern(guess, solution).0 as u8)
+            })
+            .collect();
+
+        let mut solver = Self {
+            words,
+            patterns,
+            opening: None,
+        };
+        solver.opening = solver.best_guess(&solver.all());
+        solver
+    }
+
+    /// Returns the number of words in the dictionary.
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    /// Returns whether the dictionary is empty.
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    /// Returns the index of a word in the dictionary.
+    pub fn index_of(&self, word: &str) -> Option<usize> {
+        self.words.binary_search(&Self::encode(word)?).ok()
+    }
+
+    /// Returns the word at an index of the dictionary.
+    pub fn word(&self, index: usize) -> String {
+        String::from_utf8_lossy(&self.words[index]).into_owned()
+    }
+
+    /// Returns the [`Pattern`] of tinting a guess against a solution.
+    ///
+    /// See: [`tint_letters`]
+    pub fn pattern(guess: &[u8; N], solution: &[u8; N]) -> Pattern {
+        Pattern::from_matches(&tint_letters(guess, solution))
+    }
+
+    /// Returns the precomputed patterns of a guess against every word, if the guess is in the
+    /// dictionary.
+    fn row(&self, guess: &[u8; N]) -> Option<&[u8]> {
+        let index = self.words.binary_search(guess).ok()?;
+        Some(&self.patterns[index * self.len()..(index + 1) * self.len()])
+    }
+
+    /// Calls `f` with the [`Pattern`] a guess tints each candidate into, looking it up in the
+    /// precomputed patterns where possible.
+    fn for_each_pattern<F: FnMut(Pattern)>(
+        &self,
+        guess: &[u8; N],
+        candidates: &Candidates<N>,
+        mut f: F,
+    ) {
+        match self.row(guess) {
+            Some(row) => {
+                for &index in &candidates.indices {
+                    f(Pattern(u32::from(row[index])));
+                }
+            }
+            None => {
+                for &index in &candidates.indices {
+                    f(Self::pattern(guess, &self.words[index]));
+                }
+            }
+        }
+        if let Some(outsider) = &candidates.outsider {
+            f(Self::pattern(guess, outsider));
+        }
+    }
+
+    /// Partitions the candidates by the [`Pattern`] a guess tints them into, returning the size of
+    /// each partition indexed by pattern.
+    fn partition(&self, guess: &[u8; N], candidates: &Candidates<N>) -> Vec<u32> {
+        let mut sizes = vec![0; Pattern::count::<N>()];
+        self.for_each_pattern(guess, candidates, |pattern| sizes[pattern.0 as usize] += 1);
+        sizes
+    }
+
+    /// Returns the expected information of a guess over the candidates in bits, which is the
+    /// entropy of its partition.
+    fn expected_information(&self, guess: &[u8; N], candidates: &Candidates<N>) -> f64 {
+        let total = candidates.len() as f64;
+        self.partition(guess, candidates)
+            .into_iter()
+            .filter(|&size| size > 0)
+            .map(|size| {
+                let p = f64::from(size) / total;
+                -p * p.log2()
+            })
+            .sum()
+    }
+
+    /// Keeps the candidates a guess tints into a pattern.
+    fn retain(&self, guess: &[u8; N], pattern: Pattern, candidates: &mut Candidates<N>) {
+        match self.row(guess) {
+            Some(row) => candidates
+                .indices
+                .retain(|&index| u32::from(row[index]) == pattern.0),
+            None => candidates
+                .indices
+                .retain(|&index| Self::pattern(guess, &self.words[index]) == pattern),
+        }
+        candidates.outsider = candidates
+            .outsider
+            .filter(|outsider| Self::pattern(guess, outsider) == pattern);
+    }
+
+    /// Returns every word of the dictionary as a candidate.
+    fn all(&self) -> Candidates<N> {
+        Candidates {
+            indices: (0..self.len()).collect(),
+            outsider: None,
+        }
+    }
+
+    /// Returns every word of the dictionary as a candidate, together with the solution if it is
+    /// missing from the dictionary.
+    fn candidates(&self, solution: &[u8; N]) -> Candidates<N> {
+        Candidates {
+            outsider: self
+                .words
+                .binary_search(solution)
+                .is_err()
+                .then_some(*solution),
+            ..self.all()
+        }
+    }
+
+    /// Returns the index of the guess maximizing the expected information over the candidates,
+    /// together with its expected information. Ties prefer guesses that are candidates themselves.
+    fn best_guess(&self, candidates: &Candidates<N>) -> Option<(usize, f64)> {
+        match (&candidates.indices[..], &candidates.outsider) {
+            ([only], None) => return Some((*only, 0.0)),
+            ([], Some(_)) => return None,
+            _ => {}
+        }
+
+        self.words
+            .iter()
+            .enumerate()
+            .map(|(index, guess)| (index, self.expected_information(guess, candidates)))
+            .max_by(|(a, a_bits), (b, b_bits)| {
+                a_bits
+                    .total_cmp(b_bits)
+                    .then_with(|| {
+                        let is_candidate =
+                            |index: &usize| candidates.indices.binary_search(index).is_ok();
+                        is_candidate(a).cmp(&is_candidate(b))
+                    })
+                    .then_with(|| b.cmp(a))
+            })
+    }
+
+    /// Replays a submit history against the dictionary, analyzing each guess.
+    pub fn analyze<const MAX: usize>(
+        &self,
+        solution: &PuzzleSolution<N>,
+        history: &SubmitHistory<N, MAX>,
+    ) -> Vec<GuessAnalysis> {
+        let mut candidates = match Self::encode(&solution.to_string()) {
+            Some(solution) => self.candidates(&solution),
+            None => self.all(),
+        };
+
+        let mut analyses = Vec::with_capacity(history.len());
+        for (step, word) in history.0.iter().enumerate() {
+            let guess = word
+                .0
+                .map(|letter| letter.letter.to_ascii_uppercase() as u8);
+            let pattern = Pattern::from_matches(&word.matches());
+
+            let (best, best_bits) = if step == 0 {
+                self.opening
+            } else {
+                self.best_guess(&candidates)
+            }
+            .unwrap_or((0, 0.0));
+
+            let before = candidates.len();
+            let expected = self.expected_information(&guess, &candidates);
+            self.retain(&guess, pattern, &mut candidates);
+            let after = candidates.len();
+
+            analyses.push(GuessAnalysis {
+                guess: String::from_utf8_lossy(&guess).into_owned(),
+                candidates_before: before,
+                candidates_after: after,
+                information: if after == 0 {
+                    0.0
+                } else {
+                    (before as f64 / after as f64).log2()
+                },
+                expected_information: expected,
+                best_guess: if self.is_empty() {
+                    String::new()
+                } else {
+                    self.word(best)
+                },
+                best_expected_information: best_bits,
+            });
+        }
+        analyses
+    }
+
+    /// Rates how difficult a solution is to find.
+    ///
+    /// See: [`DifficultyFactors::score`]
+    pub fn difficulty(&self, solution: &PuzzleSolution<N>) -> DifficultyFactors {
+        let solution = solution.0.map(|letter| letter.to_ascii_uppercase() as u8);
+
+        let letters: BTreeSet<u8> = solution.iter().copied().collect();
+        let letter_rarity = if self.is_empty() {
+            1.0
+        } else {
+            let frequency: usize = letters
+                .iter()
+                .map(|letter| {
+                    self.words
+                        .iter()
+                        .filter(|word| word.contains(letter))
+                        .count()
+                })
+                .sum();
+            1.0 - frequency as f64 / (letters.len() * self.len()) as f64
+        };
+
+        let neighbours = self
+            .words
+            .iter()
+            .filter(|word| word.iter().zip(&solution).filter(|(a, b)| a != b).count() == 1)
+            .count();
+
+        DifficultyFactors {
+            letter_rarity,
+            repeated_letters: N - letters.len(),
+            neighbours,
+            solver_guesses: self.solve(&solution),
+        }
+    }
+
+    /// Returns the number of guesses taken to find a solution by always playing the best guess.
+    fn solve(&self, solution: &[u8; N]) -> usize {
+        let mut candidates = self.candidates(solution);
+
+        let mut guesses = 1;
+        loop {
+            let best = if guesses == 1 {
+                self.opening
+            } else {
+                self.best_guess(&candidates)
+            };
+            // the solution is the only candidate left but is missing from the dictionary
+            let Some((best, _)) = best else {
+                return guesses;
+            };
+
+            let guess = self.words[best];
+            if guess == *solution {
+                return guesses;
+            }
+            let pattern = Self::pattern(&guess, solution);
+            self.retain(&guess, pattern, &mut candidates);
+            guesses += 1;
+        }
+    }
+
+    fn encode(word: &str) -> Option<[u8; N]> {
+        let bytes: [u8; N] = word.as_bytes().try_into().ok()?;
+        bytes
+            .iter()
+            .all(u8::is_ascii_alphabetic)
+            .then(|| bytes.map(|c| c.to_ascii_uppercase()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Pattern, PuzzleSolution, Solver, SubmitHistory, SubmitWord};
+
+    const WORDS: [&str; 8] = [
+        "crane", "slate", "light", "might", "night", "right", "sight", "tight",
+    ];
+
+    #[test]
+    fn pattern_matches_tint() {
+        for guess in WORDS.iter().chain(&["eerie", "speed"]) {
+            for solution in WORDS.iter().chain(&["theme", "sheep"]) {
+                let tinted: SubmitWord = SubmitWord::tint(
+                    &PuzzleSolution::try_from(guess.to_ascii_uppercase().as_str()).unwrap(),
+                    &PuzzleSolution::try_from(solution.to_ascii_uppercase().as_str()).unwrap(),
+                );
+                let guess: [u8; 5] = guess.to_ascii_uppercase().as_bytes().try_into().unwrap();
+                let solution: [u8; 5] =
+                    solution.to_ascii_uppercase().as_bytes().try_into().unwrap();
+
+                assert_eq!(
+                    Solver::pattern(&guess, &solution),
+                    Pattern::from_matches(&tinted.matches())
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn difficulty() {
+        let solver: Solver = Solver::new(WORDS);
+
+        let tight = solver.difficulty(&PuzzleSolution::try_from("TIGHT").unwrap());
+        assert_eq!(tight.neighbours, 5);
+        assert_eq!(tight.repeated_letters, 1);
+
+        let crane = solver.difficulty(&PuzzleSolution::try_from("CRANE").unwrap());
+        assert_eq!(crane.neighbours, 0);
+        assert_eq!(crane.repeated_letters, 0);
+        assert!(crane.letter_rarity > tight.letter_rarity);
+
+        let eerie = solver.difficulty(&PuzzleSolution::try_from("EERIE").unwrap());
+        assert_eq!(eerie.repeated_letters, 2);
+        assert!(eerie.solver_guesses >= 2);
+    }
+
+    #[test]
+    fn analyze() {
+        let solver: Solver = Solver::new(WORDS);
+        let solution = PuzzleSolution::try_from("TIGHT").unwrap();
+        let mut history: SubmitHistory = SubmitHistory::new();
+        for guess in ["NIGHT", "TIGHT"] {
+            let guess = PuzzleSolution::try_from(guess).unwrap();
+            history.submit(SubmitWord::tint(&guess, &solution)).unwrap();
+        }
+
+        let analyses = solver.analyze(&solution, &history);
+        assert_eq!(analyses.len(), 2);
+
+        // `NIGHT` only rules out itself and the words without `IGHT`
+        assert_eq!(analyses[0].candidates_before, 8);
+        assert_eq!(analyses[0].candidates_after, 5);
+        assert!((analyses[0].information - (8.0f64 / 5.0).log2()).abs() < 1e-9);
+        assert!(analyses[0].best_expected_information >= analyses[0].expected_information);
+
+        assert_eq!(analyses[1].candidates_after, 1);
+        assert!(analyses[1].best_expected_information >= analyses[1].expected_information);
+    }
+
+    #[test]
+    fn analyze_outsider() {
+        let solver: Solver = Solver::new(WORDS);
+        let solution = PuzzleSolution::try_from("FIGHT").unwrap();
+        let mut history: SubmitHistory = SubmitHistory::new();
+        for guess in ["NIGHT", "FIGHT"] {
+            let guess = PuzzleSolution::try_from(guess).unwrap();
+            history.submit(SubmitWord::tint(&guess, &solution)).unwrap();
+        }
+
+        let analyses = solver.analyze(&solution, &history);
+
+        // the solution missing from the dictionary still counts as a candidate
+        assert_eq!(analyses[0].candidates_before, 9);
+        assert_eq!(analyses[0].candidates_after, 6);
+        assert_eq!(analyses[1].candidates_after, 1);
+    }
+}
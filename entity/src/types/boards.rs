@@ -0,0 +1,370 @@
+#![allow(clippy::exhaustive_enums)]
+
+use crate::{
+    GameState, Keyboard, Matches, PUZZLE_LETTERS_COUNT, PuzzleSolution, SubmitHistory, SubmitWord,
+};
+
+use std::fmt::Display;
+
+use sea_orm::{
+    ColumnType, DeriveActiveEnum, EnumIter, TryGetableFromJson, Value,
+    prelude::StringLen,
+    sea_query::{ArrayType, Nullable, ValueType, ValueTypeErr},
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// The multi-board variants, where every guess is tinted against several solutions at once.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Serialize,
+    Deserialize,
+    ToSchema,
+    EnumIter,
+    DeriveActiveEnum,
+)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::None)")]
+#[serde(rename_all = "snake_case")]
+pub enum BoardVariant {
+    /// Two boards in 7 tries.
+    #[sea_orm(string_value = "dordle")]
+    Dordle,
+    /// Four boards in 9 tries.
+    #[sea_orm(string_value = "quordle")]
+    Quordle,
+    /// Eight boards in 13 tries.
+    #[sea_orm(string_value = "octordle")]
+    Octordle,
+}
+
+impl BoardVariant {
+    /// Returns the number of boards.
+    pub fn boards(&self) -> usize {
+        match self {
+            Self::Dordle => 2,
+            Self::Quordle => 4,
+            Self::Octordle => 8,
+        }
+    }
+
+    /// Returns the maximum number of tries allowed for all boards.
+    pub fn max_tries(&self) -> usize {
+        match self {
+            Self::Dordle => 7,
+            Self::Quordle => 9,
+            Self::Octordle => 13,
+        }
+    }
+}
+
+impl Display for BoardVariant {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Dordle => write!(f, "Dordle"),
+            Self::Quordle => write!(f, "Quordle"),
+            Self::Octordle => write!(f, "Octordle"),
+        }
+    }
+}
+
+/// The solutions of a multi-board puzzle, one per board.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BoardSolutions<const N: usize = PUZZLE_LETTERS_COUNT>(pub Vec<PuzzleSolution<N>>);
+
+impl<const N: usize> From<BoardSolutions<N>> for Value {
+    fn from(value: BoardSolutions<N>) -> Self {
+        Self::Json(serde_json::to_value(&value).ok().map(Box::new))
+    }
+}
+
+impl<const N: usize> ValueType for BoardSolutions<N> {
+    fn try_from(v: Value) -> Result<Self, ValueTypeErr> {
+        match v {
+            Value::Json(Some(json)) => serde_json::from_value(*json).map_err(|_| ValueTypeErr),
+            _ => Err(ValueTypeErr),
+        }
+    }
+
+    fn type_name() -> String {
+        stringify!(BoardSolutions).to_owned()
+    }
+
+    fn array_type() -> ArrayType {
+        ArrayType::Json
+    }
+
+    fn column_type() -> ColumnType {
+        ColumnType::JsonBinary
+    }
+}
+
+impl<const N: usize> TryGetableFromJson for BoardSolutions<N> {}
+
+impl<const N: usize> Nullable for BoardSolutions<N> {
+    fn null() -> Value {
+        Value::Json(None)
+    }
+}
+
+/// The submit history of a multi-board puzzle. Each guess holds one [`SubmitWord`] per board, or
+/// [`None`] for the boards solved by an earlier guess.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BoardsHistory<const N: usize = PUZZLE_LETTERS_COUNT> {
+    /// The variant being played.
+    pub variant: BoardVariant,
+    /// The tinted words of each guess.
+    pub words: Vec<Vec<Option<SubmitWord<N>>>>,
+}
+
+impl<const N: usize> BoardsHistory<N> {
+    /// Creates a new empty [`BoardsHistory`].
+    pub fn new(variant: BoardVariant) -> Self {
+        Self {
+            variant,
+            words: Vec::new(),
+        }
+    }
+
+    /// Returns the number of guesses made.
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    /// Returns whether no guess has been made.
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    /// Returns the number of letters in the puzzle.
+    pub fn letters_count(&self) -> usize {
+        N
+    }
+
+    /// Returns the number of remaining tries.
+    pub fn remaining_tries(&self) -> usize {
+        self.variant.max_tries().saturating_sub(self.len())
+    }
+
+    /// Returns the number of guesses taken to solve a board, if solved.
+    pub fn solved_in(&self, board: usize) -> Option<usize> {
+        self.words
+            .iter()
+            .position(|guess| {
+                guess
+                    .get(board)
+                    .copied()
+                    .flatten()
+                    .is_some_and(|word| word.all_matches())
+            })
+            .map(|index| index + 1)
+    }
+
+    /// Returns the [`GameState`] reached by the guesses made. The puzzle is won once every board
+    /// is solved, and lost once the tries run out otherwise.
+    pub fn game_state(&self) -> GameState {
+        if (0..self.variant.boards()).all(|board| self.solved_in(board).is_some()) {
+            GameState::Won { tries: self.len() }
+        } else if self.remaining_tries() == 0 {
+            GameState::Lost
+        } else {
+            GameState::InProgress
+        }
+    }
+
+    /// Returns the words tinted on a board, up to the guess solving it.
+    pub fn board(&self, board: usize) -> Vec<SubmitWord<N>> {
+        self.words
+            .iter()
+            .filter_map(|guess| guess.get(board).copied().flatten())
+            .collect()
+    }
+
+    /// Returns the letter states known on a board.
+    ///
+    /// See: [`Keyboard::new`]
+    pub fn keyboard(&self, board: usize) -> Keyboard {
+        Keyboard::new(&SubmitHistory::<N, { usize::MAX }>(self.board(board)))
+    }
+
+    /// Tints a guess against every board not solved yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`BoardsHistoryError::GameOver`] error if the game is no longer in progress, or a
+    /// [`BoardsHistoryError::WrongSolutions`] error if the solutions do not match the boards.
+    pub fn submit(
+        &mut self,
+        answer: &PuzzleSolution<N>,
+        solutions: &[PuzzleSolution<N>],
+    ) -> Result<(), BoardsHistoryError> {
+        let state = self.game_state();
+        if state.is_over() {
+            return Err(BoardsHistoryError::GameOver(state));
+        }
+        if solutions.len() != self.variant.boards() {
+            return Err(BoardsHistoryError::WrongSolutions {
+                expected: self.variant.boards(),
+                found: solutions.len(),
+            });
+        }
+
+        let guess = solutions
+            .iter()
+            .enumerate()
+            .map(|(board, solution)| {
+                self.solved_in(board)
+                    .is_none()
+                    .then(|| SubmitWord::tint(answer, solution))
+            })
+            .collect();
+        self.words.push(guess);
+        Ok(())
+    }
+
+    /// Returns the emoji grid to share, with the boards side by side in pairs. Boards already
+    /// solved are padded with `⬛`.
+    pub fn share(&self) -> String {
+        let boards: Vec<usize> = (0..self.variant.boards()).collect();
+        boards
+            .chunks(2)
+            .map(|pair| {
+                let rows = pair
+                    .iter()
+                    .map(|&board| self.solved_in(board).unwrap_or(self.len()))
+                    .max()
+                    .unwrap_or_default();
+                (0..rows)
+                    .map(|row| {
+                        pair.iter()
+                            .map(
+                                |&board| match self.words[row].get(board).copied().flatten() {
+                                    Some(word) => {
+                                        word.matches().iter().map(Matches::emoji).collect()
+                                    }
+                                    None => "⬛".repeat(N),
+                                },
+                            )
+                            .collect::<Vec<_>>()
+                            .join(" ")
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+}
+
+impl<const N: usize> From<BoardsHistory<N>> for Value {
+    fn from(value: BoardsHistory<N>) -> Self {
+        Self::Json(serde_json::to_value(&value).ok().map(Box::new))
+    }
+}
+
+impl<const N: usize> ValueType for BoardsHistory<N> {
+    fn try_from(v: Value) -> Result<Self, ValueTypeErr> {
+        match v {
+            Value::Json(Some(json)) => serde_json::from_value(*json).map_err(|_| ValueTypeErr),
+            _ => Err(ValueTypeErr),
+        }
+    }
+
+    fn type_name() -> String {
+        stringify!(BoardsHistory).to_owned()
+    }
+
+    fn array_type() -> ArrayType {
+        ArrayType::Json
+    }
+
+    fn column_type() -> ColumnType {
+        ColumnType::JsonBinary
+    }
+}
+
+impl<const N: usize> TryGetableFromJson for BoardsHistory<N> {}
+
+impl<const N: usize> Nullable for BoardsHistory<N> {
+    fn null() -> Value {
+        Value::Json(None)
+    }
+}
+
+/// The errors that can occur when submitting a guess to a [`BoardsHistory`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum BoardsHistoryError {
+    /// The game is over and accepts no further guesses.
+    GameOver(GameState),
+    /// The number of solutions does not match the number of boards.
+    WrongSolutions {
+        /// The number of boards.
+        expected: usize,
+        /// The number of solutions given.
+        found: usize,
+    },
+}
+
+impl Display for BoardsHistoryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::GameOver(state) => write!(f, "the game is over: {state}"),
+            Self::WrongSolutions { expected, found } => {
+                write!(f, "expected {expected} solutions, found {found}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for BoardsHistoryError {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BoardVariant, BoardsHistory, GameState, PuzzleSolution};
+
+    fn solution(word: &str) -> PuzzleSolution {
+        PuzzleSolution::try_from(word).unwrap()
+    }
+
+    #[test]
+    fn solves_boards_independently() {
+        let solutions = [solution("LIGHT"), solution("CRANE")];
+        let mut history: BoardsHistory = BoardsHistory::new(BoardVariant::Dordle);
+
+        history.submit(&solution("CRANE"), &solutions).unwrap();
+        assert_eq!(history.solved_in(0), None);
+        assert_eq!(history.solved_in(1), Some(1));
+        assert_eq!(history.game_state(), GameState::InProgress);
+
+        history.submit(&solution("LIGHT"), &solutions).unwrap();
+        assert_eq!(history.words[1][1], None);
+        assert_eq!(history.board(0).len(), 2);
+        assert_eq!(history.board(1).len(), 1);
+        assert_eq!(history.game_state(), GameState::Won { tries: 2 });
+        assert!(history.submit(&solution("LIGHT"), &solutions).is_err());
+
+        assert_eq!(
+            history.share(),
+            "⬜⬜⬜⬜⬜ 🟩🟩🟩🟩🟩\n🟩🟩🟩🟩🟩 ⬛⬛⬛⬛⬛"
+        );
+    }
+
+    #[test]
+    fn loses_when_out_of_tries() {
+        let solutions = [solution("LIGHT"), solution("CRANE")];
+        let mut history: BoardsHistory = BoardsHistory::new(BoardVariant::Dordle);
+        for _ in 0..BoardVariant::Dordle.max_tries() {
+            history.submit(&solution("SLATE"), &solutions).unwrap();
+        }
+        assert_eq!(history.remaining_tries(), 0);
+        assert_eq!(history.game_state(), GameState::Lost);
+        assert!(history.submit(&solution("SLATE"), &solutions[..1]).is_err());
+    }
+}
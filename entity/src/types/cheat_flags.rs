@@ -0,0 +1,89 @@
+use std::fmt::Display;
+
+use sea_orm::{
+    ColumnType, TryGetableFromJson, Value,
+    sea_query::{ArrayType, Nullable, ValueType, ValueTypeErr},
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// A reason a finished game is suspected of cheating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+#[allow(clippy::exhaustive_enums)]
+pub enum CheatFlag {
+    /// The puzzle was solved too soon after the game started to have been played.
+    FastSolve,
+    /// The session solves puzzles on the first guess far more often than chance allows.
+    LuckyFirstGuesses,
+    /// Many sessions from the same address solved the puzzle quickly.
+    SharedAddress,
+}
+
+impl Display for CheatFlag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::FastSolve => write!(f, "fast solve"),
+            Self::LuckyFirstGuesses => write!(f, "lucky first guesses"),
+            Self::SharedAddress => write!(f, "shared address"),
+        }
+    }
+}
+
+/// The cheat flags raised on a game.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct CheatFlags(pub Vec<CheatFlag>);
+
+impl CheatFlags {
+    /// Returns whether no flags have been raised.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Raises a flag, unless already raised.
+    pub fn raise(&mut self, flag: CheatFlag) {
+        if !self.0.contains(&flag) {
+            self.0.push(flag);
+        }
+    }
+
+    /// Returns whether a flag has been raised.
+    pub fn contains(&self, flag: CheatFlag) -> bool {
+        self.0.contains(&flag)
+    }
+}
+
+impl From<CheatFlags> for Value {
+    fn from(value: CheatFlags) -> Self {
+        Self::Json(serde_json::to_value(&value).ok().map(Box::new))
+    }
+}
+
+impl ValueType for CheatFlags {
+    fn try_from(v: Value) -> Result<Self, ValueTypeErr> {
+        match v {
+            Value::Json(Some(json)) => serde_json::from_value(*json).map_err(|_| ValueTypeErr),
+            _ => Err(ValueTypeErr),
+        }
+    }
+
+    fn type_name() -> String {
+        stringify!(CheatFlags).to_owned()
+    }
+
+    fn array_type() -> ArrayType {
+        ArrayType::Json
+    }
+
+    fn column_type() -> ColumnType {
+        ColumnType::JsonBinary
+    }
+}
+
+impl TryGetableFromJson for CheatFlags {}
+
+impl Nullable for CheatFlags {
+    fn null() -> Value {
+        Value::Json(None)
+    }
+}
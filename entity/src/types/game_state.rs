@@ -0,0 +1,119 @@
+use std::fmt::Display;
+
+use sea_orm::{
+    ColumnType, TryGetableFromJson, Value,
+    sea_query::{ArrayType, Nullable, ValueType, ValueTypeErr},
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// The state of a played puzzle.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(tag = "status", rename_all = "snake_case")]
+#[allow(clippy::exhaustive_enums)]
+pub enum GameState {
+    /// The puzzle accepts further submissions.
+    #[default]
+    InProgress,
+    /// The puzzle has been solved.
+    Won {
+        /// The number of tries taken to solve the puzzle, including the cost of any hints used.
+        ///
+        /// See: [`Hints::charge`](crate::Hints::charge)
+        tries: usize,
+    },
+    /// The puzzle has run out of tries without being solved.
+    Lost,
+}
+
+impl GameState {
+    /// Whether the puzzle accepts further submissions.
+    pub fn is_in_progress(&self) -> bool {
+        matches!(self, Self::InProgress)
+    }
+
+    /// Whether the puzzle has been solved.
+    pub fn is_won(&self) -> bool {
+        matches!(self, Self::Won { .. })
+    }
+
+    /// Whether the puzzle has been either won or lost.
+    pub fn is_over(&self) -> bool {
+        !self.is_in_progress()
+    }
+
+    /// Whether the solution should be revealed to the player.
+    pub fn reveals_solution(&self) -> bool {
+        matches!(self, Self::Lost)
+    }
+}
+
+impl Display for GameState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InProgress => write!(f, "in progress"),
+            Self::Won { tries } => write!(f, "won in {tries}"),
+            Self::Lost => write!(f, "lost"),
+        }
+    }
+}
+
+impl From<GameState> for Value {
+    fn from(value: GameState) -> Self {
+        Self::Json(serde_json::to_value(value).ok().map(Box::new))
+    }
+}
+
+impl ValueType for GameState {
+    fn try_from(v: Value) -> Result<Self, ValueTypeErr> {
+        match v {
+            Value::Json(Some(json)) => serde_json::from_value(*json).map_err(|_| ValueTypeErr),
+            _ => Err(ValueTypeErr),
+        }
+    }
+
+    fn type_name() -> String {
+        stringify!(GameState).to_owned()
+    }
+
+    fn array_type() -> ArrayType {
+        ArrayType::Json
+    }
+
+    fn column_type() -> ColumnType {
+        ColumnType::JsonBinary
+    }
+}
+
+impl TryGetableFromJson for GameState {}
+
+impl Nullable for GameState {
+    fn null() -> Value {
+        Value::Json(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{GameState, Matches, SubmitHistory, SubmitLetter, SubmitWord};
+
+    fn word(matches: Matches) -> SubmitWord<1> {
+        SubmitWord::new([SubmitLetter::new('A', matches)])
+    }
+
+    #[test]
+    fn from_submit_history() {
+        let mut history = SubmitHistory::<1, 2>::new();
+        assert_eq!(history.game_state(), GameState::InProgress);
+
+        history.submit(word(Matches::Partially)).unwrap();
+        assert_eq!(history.game_state(), GameState::InProgress);
+
+        let mut won = history.clone();
+        won.submit(word(Matches::Yes)).unwrap();
+        assert_eq!(won.game_state(), GameState::Won { tries: 2 });
+
+        history.submit(word(Matches::No)).unwrap();
+        assert_eq!(history.game_state(), GameState::Lost);
+    }
+}
@@ -0,0 +1,185 @@
+use crate::{GameState, HINT_COST, HISTORY_MAX_HINTS};
+
+use std::fmt::Display;
+
+use sea_orm::{
+    ColumnType, TryGetableFromJson, Value,
+    sea_query::{ArrayType, Nullable, ValueType, ValueTypeErr},
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// A letter of the solution revealed at its index.
+///
+/// See: [`SubmitWord::hint`](crate::SubmitWord::hint)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct Hint {
+    /// The index of the revealed letter.
+    pub index: usize,
+    /// The revealed letter.
+    pub letter: char,
+}
+
+impl Display for Hint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}@{}", self.letter, self.index)
+    }
+}
+
+/// The hints used for a puzzle, where `MAX` is the maximum number of hints allowed.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Hints<const MAX: usize = HISTORY_MAX_HINTS>(pub Vec<Hint>);
+
+impl<const MAX: usize> Hints<MAX> {
+    /// Returns the number of hints used.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns whether no hints have been used.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the number of remaining hints. That is, `MAX - len()`.
+    pub fn remaining(&self) -> usize {
+        MAX.saturating_sub(self.len())
+    }
+
+    /// Returns whether the hints are exhausted. That is, whether `len() >= MAX`.
+    pub fn is_full(&self) -> bool {
+        self.len() >= MAX
+    }
+
+    /// Returns the number of tries the hints add to the score of a won game. That is,
+    /// `len() * HINT_COST`.
+    pub fn cost(&self) -> usize {
+        self.len() * HINT_COST
+    }
+
+    /// Charges the [`cost`](Self::cost) of the hints to a won game, adding it to the tries taken
+    /// without exceeding `max_tries`. Other states are returned unchanged.
+    pub fn charge(&self, state: GameState, max_tries: usize) -> GameState {
+        match state {
+            GameState::Won { tries } => GameState::Won {
+                tries: (tries + self.cost()).min(max_tries),
+            },
+            state => state,
+        }
+    }
+
+    /// Returns whether a letter has been revealed at the index.
+    pub fn reveals(&self, index: usize) -> bool {
+        self.0.iter().any(|hint| hint.index == index)
+    }
+
+    /// Records a hint.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`HintsError::TooManyHints`] error if the hints are already exhausted.
+    pub fn push(&mut self, hint: Hint) -> Result<(), HintsError> {
+        if self.is_full() {
+            Err(HintsError::TooManyHints { max: MAX })
+        } else {
+            self.0.push(hint);
+            Ok(())
+        }
+    }
+
+    /// Consumes the hints and returns the inner vector.
+    pub fn into_vec(self) -> Vec<Hint> {
+        self.0
+    }
+}
+
+impl<const MAX: usize> From<Hints<MAX>> for Value {
+    fn from(value: Hints<MAX>) -> Self {
+        Self::Json(serde_json::to_value(&value).ok().map(Box::new))
+    }
+}
+
+impl<const MAX: usize> ValueType for Hints<MAX> {
+    fn try_from(v: Value) -> Result<Self, ValueTypeErr> {
+        match v {
+            Value::Json(Some(json)) => serde_json::from_value(*json).map_err(|_| ValueTypeErr),
+            _ => Err(ValueTypeErr),
+        }
+    }
+
+    fn type_name() -> String {
+        stringify!(Hints).to_owned()
+    }
+
+    fn array_type() -> ArrayType {
+        ArrayType::Json
+    }
+
+    fn column_type() -> ColumnType {
+        ColumnType::JsonBinary
+    }
+}
+
+impl<const MAX: usize> TryGetableFromJson for Hints<MAX> {}
+
+impl<const MAX: usize> Nullable for Hints<MAX> {
+    fn null() -> Value {
+        Value::Json(None)
+    }
+}
+
+/// The errors that can occur when recording a [`Hint`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum HintsError {
+    /// The hints have reached the maximum number allowed.
+    TooManyHints {
+        /// The maximum number of hints allowed.
+        max: usize,
+    },
+}
+
+impl Display for HintsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TooManyHints { max } => {
+                write!(f, "used too many hints, exceeding the maximum of {max}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for HintsError {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{GameState, Hint, Hints};
+
+    #[test]
+    fn charge() {
+        let mut hints = Hints::<2>::default();
+        let won = GameState::Won { tries: 3 };
+        assert_eq!(hints.charge(won, 6), won);
+
+        hints
+            .push(Hint {
+                index: 0,
+                letter: 'A',
+            })
+            .unwrap();
+        hints
+            .push(Hint {
+                index: 1,
+                letter: 'B',
+            })
+            .unwrap();
+        assert_eq!(hints.cost(), 2);
+        assert_eq!(hints.charge(won, 6), GameState::Won { tries: 5 });
+        assert_eq!(hints.charge(won, 4), GameState::Won { tries: 4 });
+        assert_eq!(hints.charge(GameState::Lost, 6), GameState::Lost);
+        assert_eq!(
+            hints.charge(GameState::InProgress, 6),
+            GameState::InProgress
+        );
+    }
+}
@@ -0,0 +1,157 @@
+use crate::{Matches, PUZZLE_LETTERS_COUNT, SubmitHistory};
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// The letter states known from a [`SubmitHistory`], for clients to render the on-screen keyboard
+/// and to check hard-mode constraints.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct Keyboard {
+    /// The best known match of each submitted letter from `A` to `Z`, where [`Matches::Yes`]
+    /// beats [`Matches::Partially`] beats [`Matches::No`]. Letters never submitted are absent.
+    pub letters: BTreeMap<char, Matches>,
+    /// The letter known to be at each index, if any.
+    pub fixed: Vec<Option<char>>,
+    /// The letters known not to be at each index.
+    pub excluded: Vec<BTreeSet<char>>,
+    /// The known occurrence bounds of each submitted letter.
+    pub counts: BTreeMap<char, LetterCount>,
+}
+
+/// The known bounds of how many times a letter occurs in the solution.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct LetterCount {
+    /// The minimum number of occurrences.
+    pub min: usize,
+    /// The maximum number of occurrences, if known.
+    pub max: Option<usize>,
+}
+
+impl Keyboard {
+    /// Computes the letter states from a [`SubmitHistory`].
+    pub fn new<const N: usize, const MAX: usize>(history: &SubmitHistory<N, MAX>) -> Self {
+        let mut keyboard = Self {
+            letters: BTreeMap::new(),
+            fixed: vec![None; N],
+            excluded: vec![BTreeSet::new(); N],
+            counts: BTreeMap::new(),
+        };
+
+        for word in &history.0 {
+            // occurrences confirmed by this word, and whether the word overflows them
+            let mut found: BTreeMap<char, (usize, bool)> = BTreeMap::new();
+
+            for (index, letter) in word.0.iter().enumerate() {
+                let c = letter.letter.to_ascii_uppercase();
+                keyboard
+                    .letters
+                    .entry(c)
+                    .and_modify(|best| *best = best.better(letter.matches))
+                    .or_insert(letter.matches);
+
+                let (count, overflows) = found.entry(c).or_default();
+                match letter.matches {
+                    Matches::Yes => {
+                        keyboard.fixed[index] = Some(c);
+                        *count += 1;
+                    }
+                    Matches::Partially => {
+                        keyboard.excluded[index].insert(c);
+                        *count += 1;
+                    }
+                    Matches::No => {
+                        keyboard.excluded[index].insert(c);
+                        *overflows = true;
+                    }
+                }
+            }
+
+            for (c, (count, overflows)) in found {
+                let bounds = keyboard.counts.entry(c).or_default();
+                bounds.min = bounds.min.max(count);
+                if overflows {
+                    bounds.max = Some(bounds.max.map_or(count, |max| max.min(count)));
+                }
+            }
+        }
+
+        keyboard
+    }
+}
+
+impl Default for Keyboard {
+    fn default() -> Self {
+        Self::new(&SubmitHistory::<PUZZLE_LETTERS_COUNT>::new())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Keyboard, LetterCount, Matches, PuzzleSolution, SubmitHistory, SubmitWord};
+
+    use std::collections::BTreeSet;
+
+    fn submit(history: &mut SubmitHistory, answer: &str, solution: &str) {
+        let answer = PuzzleSolution::try_from(answer).unwrap();
+        let solution = PuzzleSolution::try_from(solution).unwrap();
+        history
+            .submit(SubmitWord::tint(&answer, &solution))
+            .unwrap();
+    }
+
+    #[test]
+    fn empty() {
+        let keyboard = Keyboard::default();
+        assert!(keyboard.letters.is_empty());
+        assert_eq!(keyboard.fixed, vec![None; 5]);
+        assert_eq!(keyboard.excluded, vec![BTreeSet::new(); 5]);
+        assert!(keyboard.counts.is_empty());
+    }
+
+    #[test]
+    fn best_matches() {
+        let mut history = SubmitHistory::new();
+        submit(&mut history, "TARES", "STEAM");
+        submit(&mut history, "STONE", "STEAM");
+        let keyboard = history.keyboard();
+
+        assert_eq!(keyboard.letters[&'S'], Matches::Yes);
+        assert_eq!(keyboard.letters[&'T'], Matches::Yes);
+        assert_eq!(keyboard.letters[&'E'], Matches::Partially);
+        assert_eq!(keyboard.letters[&'R'], Matches::No);
+        assert_eq!(keyboard.letters.get(&'M'), None);
+
+        assert_eq!(keyboard.fixed, vec![Some('S'), Some('T'), None, None, None]);
+        assert_eq!(keyboard.excluded[0], BTreeSet::from(['T']));
+        assert_eq!(keyboard.excluded[4], BTreeSet::from(['E', 'S']));
+    }
+
+    #[test]
+    fn repeated_letters() {
+        let mut history = SubmitHistory::new();
+        submit(&mut history, "EERIE", "THEME");
+        let keyboard = history.keyboard();
+
+        assert_eq!(
+            keyboard.counts[&'E'],
+            LetterCount {
+                min: 2,
+                max: Some(2)
+            }
+        );
+        assert_eq!(
+            keyboard.counts[&'R'],
+            LetterCount {
+                min: 0,
+                max: Some(0)
+            }
+        );
+
+        submit(&mut history, "THEME", "THEME");
+        let keyboard = history.keyboard();
+        assert_eq!(keyboard.counts[&'T'], LetterCount { min: 1, max: None });
+        assert_eq!(keyboard.letters[&'E'], Matches::Yes);
+    }
+}
@@ -1,9 +1,10 @@
 use std::fmt::Display;
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 /// The match result for each letter in a submitted word.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
 #[allow(clippy::exhaustive_enums)]
 pub enum Matches {
     /// The letter matches exactly.
@@ -26,6 +27,25 @@ impl Matches {
             Self::No => "-",
         }
     }
+
+    /// Returns the emoji tile shown for the match in share texts.
+    pub fn emoji(&self) -> char {
+        match self {
+            Self::Yes => '🟩',
+            Self::Partially => '🟨',
+            Self::No => '⬜',
+        }
+    }
+
+    /// Returns the better of two matches, where [`Matches::Yes`] beats [`Matches::Partially`]
+    /// beats [`Matches::No`].
+    pub fn better(self, other: Self) -> Self {
+        match (self, other) {
+            (Self::Yes, _) | (_, Self::Yes) => Self::Yes,
+            (Self::Partially, _) | (_, Self::Partially) => Self::Partially,
+            _ => Self::No,
+        }
+    }
 }
 
 impl Display for Matches {
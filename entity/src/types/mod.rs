@@ -1,13 +1,25 @@
+mod boards;
+mod cheat_flags;
+mod game_state;
+mod hint;
+mod keyboard;
 mod matches;
 mod puzzle_date;
 mod puzzle_solution;
 mod submit_history;
 mod submit_letter;
 mod submit_word;
+mod timing;
 
+pub use boards::*;
+pub use cheat_flags::*;
+pub use game_state::*;
+pub use hint::*;
+pub use keyboard::*;
 pub use matches::*;
 pub use puzzle_date::*;
 pub use puzzle_solution::*;
 pub use submit_history::*;
 pub use submit_letter::*;
 pub use submit_word::*;
+pub use timing::*;
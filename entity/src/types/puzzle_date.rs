@@ -1,10 +1,14 @@
 use std::fmt::{self, Display};
 
+use chrono::{DateTime, Days, NaiveTime, TimeDelta, TimeZone, Utc};
 use sea_orm::{DeriveValueType, TryFromU64, prelude::Date};
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 /// A valid puzzle date.
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, DeriveValueType)]
+#[derive(
+    Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, DeriveValueType, ToSchema,
+)]
 pub struct PuzzleDate(pub Date);
 
 impl PuzzleDate {
@@ -22,6 +26,34 @@ impl PuzzleDate {
     pub fn inner(&self) -> Date {
         self.0
     }
+
+    /// Returns the date of the puzzle at an instant in a timezone.
+    pub fn at<Tz: TimeZone>(instant: &DateTime<Utc>, tz: &Tz) -> Self {
+        Self(instant.with_timezone(tz).date_naive())
+    }
+
+    /// Returns the instant the puzzle of the next date starts in a timezone, which is the next
+    /// midnight unless skipped by a daylight saving transition, or [`None`] if out of range.
+    pub fn next_rollover<Tz: TimeZone>(&self, tz: &Tz) -> Option<DateTime<Utc>> {
+        let midnight = self.0.succ_opt()?.and_time(NaiveTime::MIN);
+        let start = tz.from_local_datetime(&midnight).earliest().or_else(|| {
+            tz.from_local_datetime(&(midnight + TimeDelta::hours(1)))
+                .earliest()
+        })?;
+        Some(start.with_timezone(&Utc))
+    }
+
+    /// Returns the sequential number of the puzzle counted in days from an epoch, which is
+    /// puzzle number 0, or [`None`] if the date is earlier than the epoch.
+    pub fn number_from(&self, epoch: &Self) -> Option<u64> {
+        u64::try_from((self.0 - epoch.0).num_days()).ok()
+    }
+
+    /// Returns the date of the puzzle with a sequential number counted in days from an epoch, or
+    /// [`None`] if out of range.
+    pub fn from_number(epoch: &Self, number: u64) -> Option<Self> {
+        epoch.0.checked_add_days(Days::new(number)).map(Self)
+    }
 }
 
 impl Display for PuzzleDate {
@@ -8,6 +8,10 @@ use sea_orm::{
     sea_query::{ArrayType, ValueType, ValueTypeErr},
 };
 use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Visitor};
+use utoipa::{
+    PartialSchema, ToSchema,
+    openapi::{ObjectBuilder, RefOr, Schema, Type},
+};
 
 /// A puzzle solution consisting of exactly `N` ASCII alphabetic letters.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
@@ -74,6 +78,22 @@ impl<'de, const N: usize> Deserialize<'de> for PuzzleSolution<N> {
     }
 }
 
+impl<const N: usize> PartialSchema for PuzzleSolution<N> {
+    fn schema() -> RefOr<Schema> {
+        ObjectBuilder::new()
+            .schema_type(Type::String)
+            .description(Some(format!(
+                "A word consisting of exactly {N} ASCII alphabetic letters."
+            )))
+            .min_length(Some(N))
+            .max_length(Some(N))
+            .pattern(Some(format!("^[A-Za-z]{{{N}}}$")))
+            .into()
+    }
+}
+
+impl<const N: usize> ToSchema for PuzzleSolution<N> {}
+
 impl<const N: usize> From<PuzzleSolution<N>> for Value {
     fn from(value: PuzzleSolution<N>) -> Self {
         Self::String(Some(Box::new(value.to_string())))
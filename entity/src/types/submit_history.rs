@@ -1,4 +1,4 @@
-use crate::{HISTORY_MAX_TRIES, PUZZLE_LETTERS_COUNT, SubmitWord};
+use crate::{GameState, HISTORY_MAX_TRIES, Keyboard, Matches, PUZZLE_LETTERS_COUNT, SubmitWord};
 
 use std::fmt::Display;
 
@@ -47,6 +47,35 @@ impl<const N: usize, const MAX: usize> SubmitHistory<N, MAX> {
         self.0.len() >= MAX
     }
 
+    /// Returns the [`GameState`] reached by the submissions made. The puzzle is won once a word
+    /// matches entirely, and lost once the history is full otherwise.
+    pub fn game_state(&self) -> GameState {
+        match self.0.last() {
+            Some(word) if word.all_matches() => GameState::Won { tries: self.len() },
+            _ if self.is_full() => GameState::Lost,
+            _ => GameState::InProgress,
+        }
+    }
+
+    /// Returns the [`GameState`] reached by the submissions made when fewer tries than `MAX` are
+    /// allowed, such as for custom puzzles.
+    ///
+    /// See: [`SubmitHistory::game_state`]
+    pub fn game_state_within(&self, max_tries: usize) -> GameState {
+        match self.0.last() {
+            Some(word) if word.all_matches() => GameState::Won { tries: self.len() },
+            _ if self.len() >= max_tries.min(MAX) => GameState::Lost,
+            _ => GameState::InProgress,
+        }
+    }
+
+    /// Returns the letter states known from the submissions made.
+    ///
+    /// See: [`Keyboard::new`]
+    pub fn keyboard(&self) -> Keyboard {
+        Keyboard::new(self)
+    }
+
     /// Submits a new word to the history.
     ///
     /// # Errors
@@ -61,6 +90,20 @@ impl<const N: usize, const MAX: usize> SubmitHistory<N, MAX> {
         }
     }
 
+    /// Returns the emoji grid to share, with a row per submitted word.
+    pub fn share(&self) -> String {
+        self.0
+            .iter()
+            .map(|word| {
+                word.matches()
+                    .iter()
+                    .map(Matches::emoji)
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
     /// Consumes the submit history and returns the inner vector of submitted words.
     pub fn into_vec(self) -> Vec<SubmitWord<N>> {
         self.0
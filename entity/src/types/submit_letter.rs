@@ -3,11 +3,12 @@ use crate::Matches;
 use std::fmt::Display;
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 /// A submitted letter with its match status.
 ///
 /// See: [`Matches`]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
 pub struct SubmitLetter {
     /// The submitted letter.
     pub letter: char,
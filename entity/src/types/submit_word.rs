@@ -1,12 +1,18 @@
-use crate::{Matches, PUZZLE_LETTERS_COUNT, PuzzleSolution, SubmitLetter};
+use crate::{
+    Hint, Hints, Matches, PUZZLE_LETTERS_COUNT, PuzzleSolution, SubmitHistory, SubmitLetter,
+};
 
-use std::{collections::HashMap, fmt::Display};
+use std::{cmp::Reverse, fmt::Display};
 
 use serde::{
     Deserialize, Deserializer, Serialize, Serializer,
     de::{SeqAccess, Visitor},
     ser::SerializeSeq as _,
 };
+use utoipa::{
+    PartialSchema, ToSchema,
+    openapi::{ArrayBuilder, Ref, RefOr, Schema},
+};
 
 /// A submitted word consisting of letters with match statuses.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -23,51 +29,45 @@ impl<const N: usize> SubmitWord<N> {
 
     /// Tints the answer against the solution to produce match statuses.
     ///
-    /// # Panics
-    ///
-    /// Panics if the lengths of `answer` and `solution` do not equal `N`, which should never happen.
+    /// See: [`tint_letters`]
     pub fn tint(answer: &PuzzleSolution<N>, solution: &PuzzleSolution<N>) -> Self {
-        // tint matched letters
-        let mut unparsed_map = solution.inner().iter().fold(HashMap::new(), |mut map, c| {
-            *map.entry(*c).or_insert(0) += 1;
-            map
-        });
-        let letters: Vec<(char, Option<Matches>)> = answer
-            .inner()
-            .iter()
-            .enumerate()
-            .map(|(index, &c)| {
-                (
-                    c,
-                    (solution.inner()[index] == c).then(|| {
-                        unparsed_map.entry(c).and_modify(|count| *count -= 1);
-                        Matches::Yes
-                    }),
-                )
-            })
-            .collect();
+        let matches = tint_letters(answer.inner(), solution.inner());
+        Self(std::array::from_fn(|index| {
+            SubmitLetter::new(answer.inner()[index], matches[index])
+        }))
+    }
+
+    /// Returns the match statuses of the letters.
+    pub fn matches(&self) -> [Matches; N] {
+        self.0.map(|letter| letter.matches)
+    }
 
-        // tint partially matched and unmatched letters
-        let letters: Vec<SubmitLetter> = letters
+    /// Picks the most useful letter of the solution to reveal, given the submitted words and the
+    /// hints used. Returns `None` if every index is already matched or revealed.
+    ///
+    /// Letters not found by any submission are preferred, then letters occurring more often in the
+    /// solution, then lower indices.
+    pub fn hint<const MAX: usize, const HINTS: usize>(
+        solution: &PuzzleSolution<N>,
+        history: &SubmitHistory<N, MAX>,
+        hints: &Hints<HINTS>,
+    ) -> Option<Hint> {
+        let keyboard = history.keyboard();
+        let letters = solution.inner().map(|c| c.to_ascii_uppercase());
+
+        letters
             .iter()
-            .map(|&(c, matches)| match matches {
-                Some(matches) => SubmitLetter::new(c, matches),
-                None => {
-                    if unparsed_map
-                        .get(&c)
-                        .map(|&count| count > 0)
-                        .unwrap_or(false)
-                    {
-                        unparsed_map.entry(c).and_modify(|count| *count -= 1);
-                        SubmitLetter::new(c, Matches::Partially)
-                    } else {
-                        SubmitLetter::new(c, Matches::No)
-                    }
-                }
+            .enumerate()
+            .filter(|&(index, _)| keyboard.fixed[index].is_none() && !hints.reveals(index))
+            .min_by_key(|&(index, c)| {
+                let found = matches!(
+                    keyboard.letters.get(c),
+                    Some(Matches::Yes | Matches::Partially)
+                );
+                let occurrences = letters.iter().filter(|&other| other == c).count();
+                (found, Reverse(occurrences), index)
             })
-            .collect();
-
-        Self(letters[..].try_into().unwrap())
+            .map(|(index, &letter)| Hint { index, letter })
     }
 
     /// Returns the length of the word.
@@ -85,6 +85,11 @@ impl<const N: usize> SubmitWord<N> {
         self.0.iter().all(|l| l.matches == Matches::Yes)
     }
 
+    /// Returns the letters of the word without their match statuses.
+    pub fn word(&self) -> String {
+        self.0.iter().map(|l| l.letter).collect()
+    }
+
     /// Returns a vector of references to the letters in the word.
     pub fn to_vec(&self) -> Vec<&SubmitLetter> {
         self.0.iter().collect()
@@ -96,12 +101,68 @@ impl<const N: usize> SubmitWord<N> {
     }
 }
 
+/// Tints the letters of an answer against a solution. Exact matches are taken first, then the
+/// remaining letters of the solution are matched from left to right.
+///
+/// See: [`SubmitWord::tint`]
+pub fn tint_letters<T: Copy + Eq, const N: usize>(
+    answer: &[T; N],
+    solution: &[T; N],
+) -> [Matches; N] {
+    let mut matches = [Matches::No; N];
+    let mut unmatched = [false; N];
+
+    // tint matched letters
+    for index in 0..N {
+        if answer[index] == solution[index] {
+            matches[index] = Matches::Yes;
+        } else {
+            unmatched[index] = true;
+        }
+    }
+
+    // tint partially matched letters
+    for index in 0..N {
+        if matches[index] == Matches::Yes {
+            continue;
+        }
+        if let Some(found) =
+            (0..N).find(|&other| unmatched[other] && solution[other] == answer[index])
+        {
+            unmatched[found] = false;
+            matches[index] = Matches::Partially;
+        }
+    }
+
+    matches
+}
+
 impl<const N: usize> Display for SubmitWord<N> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.0.map(|l| l.to_string()).join(Self::SEPARATOR))
     }
 }
 
+impl<const N: usize> PartialSchema for SubmitWord<N> {
+    fn schema() -> RefOr<Schema> {
+        ArrayBuilder::new()
+            .items(Ref::from_schema_name(SubmitLetter::name()))
+            .description(Some(format!(
+                "A submitted word consisting of exactly {N} tinted letters."
+            )))
+            .min_items(Some(N))
+            .max_items(Some(N))
+            .into()
+    }
+}
+
+impl<const N: usize> ToSchema for SubmitWord<N> {
+    fn schemas(schemas: &mut Vec<(String, RefOr<Schema>)>) {
+        schemas.push((SubmitLetter::name().into(), SubmitLetter::schema()));
+        <SubmitLetter as ToSchema>::schemas(schemas);
+    }
+}
+
 impl<const N: usize> Serialize for SubmitWord<N> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -157,7 +218,7 @@ impl<'de, const N: usize> Visitor<'de> for SubmitWordVisitor<N> {
 
 #[cfg(test)]
 mod tests {
-    use crate::{Matches, SubmitLetter, SubmitWord};
+    use crate::{Hint, Hints, Matches, PuzzleSolution, SubmitHistory, SubmitLetter, SubmitWord};
 
     use serde_test::{Token, assert_tokens};
 
@@ -206,4 +267,58 @@ mod tests {
             .concat(),
         );
     }
+
+    #[test]
+    fn hint() {
+        let solution = PuzzleSolution::try_from("SPEED").unwrap();
+        let mut history: SubmitHistory = SubmitHistory::new();
+        let mut hints: Hints = Hints::default();
+
+        // prefers the repeated letter
+        assert_eq!(
+            SubmitWord::hint(&solution, &history, &hints),
+            Some(Hint {
+                index: 2,
+                letter: 'E'
+            })
+        );
+
+        // prefers letters not found, skipping matched indices
+        let answer = PuzzleSolution::try_from("SHEEP").unwrap();
+        history
+            .submit(SubmitWord::tint(&answer, &solution))
+            .unwrap();
+        assert_eq!(
+            SubmitWord::hint(&solution, &history, &hints),
+            Some(Hint {
+                index: 4,
+                letter: 'D'
+            })
+        );
+
+        // skips revealed indices
+        hints
+            .push(Hint {
+                index: 4,
+                letter: 'D',
+            })
+            .unwrap();
+        assert_eq!(
+            SubmitWord::hint(&solution, &history, &hints),
+            Some(Hint {
+                index: 1,
+                letter: 'P'
+            })
+        );
+
+        // reveals nothing once every index is known
+        hints
+            .push(Hint {
+                index: 1,
+                letter: 'P',
+            })
+            .unwrap();
+        assert_eq!(SubmitWord::hint(&solution, &history, &hints), None);
+        assert!(hints.is_full());
+    }
 }
@@ -0,0 +1,115 @@
+use crate::GameState;
+
+use chrono::{DateTime, TimeDelta, Utc};
+use sea_orm::{
+    ColumnType, TryGetableFromJson, Value,
+    sea_query::{ArrayType, Nullable, ValueType, ValueTypeErr},
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// The server-side timing of a game, recorded when it starts and on every submission.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct Timing {
+    /// The timestamp when the game started.
+    pub started_at: DateTime<Utc>,
+    /// The timestamps of the submissions, one per submitted word.
+    pub submitted_at: Vec<DateTime<Utc>>,
+    /// The deadline of a time attack, after which submissions count as a loss.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deadline: Option<DateTime<Utc>>,
+    /// The time taken to finish the game in milliseconds, once it is over.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub completed_in_millis: Option<i64>,
+}
+
+impl Timing {
+    /// Starts timing a game, with a deadline if a time limit is given.
+    pub fn new(started_at: DateTime<Utc>, time_limit: Option<TimeDelta>) -> Self {
+        Self {
+            started_at,
+            submitted_at: Vec::new(),
+            deadline: time_limit.map(|limit| started_at + limit),
+            completed_in_millis: None,
+        }
+    }
+
+    /// Returns whether a submission at the given time misses the deadline.
+    pub fn is_late(&self, at: DateTime<Utc>) -> bool {
+        self.deadline.is_some_and(|deadline| at > deadline)
+    }
+
+    /// Records a submission and the [`GameState`] it reached, completing the timing once the game
+    /// is over.
+    pub fn submit(&mut self, at: DateTime<Utc>, state: GameState) {
+        self.submitted_at.push(at);
+        if state.is_over() {
+            self.complete(at);
+        }
+    }
+
+    /// Completes the timing at the given time, unless already completed.
+    pub fn complete(&mut self, at: DateTime<Utc>) {
+        self.completed_in_millis
+            .get_or_insert((at - self.started_at).num_milliseconds());
+    }
+}
+
+impl From<Timing> for Value {
+    fn from(value: Timing) -> Self {
+        Self::Json(serde_json::to_value(&value).ok().map(Box::new))
+    }
+}
+
+impl ValueType for Timing {
+    fn try_from(v: Value) -> Result<Self, ValueTypeErr> {
+        match v {
+            Value::Json(Some(json)) => serde_json::from_value(*json).map_err(|_| ValueTypeErr),
+            _ => Err(ValueTypeErr),
+        }
+    }
+
+    fn type_name() -> String {
+        stringify!(Timing).to_owned()
+    }
+
+    fn array_type() -> ArrayType {
+        ArrayType::Json
+    }
+
+    fn column_type() -> ColumnType {
+        ColumnType::JsonBinary
+    }
+}
+
+impl TryGetableFromJson for Timing {}
+
+impl Nullable for Timing {
+    fn null() -> Value {
+        Value::Json(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{GameState, Timing};
+
+    use chrono::{TimeDelta, Utc};
+
+    #[test]
+    fn completes_once_over() {
+        let start = Utc::now();
+        let mut timing = Timing::new(start, Some(TimeDelta::seconds(60)));
+
+        timing.submit(start + TimeDelta::seconds(10), GameState::InProgress);
+        assert_eq!(timing.completed_in_millis, None);
+        timing.submit(start + TimeDelta::seconds(20), GameState::Won { tries: 2 });
+        assert_eq!(timing.completed_in_millis, Some(20_000));
+        timing.complete(start + TimeDelta::seconds(30));
+        assert_eq!(timing.completed_in_millis, Some(20_000));
+
+        assert!(!timing.is_late(start + TimeDelta::seconds(60)));
+        assert!(timing.is_late(start + TimeDelta::seconds(61)));
+        assert!(!Timing::new(start, None).is_late(start + TimeDelta::days(1)));
+    }
+}
@@ -0,0 +1,19 @@
+//! Column definitions shared by the migrations.
+
+use sea_orm_migration::{prelude::*, schema::*, sea_orm::DatabaseBackend};
+
+/// A JSON column, stored as `JSONB` on PostgreSQL and as `JSON` text elsewhere.
+pub(crate) fn json_for<T: IntoIden>(manager: &SchemaManager, col: T) -> ColumnDef {
+    match manager.get_database_backend() {
+        DatabaseBackend::Postgres => json_binary(col),
+        _ => json(col),
+    }
+}
+
+/// A nullable JSON column, stored as `JSONB` on PostgreSQL and as `JSON` text elsewhere.
+pub(crate) fn json_null_for<T: IntoIden>(manager: &SchemaManager, col: T) -> ColumnDef {
+    match manager.get_database_backend() {
+        DatabaseBackend::Postgres => json_binary_null(col),
+        _ => json_null(col),
+    }
+}
@@ -1,12 +1,46 @@
 pub use sea_orm_migration::prelude::*;
 
+mod columns;
+
 mod m20220101_000001_create_table;
+mod m20261018_000001_add_game_state;
+mod m20261018_000002_add_hints;
+mod m20261018_000003_add_difficulty;
+mod m20261018_000004_create_practices;
+mod m20261018_000005_create_boards;
+mod m20261018_000006_create_absurdles;
+mod m20261018_000007_create_customs;
+mod m20261018_000008_add_timing;
+mod m20261018_000009_create_versus;
+mod m20261018_000010_create_results;
+mod m20261018_000011_create_groups;
+mod m20261018_000012_add_cheat_flags;
+mod m20261018_000013_reset_lost_completion;
+mod m20261018_000014_backfill_results;
+mod m20261018_000015_hash_addresses;
 
 pub struct Migrator;
 
 #[async_trait::async_trait]
 impl MigratorTrait for Migrator {
     fn migrations() -> Vec<Box<dyn MigrationTrait>> {
-        vec![Box::new(m20220101_000001_create_table::Migration)]
+        vec![
+            Box::new(m20220101_000001_create_table::Migration),
+            Box::new(m20261018_000001_add_game_state::Migration),
+            Box::new(m20261018_000002_add_hints::Migration),
+            Box::new(m20261018_000003_add_difficulty::Migration),
+            Box::new(m20261018_000004_create_practices::Migration),
+            Box::new(m20261018_000005_create_boards::Migration),
+            Box::new(m20261018_000006_create_absurdles::Migration),
+            Box::new(m20261018_000007_create_customs::Migration),
+            Box::new(m20261018_000008_add_timing::Migration),
+            Box::new(m20261018_000009_create_versus::Migration),
+            Box::new(m20261018_000010_create_results::Migration),
+            Box::new(m20261018_000011_create_groups::Migration),
+            Box::new(m20261018_000012_add_cheat_flags::Migration),
+            Box::new(m20261018_000013_reset_lost_completion::Migration),
+            Box::new(m20261018_000014_backfill_results::Migration),
+            Box::new(m20261018_000015_hash_addresses::Migration),
+        ]
     }
 }
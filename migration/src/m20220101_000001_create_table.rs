@@ -1,3 +1,5 @@
+use crate::columns::json_null_for;
+
 use sea_orm_migration::{prelude::*, schema::*};
 
 #[derive(DeriveMigrationName)]
@@ -39,7 +41,7 @@ impl MigrationTrait for Migration {
                     .if_not_exists()
                     .col(date(Histories::Date))
                     .col(string(Histories::Session))
-                    .col(json_binary_null(Histories::SubmitHistory))
+                    .col(json_null_for(manager, Histories::SubmitHistory))
                     .col(string(Histories::Solution))
                     .col(boolean(Histories::IsCompleted).default(false))
                     .col(date_time(Histories::UploadDate))
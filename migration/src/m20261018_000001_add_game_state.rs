@@ -0,0 +1,39 @@
+use crate::columns::json_null_for;
+
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // `histories`
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Histories::Table)
+                    .add_column(json_null_for(manager, Histories::GameState))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // `histories`
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Histories::Table)
+                    .drop_column(Histories::GameState)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Histories {
+    Table,
+    GameState,
+}
@@ -0,0 +1,56 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // `puzzles`, one column per statement as SQLite alters a single column at a time
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Puzzles::Table)
+                    .add_column(double_null(Puzzles::Difficulty))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Puzzles::Table)
+                    .add_column(boolean(Puzzles::IsDifficultyBlended).default(false))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // `puzzles`
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Puzzles::Table)
+                    .drop_column(Puzzles::IsDifficultyBlended)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Puzzles::Table)
+                    .drop_column(Puzzles::Difficulty)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Puzzles {
+    Table,
+    Difficulty,
+    IsDifficultyBlended,
+}
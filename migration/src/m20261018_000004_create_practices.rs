@@ -0,0 +1,57 @@
+use crate::columns::json_null_for;
+
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // `practices`
+        manager
+            .create_table(
+                Table::create()
+                    .table(Practices::Table)
+                    .if_not_exists()
+                    .col(string(Practices::Id).primary_key())
+                    .col(string(Practices::Session))
+                    .col(json_null_for(manager, Practices::SubmitHistory))
+                    .col(string(Practices::Solution))
+                    .col(date_time(Practices::CreatedAt))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_practice_session")
+                            .from(Practices::Table, Practices::Session)
+                            .to(Sessions::Table, Sessions::Session)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // `practices`
+        manager
+            .drop_table(Table::drop().table(Practices::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Practices {
+    Table,
+    Id,
+    Session,
+    SubmitHistory,
+    Solution,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Sessions {
+    Table,
+    Session,
+}
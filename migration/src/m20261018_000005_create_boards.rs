@@ -0,0 +1,110 @@
+use crate::columns::{json_for, json_null_for};
+
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // `board_puzzles`
+        manager
+            .create_table(
+                Table::create()
+                    .table(BoardPuzzles::Table)
+                    .if_not_exists()
+                    .col(date(BoardPuzzles::Date))
+                    .col(string(BoardPuzzles::Variant))
+                    .col(json_for(manager, BoardPuzzles::Solutions))
+                    .primary_key(
+                        Index::create()
+                            .col(BoardPuzzles::Date)
+                            .col(BoardPuzzles::Variant),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // `board_histories`
+        manager
+            .create_table(
+                Table::create()
+                    .table(BoardHistories::Table)
+                    .if_not_exists()
+                    .col(date(BoardHistories::Date))
+                    .col(string(BoardHistories::Variant))
+                    .col(string(BoardHistories::Session))
+                    .col(json_null_for(manager, BoardHistories::SubmitHistory))
+                    .col(json_for(manager, BoardHistories::Solutions))
+                    .col(date_time(BoardHistories::UploadedAt))
+                    .primary_key(
+                        Index::create()
+                            .col(BoardHistories::Date)
+                            .col(BoardHistories::Variant)
+                            .col(BoardHistories::Session),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_board_puzzle")
+                            .from(
+                                BoardHistories::Table,
+                                (BoardHistories::Date, BoardHistories::Variant),
+                            )
+                            .to(
+                                BoardPuzzles::Table,
+                                (BoardPuzzles::Date, BoardPuzzles::Variant),
+                            )
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_board_session")
+                            .from(BoardHistories::Table, BoardHistories::Session)
+                            .to(Sessions::Table, Sessions::Session)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // `board_histories`
+        manager
+            .drop_table(Table::drop().table(BoardHistories::Table).to_owned())
+            .await?;
+
+        // `board_puzzles`
+        manager
+            .drop_table(Table::drop().table(BoardPuzzles::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum BoardPuzzles {
+    Table,
+    Date,
+    Variant,
+    Solutions,
+}
+
+#[derive(DeriveIden)]
+enum BoardHistories {
+    Table,
+    Date,
+    Variant,
+    Session,
+    SubmitHistory,
+    Solutions,
+    UploadedAt,
+}
+
+#[derive(DeriveIden)]
+enum Sessions {
+    Table,
+    Session,
+}
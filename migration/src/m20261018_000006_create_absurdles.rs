@@ -0,0 +1,55 @@
+use crate::columns::json_null_for;
+
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // `absurdles`
+        manager
+            .create_table(
+                Table::create()
+                    .table(Absurdles::Table)
+                    .if_not_exists()
+                    .col(string(Absurdles::Id).primary_key())
+                    .col(string(Absurdles::Session))
+                    .col(json_null_for(manager, Absurdles::SubmitHistory))
+                    .col(date_time(Absurdles::CreatedAt))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_absurdle_session")
+                            .from(Absurdles::Table, Absurdles::Session)
+                            .to(Sessions::Table, Sessions::Session)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // `absurdles`
+        manager
+            .drop_table(Table::drop().table(Absurdles::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Absurdles {
+    Table,
+    Id,
+    Session,
+    SubmitHistory,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Sessions {
+    Table,
+    Session,
+}
@@ -0,0 +1,104 @@
+use crate::columns::json_null_for;
+
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // `custom_puzzles`
+        manager
+            .create_table(
+                Table::create()
+                    .table(CustomPuzzles::Table)
+                    .if_not_exists()
+                    .col(string(CustomPuzzles::Id).primary_key())
+                    .col(string(CustomPuzzles::Session))
+                    .col(integer(CustomPuzzles::MaxTries))
+                    .col(date_time(CustomPuzzles::CreatedAt))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_custom_creator")
+                            .from(CustomPuzzles::Table, CustomPuzzles::Session)
+                            .to(Sessions::Table, Sessions::Session)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // `custom_histories`
+        manager
+            .create_table(
+                Table::create()
+                    .table(CustomHistories::Table)
+                    .if_not_exists()
+                    .col(string(CustomHistories::Puzzle))
+                    .col(string(CustomHistories::Session))
+                    .col(json_null_for(manager, CustomHistories::SubmitHistory))
+                    .col(date_time(CustomHistories::UploadedAt))
+                    .primary_key(
+                        Index::create()
+                            .col(CustomHistories::Puzzle)
+                            .col(CustomHistories::Session),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_custom_puzzle")
+                            .from(CustomHistories::Table, CustomHistories::Puzzle)
+                            .to(CustomPuzzles::Table, CustomPuzzles::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_custom_session")
+                            .from(CustomHistories::Table, CustomHistories::Session)
+                            .to(Sessions::Table, Sessions::Session)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // `custom_histories`
+        manager
+            .drop_table(Table::drop().table(CustomHistories::Table).to_owned())
+            .await?;
+
+        // `custom_puzzles`
+        manager
+            .drop_table(Table::drop().table(CustomPuzzles::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum CustomPuzzles {
+    Table,
+    Id,
+    Session,
+    MaxTries,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum CustomHistories {
+    Table,
+    Puzzle,
+    Session,
+    SubmitHistory,
+    UploadedAt,
+}
+
+#[derive(DeriveIden)]
+enum Sessions {
+    Table,
+    Session,
+}
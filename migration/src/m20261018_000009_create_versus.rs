@@ -0,0 +1,73 @@
+use crate::columns::json_null_for;
+
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // `versus_matches`
+        manager
+            .create_table(
+                Table::create()
+                    .table(VersusMatches::Table)
+                    .if_not_exists()
+                    .col(string(VersusMatches::Id).primary_key())
+                    .col(string(VersusMatches::Host))
+                    .col(string_null(VersusMatches::Guest))
+                    .col(json_null_for(manager, VersusMatches::SubmitHistory))
+                    .col(string(VersusMatches::Solution))
+                    .col(string_null(VersusMatches::Winner))
+                    .col(date_time(VersusMatches::TurnStartedAt))
+                    .col(date_time_null(VersusMatches::FinishedAt))
+                    .col(date_time(VersusMatches::CreatedAt))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_versus_host")
+                            .from(VersusMatches::Table, VersusMatches::Host)
+                            .to(Sessions::Table, Sessions::Session)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_versus_guest")
+                            .from(VersusMatches::Table, VersusMatches::Guest)
+                            .to(Sessions::Table, Sessions::Session)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // `versus_matches`
+        manager
+            .drop_table(Table::drop().table(VersusMatches::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum VersusMatches {
+    Table,
+    Id,
+    Host,
+    Guest,
+    SubmitHistory,
+    Solution,
+    Winner,
+    TurnStartedAt,
+    FinishedAt,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Sessions {
+    Table,
+    Session,
+}
@@ -0,0 +1,98 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // `result_counts`
+        manager
+            .create_table(
+                Table::create()
+                    .table(ResultCounts::Table)
+                    .if_not_exists()
+                    .col(date(ResultCounts::Date))
+                    .col(boolean(ResultCounts::IsWon))
+                    .col(integer(ResultCounts::Tries))
+                    .col(big_integer(ResultCounts::Count))
+                    .primary_key(
+                        Index::create()
+                            .col(ResultCounts::Date)
+                            .col(ResultCounts::IsWon)
+                            .col(ResultCounts::Tries),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_result_puzzle")
+                            .from(ResultCounts::Table, ResultCounts::Date)
+                            .to(Puzzles::Table, Puzzles::Date)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // `first_guess_counts`
+        manager
+            .create_table(
+                Table::create()
+                    .table(FirstGuessCounts::Table)
+                    .if_not_exists()
+                    .col(date(FirstGuessCounts::Date))
+                    .col(string(FirstGuessCounts::Word))
+                    .col(big_integer(FirstGuessCounts::Count))
+                    .primary_key(
+                        Index::create()
+                            .col(FirstGuessCounts::Date)
+                            .col(FirstGuessCounts::Word),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_first_guess_puzzle")
+                            .from(FirstGuessCounts::Table, FirstGuessCounts::Date)
+                            .to(Puzzles::Table, Puzzles::Date)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // `first_guess_counts`
+        manager
+            .drop_table(Table::drop().table(FirstGuessCounts::Table).to_owned())
+            .await?;
+
+        // `result_counts`
+        manager
+            .drop_table(Table::drop().table(ResultCounts::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum ResultCounts {
+    Table,
+    Date,
+    IsWon,
+    Tries,
+    Count,
+}
+
+#[derive(DeriveIden)]
+enum FirstGuessCounts {
+    Table,
+    Date,
+    Word,
+    Count,
+}
+
+#[derive(DeriveIden)]
+enum Puzzles {
+    Table,
+    Date,
+}
@@ -0,0 +1,104 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // `groups`
+        manager
+            .create_table(
+                Table::create()
+                    .table(Groups::Table)
+                    .if_not_exists()
+                    .col(string(Groups::Id).primary_key())
+                    .col(string(Groups::Name))
+                    .col(string_uniq(Groups::InviteCode))
+                    .col(string(Groups::Owner))
+                    .col(date_time(Groups::CreatedAt))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_group_owner")
+                            .from(Groups::Table, Groups::Owner)
+                            .to(Sessions::Table, Sessions::Session)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // `group_members`
+        manager
+            .create_table(
+                Table::create()
+                    .table(GroupMembers::Table)
+                    .if_not_exists()
+                    .col(string(GroupMembers::Group))
+                    .col(string(GroupMembers::Session))
+                    .col(string(GroupMembers::Nickname))
+                    .col(date_time(GroupMembers::JoinedAt))
+                    .primary_key(
+                        Index::create()
+                            .col(GroupMembers::Group)
+                            .col(GroupMembers::Session),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_member_group")
+                            .from(GroupMembers::Table, GroupMembers::Group)
+                            .to(Groups::Table, Groups::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_member_session")
+                            .from(GroupMembers::Table, GroupMembers::Session)
+                            .to(Sessions::Table, Sessions::Session)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // `group_members`
+        manager
+            .drop_table(Table::drop().table(GroupMembers::Table).to_owned())
+            .await?;
+
+        // `groups`
+        manager
+            .drop_table(Table::drop().table(Groups::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Groups {
+    Table,
+    Id,
+    Name,
+    InviteCode,
+    Owner,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum GroupMembers {
+    Table,
+    Group,
+    Session,
+    Nickname,
+    JoinedAt,
+}
+
+#[derive(DeriveIden)]
+enum Sessions {
+    Table,
+    Session,
+}
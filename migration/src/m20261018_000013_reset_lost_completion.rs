@@ -0,0 +1,37 @@
+use sea_orm_migration::{prelude::*, sea_orm::DatabaseBackend};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // `histories`, where `is_completed` means won, so lost games are not completed
+        let is_lost = match manager.get_database_backend() {
+            DatabaseBackend::Postgres => Expr::cust("game_state ->> 'status' = 'lost'"),
+            _ => Expr::cust("json_extract(game_state, '$.status') = 'lost'"),
+        };
+
+        manager
+            .exec_stmt(
+                Query::update()
+                    .table(Histories::Table)
+                    .value(Histories::IsCompleted, false)
+                    .and_where(Expr::col(Histories::IsCompleted).eq(true))
+                    .and_where(is_lost)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+        // `histories`, where lost games were not completed before either
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Histories {
+    Table,
+    IsCompleted,
+}
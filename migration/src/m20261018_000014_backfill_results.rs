@@ -0,0 +1,147 @@
+use sea_orm_migration::{prelude::*, sea_orm::DatabaseBackend};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        // `result_counts` and `first_guess_counts`, recounted from the finished games that are not
+        // flagged, where games started before their state was tracked are won if completed and
+        // lost once out of tries. Won games count their tries with the cost of their hints, as
+        // charged by `Hints::charge`
+        db.execute_unprepared("DELETE FROM first_guess_counts")
+            .await?;
+        db.execute_unprepared("DELETE FROM result_counts").await?;
+
+        let (results, first_guesses) = match manager.get_database_backend() {
+            DatabaseBackend::Postgres => (
+                r#"
+                INSERT INTO result_counts (date, is_won, tries, count)
+                SELECT date, is_won, tries, COUNT(*)
+                FROM (
+                    SELECT
+                        date,
+                        COALESCE(game_state ->> 'status' = 'won', is_completed) AS is_won,
+                        COALESCE(
+                            (game_state ->> 'tries')::integer,
+                            CASE
+                                WHEN game_state IS NULL AND is_completed THEN LEAST(
+                                    COALESCE(jsonb_array_length(submit_history), 0)
+                                        + COALESCE(jsonb_array_length(hints), 0),
+                                    6
+                                )
+                                ELSE COALESCE(jsonb_array_length(submit_history), 0)
+                            END
+                        ) AS tries
+                    FROM histories
+                    WHERE (flags IS NULL OR jsonb_array_length(flags) = 0)
+                      AND (
+                        game_state ->> 'status' IN ('won', 'lost')
+                        OR (
+                            game_state IS NULL
+                            AND (is_completed OR jsonb_array_length(submit_history) >= 6)
+                        )
+                      )
+                ) AS finished
+                GROUP BY date, is_won, tries
+                "#,
+                r#"
+                INSERT INTO first_guess_counts (date, word, count)
+                SELECT date, word, COUNT(*)
+                FROM (
+                    SELECT
+                        date,
+                        (
+                            SELECT string_agg(letter ->> 'letter', '' ORDER BY position)
+                            FROM jsonb_array_elements(submit_history -> 0)
+                                WITH ORDINALITY AS letters (letter, position)
+                        ) AS word
+                    FROM histories
+                    WHERE (flags IS NULL OR jsonb_array_length(flags) = 0)
+                      AND jsonb_array_length(submit_history) > 0
+                      AND (
+                        game_state ->> 'status' IN ('won', 'lost')
+                        OR (
+                            game_state IS NULL
+                            AND (is_completed OR jsonb_array_length(submit_history) >= 6)
+                        )
+                      )
+                ) AS finished
+                GROUP BY date, word
+                "#,
+            ),
+            _ => (
+                r#"
+                INSERT INTO result_counts (date, is_won, tries, count)
+                SELECT date, is_won, tries, COUNT(*)
+                FROM (
+                    SELECT
+                        date,
+                        COALESCE(json_extract(game_state, '$.status') = 'won', is_completed)
+                            AS is_won,
+                        COALESCE(
+                            json_extract(game_state, '$.tries'),
+                            CASE
+                                WHEN game_state IS NULL AND is_completed THEN MIN(
+                                    COALESCE(json_array_length(submit_history), 0)
+                                        + COALESCE(json_array_length(hints), 0),
+                                    6
+                                )
+                                ELSE COALESCE(json_array_length(submit_history), 0)
+                            END
+                        ) AS tries
+                    FROM histories
+                    WHERE (flags IS NULL OR json_array_length(flags) = 0)
+                      AND (
+                        json_extract(game_state, '$.status') IN ('won', 'lost')
+                        OR (
+                            game_state IS NULL
+                            AND (is_completed OR json_array_length(submit_history) >= 6)
+                        )
+                      )
+                ) AS finished
+                GROUP BY date, is_won, tries
+                "#,
+                r#"
+                INSERT INTO first_guess_counts (date, word, count)
+                SELECT date, word, COUNT(*)
+                FROM (
+                    SELECT
+                        date,
+                        (
+                            SELECT group_concat(json_extract(value, '$.letter'), '')
+                            FROM (
+                                SELECT value
+                                FROM json_each(submit_history, '$[0]')
+                                ORDER BY key
+                            )
+                        ) AS word
+                    FROM histories
+                    WHERE (flags IS NULL OR json_array_length(flags) = 0)
+                      AND json_array_length(submit_history) > 0
+                      AND (
+                        json_extract(game_state, '$.status') IN ('won', 'lost')
+                        OR (
+                            game_state IS NULL
+                            AND (is_completed OR json_array_length(submit_history) >= 6)
+                        )
+                      )
+                ) AS finished
+                GROUP BY date, word
+                "#,
+            ),
+        };
+
+        db.execute_unprepared(results).await?;
+        db.execute_unprepared(first_guesses).await?;
+        Ok(())
+    }
+
+    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+        // `result_counts` and `first_guess_counts`, which are dropped with their tables
+        Ok(())
+    }
+}
@@ -0,0 +1,72 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // `histories`, whose raw addresses are dropped as only their keyed hashes are stored from
+        // now on
+        manager
+            .exec_stmt(
+                Query::update()
+                    .table(Histories::Table)
+                    .value(Histories::Address, Option::<String>::None)
+                    .and_where(Expr::col(Histories::Address).is_not_null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_histories_session_date")
+                    .table(Histories::Table)
+                    .col(Histories::Session)
+                    .col(Histories::Date)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_histories_date_address")
+                    .table(Histories::Table)
+                    .col(Histories::Date)
+                    .col(Histories::Address)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // `histories`, whose dropped addresses are not restored
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_histories_date_address")
+                    .table(Histories::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_histories_session_date")
+                    .table(Histories::Table)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Histories {
+    Table,
+    Date,
+    Session,
+    Address,
+}
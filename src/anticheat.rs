@@ -0,0 +1,130 @@
+//! Flags games suspected of cheating.
+//!
+//! Solutions leak easily and sessions are free, so won games are checked against a few heuristics
+//! as they finish. Flagged games are kept in the histories for review, but excluded from the
+//! aggregate results.
+
+use crate::env::{
+    CHEAT_FAST_SOLVE, CHEAT_FIRST_GUESS_RATE, CHEAT_FIRST_GUESS_SAMPLE, CHEAT_QUICK_SOLVE,
+    CHEAT_SHARED_ADDRESS_SESSIONS,
+};
+
+use std::time::Duration;
+
+use chrono::Days;
+use entity::{CheatFlag, CheatFlags, GameState, PuzzleDate, histories::Model as History};
+
+/// The number of days before a game whose games of the same session are checked for
+/// [`CheatFlag::LuckyFirstGuesses`].
+pub const FIRST_GUESS_WINDOW_DAYS: u64 = 90;
+
+/// Returns the first date of the games of a session checked against a game of a date.
+pub fn first_guess_window_start(date: &PuzzleDate) -> PuzzleDate {
+    PuzzleDate::new(date.0 - Days::new(FIRST_GUESS_WINDOW_DAYS))
+}
+
+/// The limits past which a won game is flagged.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CheatLimits {
+    /// The time from the start under which a win is a [`CheatFlag::FastSolve`].
+    pub fast_solve: Duration,
+    /// The share of the recent finished games of a session won on the first guess from which they
+    /// are [`CheatFlag::LuckyFirstGuesses`].
+    pub first_guess_rate: f64,
+    /// The number of recent finished games of a session needed to flag its first-guess wins.
+    pub first_guess_sample: usize,
+    /// The time from the start under which a win counts towards [`CheatFlag::SharedAddress`].
+    pub quick_solve: Duration,
+    /// The number of sessions from an address quickly winning a puzzle from which they are
+    /// [`CheatFlag::SharedAddress`].
+    pub shared_address_sessions: usize,
+}
+
+impl CheatLimits {
+    /// Reads the limits from the environment.
+    ///
+    /// See: [`CHEAT_FAST_SOLVE`], [`CHEAT_FIRST_GUESS_RATE`], [`CHEAT_FIRST_GUESS_SAMPLE`],
+    /// [`CHEAT_QUICK_SOLVE`], [`CHEAT_SHARED_ADDRESS_SESSIONS`]
+    pub fn from_env() -> Self {
+        Self {
+            fast_solve: *CHEAT_FAST_SOLVE,
+            first_guess_rate: *CHEAT_FIRST_GUESS_RATE,
+            first_guess_sample: *CHEAT_FIRST_GUESS_SAMPLE,
+            quick_solve: *CHEAT_QUICK_SOLVE,
+            shared_address_sessions: *CHEAT_SHARED_ADDRESS_SESSIONS,
+        }
+    }
+
+    /// Inspects a finished game against the games of its session since
+    /// [`first_guess_window_start`] and the games of its date from its address, returning the
+    /// flags raised, which are empty unless the game is a suspicious win.
+    pub fn inspect(
+        &self,
+        history: &History,
+        session_histories: &[History],
+        address_histories: &[History],
+    ) -> CheatFlags {
+        let mut flags = CheatFlags::default();
+        let GameState::Won { tries } = history.game_state() else {
+            return flags;
+        };
+
+        if is_won_within(history, self.fast_solve) {
+            flags.raise(CheatFlag::FastSolve);
+        }
+
+        if tries == 1 {
+            let (finished, first_guess_wins) = session_histories
+                .iter()
+                .filter(|other| other.date != history.date)
+                .map(History::game_state)
+                .filter(|state| state.is_over())
+                .fold((1_usize, 1_usize), |(finished, wins), state| {
+                    let is_first_guess_win = state == GameState::Won { tries: 1 };
+                    (finished + 1, wins + usize::from(is_first_guess_win))
+                });
+            if finished >= self.first_guess_sample
+                && first_guess_wins as f64 / finished as f64 >= self.first_guess_rate
+            {
+                flags.raise(CheatFlag::LuckyFirstGuesses);
+            }
+        }
+
+        if self.accomplices(history, address_histories).count() + 1 >= self.shared_address_sessions
+            && is_won_within(history, self.quick_solve)
+        {
+            flags.raise(CheatFlag::SharedAddress);
+        }
+
+        flags
+    }
+
+    /// Returns the other games of the date of a game that were quickly won from its address, which
+    /// share its [`CheatFlag::SharedAddress`] once raised.
+    pub fn accomplices<'a>(
+        &self,
+        history: &'a History,
+        address_histories: &'a [History],
+    ) -> impl Iterator<Item = &'a History> {
+        let quick_solve = self.quick_solve;
+        address_histories.iter().filter(move |other| {
+            other.session != history.session
+                && other.date == history.date
+                && history.address.is_some()
+                && other.address == history.address
+                && is_won_within(other, quick_solve)
+        })
+    }
+}
+
+/// Returns whether a game was won within a time from its start, as timed by the server.
+fn is_won_within(history: &History, limit: Duration) -> bool {
+    let completed_in_millis = history
+        .timing
+        .as_ref()
+        .and_then(|timing| timing.completed_in_millis);
+    matches!(history.game_state(), GameState::Won { .. })
+        && completed_in_millis.is_some_and(|millis| {
+            u128::try_from(millis).is_ok_and(|millis| millis < limit.as_millis())
+        })
+}
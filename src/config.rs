@@ -13,6 +13,8 @@ use crate::env::CONFIG_DIR;
 pub enum ConfigFile {
     /// The CORS configuration.
     Cors,
+    /// The configuration of the puzzle feeds.
+    Feeds,
 }
 
 impl ConfigFile {
@@ -20,6 +22,7 @@ impl ConfigFile {
     pub fn file_name(&self) -> &'static str {
         match self {
             Self::Cors => "cors.toml",
+            Self::Feeds => "feeds.toml",
         }
     }
 
@@ -108,3 +111,58 @@ pub mod services {
         }
     }
 }
+
+/// The feeds config.
+pub mod feeds {
+    use entity::PuzzleDate;
+
+    use super::*;
+
+    /// Defines how the feeds and the calendar describe and link to the puzzles.
+    #[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+    pub struct FeedsConfig {
+        /// The title of the feeds.
+        #[serde(default = "default_title")]
+        pub title: String,
+        /// The link to the site the feeds belong to.
+        pub site: Option<String>,
+        /// The link to the puzzle of each entry, where `{date}` is replaced by the date in
+        /// `YYYY-MM-DD` format and `{number}` by the puzzle number.
+        pub link_template: Option<String>,
+    }
+
+    fn default_title() -> String {
+        "Wordle".to_owned()
+    }
+
+    impl Default for FeedsConfig {
+        fn default() -> Self {
+            Self {
+                title: default_title(),
+                site: None,
+                link_template: None,
+            }
+        }
+    }
+
+    impl FeedsConfig {
+        /// Returns the link to the puzzle of a date, if a link template is configured. Puzzles
+        /// without a number are linked by date only.
+        pub fn link(&self, date: &PuzzleDate, number: Option<u64>) -> Option<String> {
+            let link = self
+                .link_template
+                .as_ref()?
+                .replace("{date}", &date.to_string());
+            match number {
+                Some(number) => Some(link.replace("{number}", &number.to_string())),
+                None => (!link.contains("{number}")).then_some(link),
+            }
+        }
+    }
+
+    impl Config<'_> for FeedsConfig {
+        fn file() -> ConfigFile {
+            ConfigFile::Feeds
+        }
+    }
+}
@@ -0,0 +1,923 @@
+//! The in-memory storage.
+//!
+//! [`MemoryStore`] keeps every table in process memory and mirrors the constraints of the database
+//! schema, so it can stand in for the database in tests and in ephemeral mode.
+
+use crate::{
+    database::repo::{
+        self, AbsurdleRepo, ArchivePage, BoardRepo, CustomRepo, GroupRepo, HintError, HistoryRepo,
+        PracticeRepo, PuzzleRepo, ResultRepo, SessionRepo, SubmitError, SubmitResult, VersusRepo,
+    },
+    live::LIVE,
+};
+
+use std::collections::BTreeMap;
+
+use async_trait::async_trait;
+use chrono::{NaiveDateTime, Utc};
+use entity::{
+    BoardSolutions, BoardVariant, GameState, Hint, Hints, PuzzleDate, PuzzleSolution, SubmitWord,
+    Timing, absurdles::Model as AbsurdleGame, board_histories::Model as BoardHistory,
+    board_puzzles::Model as BoardPuzzle, custom_histories::Model as CustomHistory,
+    custom_puzzles::Model as CustomPuzzle, first_guess_counts::Model as FirstGuessCount,
+    group_members::Model as GroupMember, groups::Model as Group, histories::Model as History,
+    practices::Model as Practice, puzzles::Model as Puzzle, result_counts::Model as ResultCount,
+    sessions::Model as Session, versus_matches::Model as VersusMatch,
+};
+use parking_lot::RwLock;
+use sea_orm::DbErr;
+use tokio::sync::Mutex;
+
+/// A thread-safe storage holding all tables in memory.
+#[derive(Debug, Default)]
+pub struct MemoryStore {
+    /// Serializes the read-modify-write updates of histories, like a row lock in the database.
+    history_lock: Mutex<()>,
+    puzzles: RwLock<BTreeMap<PuzzleDate, Puzzle>>,
+    histories: RwLock<BTreeMap<(PuzzleDate, String), History>>,
+    result_counts: RwLock<BTreeMap<(PuzzleDate, bool, i32), ResultCount>>,
+    first_guess_counts: RwLock<BTreeMap<(PuzzleDate, String), FirstGuessCount>>,
+    practices: RwLock<BTreeMap<String, Practice>>,
+    board_puzzles: RwLock<BTreeMap<(PuzzleDate, BoardVariant), BoardPuzzle>>,
+    board_histories: RwLock<BTreeMap<(PuzzleDate, BoardVariant, String), BoardHistory>>,
+    absurdles: RwLock<BTreeMap<String, AbsurdleGame>>,
+    custom_puzzles: RwLock<BTreeMap<String, CustomPuzzle>>,
+    custom_histories: RwLock<BTreeMap<(String, String), CustomHistory>>,
+    versus_matches: RwLock<BTreeMap<String, VersusMatch>>,
+    groups: RwLock<BTreeMap<String, Group>>,
+    group_members: RwLock<BTreeMap<(String, String), GroupMember>>,
+    sessions: RwLock<BTreeMap<String, Session>>,
+}
+
+#[async_trait]
+impl PuzzleRepo for MemoryStore {
+    async fn get_dates(&self) -> Vec<PuzzleDate> {
+        tracing::info!("getting dates…");
+        self.puzzles.read().keys().cloned().collect()
+    }
+
+    async fn get_puzzles(&self) -> Vec<Puzzle> {
+        tracing::info!("getting puzzles…");
+        self.puzzles.read().values().cloned().collect()
+    }
+
+    async fn get_puzzle(&self, date: &PuzzleDate) -> Option<Puzzle> {
+        tracing::info!("getting puzzle for {date}…");
+        self.puzzles.read().get(date).cloned()
+    }
+
+    async fn get_unblended_puzzles(&self, before: &PuzzleDate) -> Vec<Puzzle> {
+        tracing::info!("getting unblended puzzles before {before}…");
+        self.puzzles
+            .read()
+            .range(..before.clone())
+            .map(|(_, puzzle)| puzzle)
+            .filter(|puzzle| !puzzle.is_difficulty_blended)
+            .cloned()
+            .collect()
+    }
+
+    async fn insert_solution(
+        &self,
+        date: &PuzzleDate,
+        solution: &PuzzleSolution,
+        difficulty: Option<f64>,
+    ) -> Result<(), DbErr> {
+        tracing::info!("inserting puzzle for {date}…");
+        self.puzzles.write().insert(
+            date.clone(),
+            Puzzle {
+                date: date.clone(),
+                solution: solution.clone(),
+                difficulty,
+                is_difficulty_blended: false,
+            },
+        );
+        tracing::info!("inserted solution {solution} for {date}");
+        Ok(())
+    }
+
+    async fn update_predicted_difficulty(
+        &self,
+        date: &PuzzleDate,
+        solution: &PuzzleSolution,
+        difficulty: f64,
+    ) -> Result<(), DbErr> {
+        tracing::info!("updating predicted difficulty for {date}…");
+        if let Some(stored) = self.puzzles.write().get_mut(date)
+            && &stored.solution == solution
+            && !stored.is_difficulty_blended
+        {
+            stored.difficulty = Some(difficulty);
+            tracing::info!("updated predicted difficulty for {date} to {difficulty:.2}");
+        }
+        Ok(())
+    }
+
+    async fn update_difficulty(&self, date: &PuzzleDate, difficulty: f64) -> Result<(), DbErr> {
+        tracing::info!("updating difficulty for {date}…");
+        let mut puzzles = self.puzzles.write();
+        let Some(stored) = puzzles.get_mut(date) else {
+            return Err(DbErr::RecordNotUpdated);
+        };
+        stored.difficulty = Some(difficulty);
+        stored.is_difficulty_blended = true;
+
+        tracing::info!("updated difficulty for {date} to {difficulty:.2}");
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ResultRepo for MemoryStore {
+    async fn get_result_counts(&self, date: &PuzzleDate) -> Vec<ResultCount> {
+        tracing::info!("getting result counts for {date}…");
+        self.result_counts
+            .read()
+            .values()
+            .filter(|count| &count.date == date)
+            .cloned()
+            .collect()
+    }
+
+    async fn get_first_guess_counts(
+        &self,
+        date: &PuzzleDate,
+        limit: usize,
+    ) -> Vec<FirstGuessCount> {
+        tracing::info!("getting first guess counts for {date}…");
+        let mut counts: Vec<_> = self
+            .first_guess_counts
+            .read()
+            .values()
+            .filter(|count| &count.date == date)
+            .cloned()
+            .collect();
+        counts.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.word.cmp(&b.word)));
+        counts.truncate(limit);
+        counts
+    }
+
+    async fn record_result(
+        &self,
+        date: &PuzzleDate,
+        state: GameState,
+        tries: usize,
+        first_guess: Option<&SubmitWord>,
+    ) -> Result<(), DbErr> {
+        tracing::info!("recording result {state} for {date}…");
+        if !self.puzzles.read().contains_key(date) {
+            return Err(DbErr::RecordNotInserted);
+        }
+
+        let is_won = matches!(state, GameState::Won { .. });
+        let tries = i32::try_from(tries).map_err(|err| DbErr::Custom(err.to_string()))?;
+        self.result_counts
+            .write()
+            .entry((date.clone(), is_won, tries))
+            .or_insert_with(|| ResultCount {
+                date: date.clone(),
+                is_won,
+                tries,
+                count: 0,
+            })
+            .count += 1;
+        if let Some(first_guess) = first_guess {
+            let word = first_guess.word();
+            self.first_guess_counts
+                .write()
+                .entry((date.clone(), word.clone()))
+                .or_insert_with(|| FirstGuessCount {
+                    date: date.clone(),
+                    word,
+                    count: 0,
+                })
+                .count += 1;
+        }
+
+        tracing::info!("recorded result {state} for {date}");
+        Ok(())
+    }
+
+    async fn discount_result(
+        &self,
+        date: &PuzzleDate,
+        state: GameState,
+        tries: usize,
+        first_guess: Option<&SubmitWord>,
+    ) -> Result<(), DbErr> {
+        tracing::info!("discounting result {state} for {date}…");
+        let is_won = matches!(state, GameState::Won { .. });
+        let tries = i32::try_from(tries).map_err(|err| DbErr::Custom(err.to_string()))?;
+        if let Some(count) = self
+            .result_counts
+            .write()
+            .get_mut(&(date.clone(), is_won, tries))
+        {
+            count.count = (count.count - 1).max(0);
+        }
+        if let Some(first_guess) = first_guess
+            && let Some(count) = self
+                .first_guess_counts
+                .write()
+                .get_mut(&(date.clone(), first_guess.word()))
+        {
+            count.count = (count.count - 1).max(0);
+        }
+
+        tracing::info!("discounted result {state} for {date}");
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl HistoryRepo for MemoryStore {
+    async fn get_history(&self, date: &PuzzleDate, session: &str) -> Option<History> {
+        tracing::info!("getting history for {date} with session {session}…");
+        self.histories
+            .read()
+            .get(&(date.clone(), session.to_owned()))
+            .cloned()
+    }
+
+    async fn get_histories(&self, date: &PuzzleDate) -> Vec<History> {
+        tracing::info!("getting histories for {date}…");
+        self.histories
+            .read()
+            .values()
+            .filter(|history| history.date == *date)
+            .cloned()
+            .collect()
+    }
+
+    async fn get_session_histories(&self, sessions: &[String], since: &PuzzleDate) -> Vec<History> {
+        tracing::info!(
+            "getting histories for {} sessions since {since}…",
+            sessions.len()
+        );
+        self.histories
+            .read()
+            .values()
+            .filter(|history| sessions.contains(&history.session) && history.date >= *since)
+            .cloned()
+            .collect()
+    }
+
+    async fn get_address_histories(&self, date: &PuzzleDate, address: &str) -> Vec<History> {
+        tracing::info!("getting histories for {date} with address {address}…");
+        self.histories
+            .read()
+            .values()
+            .filter(|history| history.date == *date && history.address.as_deref() == Some(address))
+            .cloned()
+            .collect()
+    }
+
+    async fn get_flagged_histories(&self) -> Vec<History> {
+        tracing::info!("getting flagged histories…");
+        let mut histories: Vec<_> = self
+            .histories
+            .read()
+            .values()
+            .filter(|history| history.flags.is_some())
+            .cloned()
+            .collect();
+        histories.sort_by(|a, b| b.date.cmp(&a.date));
+        histories
+    }
+
+    async fn get_archive(&self, session: &str, page: u64, per_page: u64) -> ArchivePage {
+        tracing::info!("getting archive page {page} with session {session}…");
+        let puzzles = self.puzzles.read();
+        let histories = self.histories.read();
+        let skipped = page.saturating_mul(per_page);
+        ArchivePage {
+            total: puzzles.len() as u64,
+            entries: puzzles
+                .values()
+                .rev()
+                .skip(usize::try_from(skipped).unwrap_or(usize::MAX))
+                .take(usize::try_from(per_page).unwrap_or(usize::MAX))
+                .map(|puzzle| {
+                    let key = (puzzle.date.clone(), session.to_owned());
+                    (puzzle.clone(), histories.get(&key).cloned())
+                })
+                .collect(),
+        }
+    }
+
+    async fn create_history(
+        &self,
+        date: &PuzzleDate,
+        session: &str,
+        solution: &PuzzleSolution,
+        timing: &Timing,
+    ) -> Result<(), DbErr> {
+        tracing::info!("creating history for {date} with session {session}…");
+        if !self.puzzles.read().contains_key(date) {
+            return Err(DbErr::RecordNotInserted);
+        }
+        if !self.sessions.read().contains_key(session) {
+            return Err(DbErr::RecordNotInserted);
+        }
+
+        let mut histories = self.histories.write();
+        let key = (date.clone(), session.to_owned());
+        if histories.contains_key(&key) {
+            return Err(DbErr::RecordNotInserted);
+        }
+        histories.insert(
+            key,
+            History {
+                date: date.clone(),
+                session: session.to_owned(),
+                submit_history: None,
+                solution: solution.clone(),
+                is_completed: false,
+                game_state: Some(GameState::InProgress),
+                hints: None,
+                timing: Some(timing.clone()),
+                address: None,
+                flags: None,
+                uploaded_at: Utc::now().naive_utc(),
+            },
+        );
+
+        tracing::info!("created history for {date} with session {session} and solution {solution}");
+        Ok(())
+    }
+
+    async fn update_history(&self, history: &History) -> Result<(), DbErr> {
+        let History { date, session, .. } = history;
+        tracing::info!("updating history for {date} with session {session}…");
+
+        let mut histories = self.histories.write();
+        let Some(stored) = histories.get_mut(&(date.clone(), session.clone())) else {
+            return Err(DbErr::RecordNotUpdated);
+        };
+        stored.submit_history = history.submit_history.clone();
+        stored.is_completed = history.is_completed;
+        stored.game_state = history.game_state;
+        stored.hints = history.hints.clone();
+        stored.timing = history.timing.clone();
+        stored.address = history.address.clone();
+        stored.flags = history.flags.clone();
+
+        tracing::info!("updated history for {date} with session {session}");
+        Ok(())
+    }
+
+    async fn submit_to_history(
+        &self,
+        date: &PuzzleDate,
+        session: &str,
+        answer: &PuzzleSolution,
+        address: &str,
+    ) -> Result<SubmitResult, SubmitError> {
+        tracing::info!("submitting {answer} to history at {date} with {session}…");
+        let mut outbox = LIVE.outbox(date).await;
+        let _guard = self.history_lock.lock().await;
+        let Some(history) = self.get_history(date, session).await else {
+            return Err(repo::missing_history(date, session).into());
+        };
+
+        let result = repo::submit(self, history, answer, address, &mut outbox).await;
+        outbox.publish();
+        result
+    }
+
+    async fn use_hint(&self, date: &PuzzleDate, session: &str) -> Result<(Hint, Hints), HintError> {
+        tracing::info!("using hint at {date} with {session}…");
+        let mut outbox = LIVE.outbox(date).await;
+        let _guard = self.history_lock.lock().await;
+        let Some(history) = self.get_history(date, session).await else {
+            return Err(repo::missing_history(date, session).into());
+        };
+
+        let result = repo::reveal_hint(self, history, &mut outbox).await;
+        outbox.publish();
+        result
+    }
+}
+
+#[async_trait]
+impl PracticeRepo for MemoryStore {
+    async fn get_practice(&self, id: &str, session: &str) -> Option<Practice> {
+        tracing::info!("getting practice {id} with session {session}…");
+        self.practices
+            .read()
+            .get(id)
+            .filter(|practice| practice.session == session)
+            .cloned()
+    }
+
+    async fn create_practice(
+        &self,
+        id: &str,
+        session: &str,
+        solution: &PuzzleSolution,
+    ) -> Result<Practice, DbErr> {
+        tracing::info!("creating practice {id} with session {session}…");
+        if !self.sessions.read().contains_key(session) {
+            return Err(DbErr::RecordNotInserted);
+        }
+
+        let mut practices = self.practices.write();
+        if practices.contains_key(id) {
+            return Err(DbErr::RecordNotInserted);
+        }
+        let practice = Practice {
+            id: id.to_owned(),
+            session: session.to_owned(),
+            submit_history: None,
+            solution: solution.clone(),
+            created_at: Utc::now().naive_utc(),
+        };
+        practices.insert(id.to_owned(), practice.clone());
+
+        tracing::info!("created practice {id} with session {session} and solution {solution}");
+        Ok(practice)
+    }
+
+    async fn update_practice(&self, practice: &Practice) -> Result<(), DbErr> {
+        let Practice { id, session, .. } = practice;
+        tracing::info!("updating practice {id} with session {session}…");
+
+        let mut practices = self.practices.write();
+        let Some(stored) = practices.get_mut(id) else {
+            return Err(DbErr::RecordNotUpdated);
+        };
+        stored.submit_history = practice.submit_history.clone();
+
+        tracing::info!("updated practice {id} with session {session}");
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl BoardRepo for MemoryStore {
+    async fn get_board_puzzle(
+        &self,
+        date: &PuzzleDate,
+        variant: BoardVariant,
+    ) -> Option<BoardPuzzle> {
+        tracing::info!("getting {variant} puzzle for {date}…");
+        self.board_puzzles
+            .read()
+            .get(&(date.clone(), variant))
+            .cloned()
+    }
+
+    async fn insert_board_solutions(
+        &self,
+        date: &PuzzleDate,
+        variant: BoardVariant,
+        solutions: &BoardSolutions,
+    ) -> Result<(), DbErr> {
+        tracing::info!("inserting {variant} puzzle for {date}…");
+        self.board_puzzles.write().insert(
+            (date.clone(), variant),
+            BoardPuzzle {
+                date: date.clone(),
+                variant,
+                solutions: solutions.clone(),
+            },
+        );
+        tracing::info!("inserted {variant} solutions {:?} for {date}", solutions.0);
+        Ok(())
+    }
+
+    async fn get_board_history(
+        &self,
+        date: &PuzzleDate,
+        variant: BoardVariant,
+        session: &str,
+    ) -> Option<BoardHistory> {
+        tracing::info!("getting {variant} history for {date} with session {session}…");
+        self.board_histories
+            .read()
+            .get(&(date.clone(), variant, session.to_owned()))
+            .cloned()
+    }
+
+    async fn create_board_history(
+        &self,
+        date: &PuzzleDate,
+        variant: BoardVariant,
+        session: &str,
+        solutions: &BoardSolutions,
+    ) -> Result<(), DbErr> {
+        tracing::info!("creating {variant} history for {date} with session {session}…");
+        if !self
+            .board_puzzles
+            .read()
+            .contains_key(&(date.clone(), variant))
+        {
+            return Err(DbErr::RecordNotInserted);
+        }
+        if !self.sessions.read().contains_key(session) {
+            return Err(DbErr::RecordNotInserted);
+        }
+
+        let mut histories = self.board_histories.write();
+        let key = (date.clone(), variant, session.to_owned());
+        if histories.contains_key(&key) {
+            return Err(DbErr::RecordNotInserted);
+        }
+        histories.insert(
+            key,
+            BoardHistory {
+                date: date.clone(),
+                variant,
+                session: session.to_owned(),
+                submit_history: None,
+                solutions: solutions.clone(),
+                uploaded_at: Utc::now().naive_utc(),
+            },
+        );
+
+        tracing::info!("created {variant} history for {date} with session {session}");
+        Ok(())
+    }
+
+    async fn update_board_history(&self, history: &BoardHistory) -> Result<(), DbErr> {
+        let BoardHistory {
+            date,
+            variant,
+            session,
+            ..
+        } = history;
+        tracing::info!("updating {variant} history for {date} with session {session}…");
+
+        let mut histories = self.board_histories.write();
+        let Some(stored) = histories.get_mut(&(date.clone(), *variant, session.clone())) else {
+            return Err(DbErr::RecordNotUpdated);
+        };
+        stored.submit_history = history.submit_history.clone();
+
+        tracing::info!("updated {variant} history for {date} with session {session}");
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl AbsurdleRepo for MemoryStore {
+    async fn get_absurdle(&self, id: &str, session: &str) -> Option<AbsurdleGame> {
+        tracing::info!("getting absurdle {id} with session {session}…");
+        self.absurdles
+            .read()
+            .get(id)
+            .filter(|game| game.session == session)
+            .cloned()
+    }
+
+    async fn create_absurdle(&self, id: &str, session: &str) -> Result<AbsurdleGame, DbErr> {
+        tracing::info!("creating absurdle {id} with session {session}…");
+        if !self.sessions.read().contains_key(session) {
+            return Err(DbErr::RecordNotInserted);
+        }
+
+        let mut absurdles = self.absurdles.write();
+        if absurdles.contains_key(id) {
+            return Err(DbErr::RecordNotInserted);
+        }
+        let game = AbsurdleGame {
+            id: id.to_owned(),
+            session: session.to_owned(),
+            submit_history: None,
+            created_at: Utc::now().naive_utc(),
+        };
+        absurdles.insert(id.to_owned(), game.clone());
+
+        tracing::info!("created absurdle {id} with session {session}");
+        Ok(game)
+    }
+
+    async fn update_absurdle(&self, game: &AbsurdleGame) -> Result<(), DbErr> {
+        let AbsurdleGame { id, session, .. } = game;
+        tracing::info!("updating absurdle {id} with session {session}…");
+
+        let mut absurdles = self.absurdles.write();
+        let Some(stored) = absurdles.get_mut(id) else {
+            return Err(DbErr::RecordNotUpdated);
+        };
+        stored.submit_history = game.submit_history.clone();
+
+        tracing::info!("updated absurdle {id} with session {session}");
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl CustomRepo for MemoryStore {
+    async fn get_custom_puzzle(&self, id: &str) -> Option<CustomPuzzle> {
+        tracing::info!("getting custom puzzle {id}…");
+        self.custom_puzzles.read().get(id).cloned()
+    }
+
+    async fn create_custom_puzzle(
+        &self,
+        id: &str,
+        session: &str,
+        max_tries: usize,
+    ) -> Result<CustomPuzzle, DbErr> {
+        tracing::info!("creating custom puzzle {id} with session {session}…");
+        if !self.sessions.read().contains_key(session) {
+            return Err(DbErr::RecordNotInserted);
+        }
+
+        let mut custom_puzzles = self.custom_puzzles.write();
+        if custom_puzzles.contains_key(id) {
+            return Err(DbErr::RecordNotInserted);
+        }
+        let puzzle = CustomPuzzle {
+            id: id.to_owned(),
+            session: session.to_owned(),
+            max_tries: i32::try_from(max_tries).map_err(|err| DbErr::Custom(err.to_string()))?,
+            created_at: Utc::now().naive_utc(),
+        };
+        custom_puzzles.insert(id.to_owned(), puzzle.clone());
+
+        tracing::info!("created custom puzzle {id} with session {session}");
+        Ok(puzzle)
+    }
+
+    async fn get_custom_history(&self, puzzle: &str, session: &str) -> Option<CustomHistory> {
+        tracing::info!("getting history for custom puzzle {puzzle} with session {session}…");
+        self.custom_histories
+            .read()
+            .get(&(puzzle.to_owned(), session.to_owned()))
+            .cloned()
+    }
+
+    async fn get_custom_histories(&self, puzzle: &str) -> Vec<CustomHistory> {
+        tracing::info!("getting histories for custom puzzle {puzzle}…");
+        self.custom_histories
+            .read()
+            .values()
+            .filter(|history| history.puzzle == puzzle)
+            .cloned()
+            .collect()
+    }
+
+    async fn insert_or_update_custom_history(&self, history: &CustomHistory) -> Result<(), DbErr> {
+        let CustomHistory {
+            puzzle, session, ..
+        } = history;
+        tracing::info!("upserting history for custom puzzle {puzzle} with session {session}…");
+        if !self.custom_puzzles.read().contains_key(puzzle)
+            || !self.sessions.read().contains_key(session)
+        {
+            return Err(DbErr::RecordNotInserted);
+        }
+
+        self.custom_histories
+            .write()
+            .entry((puzzle.clone(), session.clone()))
+            .and_modify(|stored| stored.submit_history = history.submit_history.clone())
+            .or_insert_with(|| history.clone());
+
+        tracing::info!("upserted history for custom puzzle {puzzle} with session {session}");
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl GroupRepo for MemoryStore {
+    async fn get_group(&self, id: &str) -> Option<Group> {
+        tracing::info!("getting group {id}…");
+        self.groups.read().get(id).cloned()
+    }
+
+    async fn get_group_by_invite_code(&self, invite_code: &str) -> Option<Group> {
+        tracing::info!("getting group by invite code {invite_code}…");
+        self.groups
+            .read()
+            .values()
+            .find(|group| group.invite_code == invite_code)
+            .cloned()
+    }
+
+    async fn create_group(
+        &self,
+        id: &str,
+        name: &str,
+        invite_code: &str,
+        session: &str,
+        nickname: &str,
+    ) -> Result<Group, DbErr> {
+        tracing::info!("creating group {id} with session {session}…");
+        if !self.sessions.read().contains_key(session) {
+            return Err(DbErr::RecordNotInserted);
+        }
+
+        let mut groups = self.groups.write();
+        if groups.contains_key(id)
+            || groups
+                .values()
+                .any(|group| group.invite_code == invite_code)
+        {
+            return Err(DbErr::RecordNotInserted);
+        }
+        let now = Utc::now().naive_utc();
+        let group = Group {
+            id: id.to_owned(),
+            name: name.to_owned(),
+            invite_code: invite_code.to_owned(),
+            owner: session.to_owned(),
+            created_at: now,
+        };
+        groups.insert(id.to_owned(), group.clone());
+        self.group_members.write().insert(
+            (id.to_owned(), session.to_owned()),
+            GroupMember {
+                group: id.to_owned(),
+                session: session.to_owned(),
+                nickname: nickname.to_owned(),
+                joined_at: now,
+            },
+        );
+
+        tracing::info!("created group {id} with session {session}");
+        Ok(group)
+    }
+
+    async fn get_group_members(&self, id: &str) -> Vec<GroupMember> {
+        tracing::info!("getting members of group {id}…");
+        self.group_members
+            .read()
+            .values()
+            .filter(|member| member.group == id)
+            .cloned()
+            .collect()
+    }
+
+    async fn insert_or_update_group_member(&self, member: &GroupMember) -> Result<(), DbErr> {
+        let GroupMember { group, session, .. } = member;
+        tracing::info!("upserting member of group {group} with session {session}…");
+        if !self.groups.read().contains_key(group) || !self.sessions.read().contains_key(session) {
+            return Err(DbErr::RecordNotInserted);
+        }
+
+        self.group_members
+            .write()
+            .entry((group.clone(), session.clone()))
+            .and_modify(|stored| stored.nickname = member.nickname.clone())
+            .or_insert_with(|| member.clone());
+
+        tracing::info!("upserted member of group {group} with session {session}");
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl VersusRepo for MemoryStore {
+    async fn get_versus(&self, id: &str, session: &str) -> Option<VersusMatch> {
+        tracing::info!("getting versus {id} with session {session}…");
+        self.versus_matches
+            .read()
+            .get(id)
+            .filter(|game| game.is_player(session))
+            .cloned()
+    }
+
+    async fn get_waiting_versus(&self, session: &str, since: NaiveDateTime) -> Option<VersusMatch> {
+        tracing::info!("getting waiting versus with session {session}…");
+        self.versus_matches
+            .read()
+            .values()
+            .filter(|game| game.host == session && game.guest.is_none())
+            .filter(|game| !game.is_over() && game.created_at >= since)
+            .max_by_key(|game| game.created_at)
+            .cloned()
+    }
+
+    async fn create_versus(
+        &self,
+        id: &str,
+        session: &str,
+        solution: &PuzzleSolution,
+    ) -> Result<VersusMatch, DbErr> {
+        tracing::info!("creating versus {id} with session {session}…");
+        if !self.sessions.read().contains_key(session) {
+            return Err(DbErr::RecordNotInserted);
+        }
+
+        let mut versus_matches = self.versus_matches.write();
+        if versus_matches.contains_key(id) {
+            return Err(DbErr::RecordNotInserted);
+        }
+        let now = Utc::now().naive_utc();
+        let game = VersusMatch {
+            id: id.to_owned(),
+            host: session.to_owned(),
+            guest: None,
+            submit_history: None,
+            solution: solution.clone(),
+            winner: None,
+            turn_started_at: now,
+            finished_at: None,
+            created_at: now,
+        };
+        versus_matches.insert(id.to_owned(), game.clone());
+
+        tracing::info!("created versus {id} with session {session}");
+        Ok(game)
+    }
+
+    async fn join_waiting_versus(
+        &self,
+        session: &str,
+        since: NaiveDateTime,
+    ) -> Result<Option<VersusMatch>, DbErr> {
+        tracing::info!("joining waiting versus with session {session}…");
+        if !self.sessions.read().contains_key(session) {
+            return Err(DbErr::RecordNotUpdated);
+        }
+
+        let mut versus_matches = self.versus_matches.write();
+        let Some(game) = versus_matches
+            .values_mut()
+            .filter(|game| game.host != session && game.guest.is_none())
+            .filter(|game| !game.is_over() && game.created_at >= since)
+            .min_by_key(|game| game.created_at)
+        else {
+            tracing::info!("no waiting versus to join with session {session}");
+            return Ok(None);
+        };
+        game.guest = Some(session.to_owned());
+        game.turn_started_at = Utc::now().naive_utc();
+
+        tracing::info!("joined versus {} with session {session}", game.id);
+        Ok(Some(game.clone()))
+    }
+
+    async fn update_versus(&self, game: &VersusMatch) -> Result<(), DbErr> {
+        let VersusMatch { id, .. } = game;
+        tracing::info!("updating versus {id}…");
+
+        let mut versus_matches = self.versus_matches.write();
+        let Some(stored) = versus_matches.get_mut(id) else {
+            return Err(DbErr::RecordNotUpdated);
+        };
+        stored.submit_history = game.submit_history.clone();
+        stored.winner = game.winner.clone();
+        stored.turn_started_at = game.turn_started_at;
+        stored.finished_at = game.finished_at;
+
+        tracing::info!("updated versus {id}");
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl SessionRepo for MemoryStore {
+    async fn insert_or_update_session(&self, session: &str) -> Result<(), DbErr> {
+        tracing::info!("inserting or updating session {session}…");
+        let now = Utc::now().naive_utc();
+        self.sessions
+            .write()
+            .entry(session.to_owned())
+            .and_modify(|stored| stored.updated_at = now)
+            .or_insert_with(|| Session {
+                session: session.to_owned(),
+                created_at: now,
+                updated_at: now,
+            });
+
+        tracing::info!("inserted or updated session {session} at {now}");
+        Ok(())
+    }
+
+    async fn delete_session(&self, session: &str) -> Result<(), DbErr> {
+        self.sessions.write().remove(session);
+        self.histories
+            .write()
+            .retain(|(_, stored), _| stored != session);
+        self.practices
+            .write()
+            .retain(|_, stored| stored.session != session);
+        self.board_histories
+            .write()
+            .retain(|(_, _, stored), _| stored != session);
+        self.absurdles
+            .write()
+            .retain(|_, stored| stored.session != session);
+        let mut custom_puzzles = self.custom_puzzles.write();
+        custom_puzzles.retain(|_, stored| stored.session != session);
+        self.custom_histories
+            .write()
+            .retain(|(puzzle, stored), _| stored != session && custom_puzzles.contains_key(puzzle));
+        self.versus_matches
+            .write()
+            .retain(|_, stored| !stored.is_player(session));
+        let mut groups = self.groups.write();
+        groups.retain(|_, stored| stored.owner != session);
+        self.group_members
+            .write()
+            .retain(|(group, stored), _| stored != session && groups.contains_key(group));
+
+        tracing::info!("deleted session {session}");
+        Ok(())
+    }
+}
@@ -6,13 +6,15 @@ use crate::env::DATABASE_URL;
 
 use api_framework::static_lazy_lock;
 use migration::{Migrator, MigratorTrait as _};
-use sea_orm::{ConnectOptions, Database, DatabaseConnection, DbErr};
+use sea_orm::{ConnectOptions, Database, DatabaseConnection, DbBackend, DbErr};
 use tracing::log::LevelFilter;
 
+pub mod memory;
+pub mod repo;
 pub mod tables;
 
 static_lazy_lock! {
-    /// The connect options for PostgreSQL.
+    /// The connect options for the database at [`DATABASE_URL`].
     pub OPTIONS: ConnectOptions = {
         let mut options = ConnectOptions::new(&*DATABASE_URL);
         options.max_connections(100)
@@ -24,45 +26,47 @@ static_lazy_lock! {
     };
 }
 
-/// Acquires a database connection, or runs an expression.
+/// Returns the [`DbBackend`] selected by the scheme of a database connection URL, which is
+/// `postgres://` for PostgreSQL and `sqlite://` for SQLite.
 ///
-/// See: [`acquire`]
-#[macro_export]
-macro_rules! acquire_or {
-    (|$name:ident| $expr:expr) => {
-        match $crate::database::acquire().await {
-            Ok(db) => db,
-            Err($name) => $expr,
+/// # Errors
+///
+/// Returns a [`DbErr`] if the scheme is unknown, or if the backend is not enabled in this build.
+pub fn backend_of(url: &str) -> Result<DbBackend, DbErr> {
+    let scheme = url.split_once(':').map_or(url, |(scheme, _)| scheme);
+    let (backend, feature, enabled) = match scheme {
+        "postgres" | "postgresql" => (DbBackend::Postgres, "postgres", cfg!(feature = "postgres")),
+        "sqlite" => (DbBackend::Sqlite, "sqlite", cfg!(feature = "sqlite")),
+        _ => {
+            return Err(DbErr::Custom(format!(
+                "unsupported database scheme `{scheme}`"
+            )));
         }
     };
-}
 
-pub use acquire_or;
-
-/// Acquires a database connection, or responses with a 500 Internal Server Error and the corresponding error description.
-///
-/// See: [`acquire`], [`acquire_or`]
-#[macro_export]
-macro_rules! acquire_or_response {
-    () => {
-        $crate::database::acquire_or!(|err| return (
-            axum::http::StatusCode::INTERNAL_SERVER_ERROR,
-            err.to_string()
-        )
-            .into_response())
-    };
+    if enabled {
+        Ok(backend)
+    } else {
+        Err(DbErr::Custom(format!(
+            "database scheme `{scheme}` requires the `{feature}` feature"
+        )))
+    }
 }
 
-pub use acquire_or_response;
-
-/// Sets up the database and run necessary migrations.
+/// Sets up the database and run necessary migrations, returning the connection.
 ///
 /// # Errors
 ///
 /// Returns a [`DbErr`] if the setup process fails.
-pub async fn setup() -> Result<(), DbErr> {
+///
+/// See: [`backend_of`]
+pub async fn setup() -> Result<DatabaseConnection, DbErr> {
+    let backend = backend_of(&DATABASE_URL)?;
+    tracing::info!("using {backend:?} database backend");
+
     let db = acquire().await?;
-    Migrator::up(&db, None).await
+    Migrator::up(&db, None).await?;
+    Ok(db)
 }
 
 /// Acquires a database connection.
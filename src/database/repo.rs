@@ -0,0 +1,1151 @@
+//! The repositories abstracting over the storage.
+//!
+//! The handlers access the storage only through these traits, so the same endpoints can be served
+//! from a database or from [`MemoryStore`].
+
+use crate::{
+    anticheat::{CheatLimits, first_guess_window_start},
+    database::memory::MemoryStore,
+    live::{LIVE, LiveEvent, LiveOutbox},
+};
+
+use std::{
+    fmt::{Debug, Display},
+    sync::Arc,
+};
+
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDateTime, TimeDelta, Utc};
+use entity::{
+    BoardSolutions, BoardVariant, BoardsHistory, CheatFlag, GameState, HISTORY_MAX_TRIES, Hint,
+    Hints, PuzzleDate, PuzzleSolution, SubmitHistory, SubmitWord, Timing,
+    absurdles::Model as AbsurdleGame, board_histories::Model as BoardHistory,
+    board_puzzles::Model as BoardPuzzle, custom_histories::Model as CustomHistory,
+    custom_puzzles::Model as CustomPuzzle, first_guess_counts::Model as FirstGuessCount,
+    group_members::Model as GroupMember, groups::Model as Group, histories::Model as History,
+    practices::Model as Practice, puzzles::Model as Puzzle, result_counts::Model as ResultCount,
+    versus_matches::Model as VersusMatch,
+};
+use sea_orm::{DatabaseConnection, DbErr};
+
+/// The repository of puzzles.
+#[async_trait]
+pub trait PuzzleRepo: Debug + Send + Sync {
+    /// Gets all puzzle dates.
+    async fn get_dates(&self) -> Vec<PuzzleDate>;
+
+    /// Gets all puzzles.
+    async fn get_puzzles(&self) -> Vec<Puzzle>;
+
+    /// Gets a puzzle by date.
+    async fn get_puzzle(&self, date: &PuzzleDate) -> Option<Puzzle>;
+
+    /// Gets the puzzles before a date whose difficulty has not been blended with the play data.
+    async fn get_unblended_puzzles(&self, before: &PuzzleDate) -> Vec<Puzzle>;
+
+    /// Inserts a puzzle solution with its predicted difficulty for a given date, replacing the
+    /// existing one.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DbErr`] if the insertion fails.
+    async fn insert_solution(
+        &self,
+        date: &PuzzleDate,
+        solution: &PuzzleSolution,
+        difficulty: Option<f64>,
+    ) -> Result<(), DbErr>;
+
+    /// Stores the predicted difficulty of a puzzle, unless its solution has been replaced or its
+    /// difficulty has already been blended with the play data.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DbErr`] if the update fails.
+    async fn update_predicted_difficulty(
+        &self,
+        date: &PuzzleDate,
+        solution: &PuzzleSolution,
+        difficulty: f64,
+    ) -> Result<(), DbErr>;
+
+    /// Stores the difficulty of a puzzle blended with the play data.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DbErr`] if the puzzle is missing or the update fails.
+    async fn update_difficulty(&self, date: &PuzzleDate, difficulty: f64) -> Result<(), DbErr>;
+}
+
+/// A page of the puzzles joined with the histories of a session, latest date first.
+#[derive(Debug, Clone, Default)]
+pub struct ArchivePage {
+    /// The total number of puzzles across all pages.
+    pub total: u64,
+    /// The puzzles of the page, each with the history of the session if started.
+    pub entries: Vec<(Puzzle, Option<History>)>,
+}
+
+/// The result for submitting a word to history.
+#[derive(Debug, Clone)]
+pub struct SubmitResult {
+    /// The updated submit history.
+    pub submit_history: SubmitHistory,
+    /// The game state after the submission.
+    pub state: GameState,
+    /// The solution of the puzzle, which should only be revealed if
+    /// [`GameState::reveals_solution`].
+    pub solution: PuzzleSolution,
+    /// The hints used.
+    pub hints: Hints,
+    /// The server-side timing, which is missing for games that are not timed.
+    pub timing: Option<Timing>,
+}
+
+/// The errors that can occur when submitting a word to history.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum SubmitError {
+    /// The game is over and accepts no further submissions. Contains the final result.
+    GameOver(SubmitResult),
+    /// The storage failed.
+    Db(DbErr),
+}
+
+impl Display for SubmitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::GameOver(result) => write!(f, "the game is over: {}", result.state),
+            Self::Db(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for SubmitError {}
+
+impl From<DbErr> for SubmitError {
+    fn from(value: DbErr) -> Self {
+        Self::Db(value)
+    }
+}
+
+/// The errors that can occur when using a hint.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum HintError {
+    /// The game is over and accepts no further hints.
+    GameOver(GameState),
+    /// All hints allowed for the puzzle have been used.
+    Exhausted(Hints),
+    /// Every letter is already matched or revealed.
+    NothingToReveal(Hints),
+    /// The storage failed.
+    Db(DbErr),
+}
+
+impl Display for HintError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::GameOver(state) => write!(f, "the game is over: {state}"),
+            Self::Exhausted(hints) => write!(f, "all {} hints have been used", hints.len()),
+            Self::NothingToReveal(_) => write!(f, "every letter is already known"),
+            Self::Db(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for HintError {}
+
+impl From<DbErr> for HintError {
+    fn from(value: DbErr) -> Self {
+        Self::Db(value)
+    }
+}
+
+/// The repository of the aggregate results of puzzles, materialised as games finish.
+#[async_trait]
+pub trait ResultRepo: Debug + Send + Sync {
+    /// Gets the counts of the finished games of a date by outcome and number of guesses.
+    async fn get_result_counts(&self, date: &PuzzleDate) -> Vec<ResultCount>;
+
+    /// Gets the most common first guesses of the finished games of a date, most common first.
+    async fn get_first_guess_counts(&self, date: &PuzzleDate, limit: usize)
+    -> Vec<FirstGuessCount>;
+
+    /// Counts a finished game of a date with its first guess.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DbErr`] if the puzzle is missing or the update fails.
+    async fn record_result(
+        &self,
+        date: &PuzzleDate,
+        state: GameState,
+        tries: usize,
+        first_guess: Option<&SubmitWord>,
+    ) -> Result<(), DbErr>;
+
+    /// Uncounts a finished game of a date with its first guess, once it is flagged after being
+    /// counted.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DbErr`] if the update fails.
+    async fn discount_result(
+        &self,
+        date: &PuzzleDate,
+        state: GameState,
+        tries: usize,
+        first_guess: Option<&SubmitWord>,
+    ) -> Result<(), DbErr>;
+}
+
+/// The repository of histories, which also materialises the results of the games it finishes.
+#[async_trait]
+pub trait HistoryRepo: ResultRepo + Debug + Send + Sync {
+    /// Gets a history by date and session.
+    async fn get_history(&self, date: &PuzzleDate, session: &str) -> Option<History>;
+
+    /// Gets all histories of a date.
+    async fn get_histories(&self, date: &PuzzleDate) -> Vec<History>;
+
+    /// Gets the histories of some sessions since a date, included.
+    async fn get_session_histories(&self, sessions: &[String], since: &PuzzleDate) -> Vec<History>;
+
+    /// Gets the histories of a date last submitted from an address.
+    async fn get_address_histories(&self, date: &PuzzleDate, address: &str) -> Vec<History>;
+
+    /// Gets all histories flagged as suspected of cheating, latest date first.
+    async fn get_flagged_histories(&self) -> Vec<History>;
+
+    /// Gets a page of the puzzles with the histories of a session, latest date first, where pages
+    /// are numbered from 0.
+    async fn get_archive(&self, session: &str, page: u64, per_page: u64) -> ArchivePage;
+
+    /// Creates a new history, timed from its start.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DbErr`] if the insertion fails.
+    async fn create_history(
+        &self,
+        date: &PuzzleDate,
+        session: &str,
+        solution: &PuzzleSolution,
+        timing: &Timing,
+    ) -> Result<(), DbErr>;
+
+    /// Updates the mutable columns of an existing history.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DbErr`] if the update fails.
+    async fn update_history(&self, history: &History) -> Result<(), DbErr>;
+
+    /// Submits a word to history, recording the time and the hashed address of the submission. A
+    /// submission missing the deadline of a time attack is not tinted and loses the game instead. A
+    /// won game suspected of cheating is flagged and left out of the aggregate results, together
+    /// with the games of the other sessions sharing its address if that is the suspicion.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SubmitError::GameOver`] if the game is no longer in progress or the deadline is
+    /// missed, or [`SubmitError::Db`] if the history is missing or the submission fails.
+    async fn submit_to_history(
+        &self,
+        date: &PuzzleDate,
+        session: &str,
+        answer: &PuzzleSolution,
+        address: &str,
+    ) -> Result<SubmitResult, SubmitError> {
+        tracing::info!("submitting {answer} to history at {date} with {session}…");
+        let mut outbox = LIVE.outbox(date).await;
+        let Some(history) = self.get_history(date, session).await else {
+            return Err(missing_history(date, session).into());
+        };
+
+        let result = submit(self, history, answer, address, &mut outbox).await;
+        outbox.publish();
+        result
+    }
+
+    /// Reveals a letter of the solution and records it as a hint in history.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HintError::GameOver`] if the game is no longer in progress,
+    /// [`HintError::Exhausted`] or [`HintError::NothingToReveal`] if no hint can be given, or
+    /// [`HintError::Db`] if the history is missing or the update fails.
+    ///
+    /// See: [`SubmitWord::hint`]
+    async fn use_hint(&self, date: &PuzzleDate, session: &str) -> Result<(Hint, Hints), HintError> {
+        tracing::info!("using hint at {date} with {session}…");
+        let mut outbox = LIVE.outbox(date).await;
+        let Some(history) = self.get_history(date, session).await else {
+            return Err(missing_history(date, session).into());
+        };
+
+        let result = reveal_hint(self, history, &mut outbox).await;
+        outbox.publish();
+        result
+    }
+}
+
+/// Returns the error for a session submitting to a date it has no history for.
+pub(crate) fn missing_history(date: &PuzzleDate, session: &str) -> DbErr {
+    tracing::error!("no history found for {date} with session {session}!");
+    DbErr::Custom(format!("session {session} has no history"))
+}
+
+/// Records the loss of a history that has missed the deadline of its time attack.
+///
+/// # Errors
+///
+/// Returns [`DbErr`] if the update fails.
+///
+/// See: [`History::is_overdue`]
+pub(crate) async fn expire<R>(
+    repo: &R,
+    history: &mut History,
+    now: DateTime<Utc>,
+    outbox: &mut LiveOutbox<'_>,
+) -> Result<(), DbErr>
+where
+    R: HistoryRepo + ?Sized,
+{
+    let History { date, session, .. } = &*history;
+    tracing::warn!("deadline missed for {date} with session {session}!");
+    if let Some(timing) = history.timing.as_mut() {
+        timing.complete(now);
+    }
+    history.game_state = Some(GameState::Lost);
+    repo.update_history(history).await?;
+
+    let date = &history.date;
+    outbox.push(LiveEvent::Lost);
+    let submit_history = history.submit_history.clone().unwrap_or_default();
+    repo.record_result(
+        date,
+        GameState::Lost,
+        result_tries(GameState::Lost, &submit_history),
+        submit_history.0.first(),
+    )
+    .await
+}
+
+/// Returns the tries a finished game counts for in the aggregate results, which include the cost
+/// of the hints if it is won.
+///
+/// See: [`Hints::charge`]
+fn result_tries(state: GameState, submit_history: &SubmitHistory) -> usize {
+    match state {
+        GameState::Won { tries } => tries,
+        _ => submit_history.len(),
+    }
+}
+
+/// Submits a word to a history read by the repository, recording the time and address of the
+/// submission.
+///
+/// # Errors
+///
+/// See: [`HistoryRepo::submit_to_history`]
+pub(crate) async fn submit<R>(
+    repo: &R,
+    mut history: History,
+    answer: &PuzzleSolution,
+    address: &str,
+    outbox: &mut LiveOutbox<'_>,
+) -> Result<SubmitResult, SubmitError>
+where
+    R: HistoryRepo + ?Sized,
+{
+    let date = history.date.clone();
+    let session = history.session.clone();
+    let now = Utc::now();
+
+    if history.is_overdue(now) {
+        expire(repo, &mut history, now, outbox).await?;
+    }
+
+    let state = history.game_state_at(now);
+    if state.is_over() {
+        tracing::warn!("game is {state} for {date} with session {session}!");
+        return Err(SubmitError::GameOver(SubmitResult {
+            submit_history: history.submit_history.unwrap_or_default(),
+            state,
+            solution: history.solution,
+            hints: history.hints.unwrap_or_default(),
+            timing: history.timing,
+        }));
+    }
+
+    let word = SubmitWord::tint(answer, &history.solution);
+    let mut submit_history = history.submit_history.take().unwrap_or_default();
+    submit_history
+        .submit(word)
+        .map_err(|e| DbErr::Custom(e.to_string()))?;
+
+    let state = history
+        .hints
+        .clone()
+        .unwrap_or_default()
+        .charge(submit_history.game_state(), HISTORY_MAX_TRIES);
+    if let Some(timing) = history.timing.as_mut() {
+        timing.submit(now, state);
+    }
+    history.submit_history = Some(submit_history.clone());
+    history.game_state = Some(state);
+    history.is_completed = state.is_won();
+    history.address = Some(address.to_owned());
+    if let GameState::Won { .. } = state {
+        let session_histories = repo
+            .get_session_histories(
+                std::slice::from_ref(&session),
+                &first_guess_window_start(&date),
+            )
+            .await;
+        let address_histories = repo.get_address_histories(&date, address).await;
+        let limits = CheatLimits::from_env();
+        let flags = limits.inspect(&history, &session_histories, &address_histories);
+        if flags.contains(CheatFlag::SharedAddress) {
+            for accomplice in limits.accomplices(&history, &address_histories) {
+                flag_shared_address(repo, accomplice.clone()).await?;
+            }
+        }
+        if !flags.is_empty() {
+            tracing::warn!("flagged history for {date} with session {session}: {flags:?}");
+            history.flags = Some(flags);
+        }
+    }
+    repo.update_history(&history).await?;
+    if !history.is_flagged() {
+        match state {
+            GameState::Won { tries } => outbox.push(LiveEvent::Won { tries }),
+            GameState::Lost => outbox.push(LiveEvent::Lost),
+            _ => {}
+        }
+        if state.is_over() {
+            let tries = result_tries(state, &submit_history);
+            repo.record_result(&date, state, tries, submit_history.0.first())
+                .await?;
+        }
+    }
+
+    tracing::info!("submitted {answer} to history at {date} with session {session}: {state}");
+    Ok(SubmitResult {
+        submit_history,
+        state,
+        solution: history.solution,
+        hints: history.hints.unwrap_or_default(),
+        timing: history.timing,
+    })
+}
+
+/// Flags another game won from the address of a game flagged as [`CheatFlag::SharedAddress`],
+/// leaving it out of the aggregate results unless it was already.
+///
+/// # Errors
+///
+/// Returns [`DbErr`] if the update fails.
+async fn flag_shared_address<R>(repo: &R, mut history: History) -> Result<(), DbErr>
+where
+    R: HistoryRepo + ?Sized,
+{
+    let mut flags = history.flags.take().unwrap_or_default();
+    if flags.contains(CheatFlag::SharedAddress) {
+        return Ok(());
+    }
+    let was_counted = flags.is_empty();
+    flags.raise(CheatFlag::SharedAddress);
+    tracing::warn!(
+        "flagged history for {} with session {}: {flags:?}",
+        history.date,
+        history.session
+    );
+    history.flags = Some(flags);
+    repo.update_history(&history).await?;
+
+    let state = history.game_state();
+    if was_counted && state.is_over() {
+        let submit_history = history.submit_history.unwrap_or_default();
+        repo.discount_result(
+            &history.date,
+            state,
+            result_tries(state, &submit_history),
+            submit_history.0.first(),
+        )
+        .await?;
+    }
+    Ok(())
+}
+
+/// Reveals a letter of the solution and records it as a hint in a history read by the repository.
+///
+/// # Errors
+///
+/// See: [`HistoryRepo::use_hint`]
+pub(crate) async fn reveal_hint<R>(
+    repo: &R,
+    mut history: History,
+    outbox: &mut LiveOutbox<'_>,
+) -> Result<(Hint, Hints), HintError>
+where
+    R: HistoryRepo + ?Sized,
+{
+    let date = history.date.clone();
+    let session = history.session.clone();
+    let now = Utc::now();
+    if history.is_overdue(now) {
+        expire(repo, &mut history, now, outbox).await?;
+    }
+
+    let state = history.game_state_at(now);
+    if state.is_over() {
+        tracing::warn!("game is {state} for {date} with session {session}!");
+        return Err(HintError::GameOver(state));
+    }
+
+    let mut hints = history.hints.take().unwrap_or_default();
+    if hints.is_full() {
+        tracing::warn!("hints are exhausted for {date} with session {session}!");
+        return Err(HintError::Exhausted(hints));
+    }
+
+    let submit_history = history.submit_history.clone().unwrap_or_default();
+    let Some(hint) = SubmitWord::hint(&history.solution, &submit_history, &hints) else {
+        tracing::warn!("nothing to reveal for {date} with session {session}!");
+        return Err(HintError::NothingToReveal(hints));
+    };
+    hints.push(hint).map_err(|e| DbErr::Custom(e.to_string()))?;
+
+    history.hints = Some(hints.clone());
+    repo.update_history(&history).await?;
+
+    tracing::info!("used hint {hint} at {date} with session {session}");
+    Ok((hint, hints))
+}
+
+/// The repository of practices, which are kept apart from the daily histories.
+#[async_trait]
+pub trait PracticeRepo: Debug + Send + Sync {
+    /// Gets a practice by id, if owned by the session.
+    async fn get_practice(&self, id: &str, session: &str) -> Option<Practice>;
+
+    /// Creates a new practice.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DbErr`] if the insertion fails.
+    async fn create_practice(
+        &self,
+        id: &str,
+        session: &str,
+        solution: &PuzzleSolution,
+    ) -> Result<Practice, DbErr>;
+
+    /// Updates the submit history of an existing practice.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DbErr`] if the update fails.
+    async fn update_practice(&self, practice: &Practice) -> Result<(), DbErr>;
+
+    /// Submits a word to a practice read by the caller. Storages that lock rows read it again
+    /// under the lock, so concurrent submissions never lose a guess.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SubmitError::GameOver`] if the practice is no longer in progress, or
+    /// [`SubmitError::Db`] if the submission fails.
+    async fn submit_to_practice(
+        &self,
+        practice: Practice,
+        answer: &PuzzleSolution,
+    ) -> Result<SubmitResult, SubmitError> {
+        tracing::info!(
+            "submitting {answer} to practice {} with {}…",
+            practice.id,
+            practice.session
+        );
+        submit_practice(self, practice, answer).await
+    }
+}
+
+/// Submits a word to a practice read by the repository.
+///
+/// # Errors
+///
+/// See: [`PracticeRepo::submit_to_practice`]
+pub(crate) async fn submit_practice<R>(
+    repo: &R,
+    mut practice: Practice,
+    answer: &PuzzleSolution,
+) -> Result<SubmitResult, SubmitError>
+where
+    R: PracticeRepo + ?Sized,
+{
+    let id = practice.id.clone();
+    let session = practice.session.clone();
+    let state = practice.game_state();
+    if state.is_over() {
+        tracing::warn!("game is {state} for practice {id} with session {session}!");
+        return Err(SubmitError::GameOver(SubmitResult {
+            submit_history: practice.submit_history.unwrap_or_default(),
+            state,
+            solution: practice.solution,
+            hints: Hints::default(),
+            timing: None,
+        }));
+    }
+
+    let word = SubmitWord::tint(answer, &practice.solution);
+    let mut submit_history = practice.submit_history.take().unwrap_or_default();
+    submit_history
+        .submit(word)
+        .map_err(|e| DbErr::Custom(e.to_string()))?;
+
+    let state = submit_history.game_state();
+    practice.submit_history = Some(submit_history.clone());
+    repo.update_practice(&practice).await?;
+
+    tracing::info!("submitted {answer} to practice {id} with session {session}: {state}");
+    Ok(SubmitResult {
+        submit_history,
+        state,
+        solution: practice.solution,
+        hints: Hints::default(),
+        timing: None,
+    })
+}
+
+/// The repository of custom puzzles created by players and their histories.
+#[async_trait]
+pub trait CustomRepo: Debug + Send + Sync {
+    /// Gets a custom puzzle by id.
+    async fn get_custom_puzzle(&self, id: &str) -> Option<CustomPuzzle>;
+
+    /// Creates a new custom puzzle owned by its creator.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DbErr`] if the insertion fails.
+    async fn create_custom_puzzle(
+        &self,
+        id: &str,
+        session: &str,
+        max_tries: usize,
+    ) -> Result<CustomPuzzle, DbErr>;
+
+    /// Gets a custom history by puzzle id and session.
+    async fn get_custom_history(&self, puzzle: &str, session: &str) -> Option<CustomHistory>;
+
+    /// Gets all histories of a custom puzzle.
+    async fn get_custom_histories(&self, puzzle: &str) -> Vec<CustomHistory>;
+
+    /// Inserts a custom history, or updates its submit history if it exists.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DbErr`] if the insertion fails.
+    async fn insert_or_update_custom_history(&self, history: &CustomHistory) -> Result<(), DbErr>;
+}
+
+/// The repository of friends groups and their members.
+#[async_trait]
+pub trait GroupRepo: Debug + Send + Sync {
+    /// Gets a group by id.
+    async fn get_group(&self, id: &str) -> Option<Group>;
+
+    /// Gets a group by its invite code.
+    async fn get_group_by_invite_code(&self, invite_code: &str) -> Option<Group>;
+
+    /// Creates a new group owned by the session, which joins it under a nickname.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DbErr`] if the insertion fails.
+    async fn create_group(
+        &self,
+        id: &str,
+        name: &str,
+        invite_code: &str,
+        session: &str,
+        nickname: &str,
+    ) -> Result<Group, DbErr>;
+
+    /// Gets all members of a group.
+    async fn get_group_members(&self, id: &str) -> Vec<GroupMember>;
+
+    /// Inserts a member of a group, or updates its nickname if it exists.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DbErr`] if the insertion fails.
+    async fn insert_or_update_group_member(&self, member: &GroupMember) -> Result<(), DbErr>;
+}
+
+/// The repository of Absurdle games, which are kept apart from the daily histories.
+#[async_trait]
+pub trait AbsurdleRepo: Debug + Send + Sync {
+    /// Gets an Absurdle game by id, if owned by the session.
+    async fn get_absurdle(&self, id: &str, session: &str) -> Option<AbsurdleGame>;
+
+    /// Creates a new Absurdle game.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DbErr`] if the insertion fails.
+    async fn create_absurdle(&self, id: &str, session: &str) -> Result<AbsurdleGame, DbErr>;
+
+    /// Updates the submit history of an existing Absurdle game.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DbErr`] if the update fails.
+    async fn update_absurdle(&self, game: &AbsurdleGame) -> Result<(), DbErr>;
+}
+
+/// The errors that can occur when submitting a word to a versus match.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum VersusError {
+    /// The session plays no such match.
+    NotFound,
+    /// The match is still waiting for a guest in the lobby.
+    Waiting,
+    /// It is the turn of the opponent.
+    NotYourTurn,
+    /// The match is over and accepts no further words. Contains the final match.
+    GameOver(VersusMatch),
+    /// The storage failed.
+    Db(DbErr),
+}
+
+impl Display for VersusError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotFound => write!(f, "no such match"),
+            Self::Waiting => write!(f, "the match is waiting for an opponent"),
+            Self::NotYourTurn => write!(f, "it is the turn of the opponent"),
+            Self::GameOver(_) => write!(f, "the match is over"),
+            Self::Db(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for VersusError {}
+
+impl From<DbErr> for VersusError {
+    fn from(value: DbErr) -> Self {
+        Self::Db(value)
+    }
+}
+
+/// The repository of head-to-head versus matches, which are kept apart from the daily histories.
+#[async_trait]
+pub trait VersusRepo: Debug + Send + Sync {
+    /// Gets a versus match by id, if played by the session.
+    async fn get_versus(&self, id: &str, session: &str) -> Option<VersusMatch>;
+
+    /// Gets the latest match hosted by the session still waiting in the lobby, if queued since a
+    /// given time.
+    async fn get_waiting_versus(&self, session: &str, since: NaiveDateTime) -> Option<VersusMatch>;
+
+    /// Creates a new match hosted by the session, waiting in the lobby for a guest.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DbErr`] if the insertion fails.
+    async fn create_versus(
+        &self,
+        id: &str,
+        session: &str,
+        solution: &PuzzleSolution,
+    ) -> Result<VersusMatch, DbErr>;
+
+    /// Joins the session as the guest of the oldest match hosted by someone else, if queued since a
+    /// given time. Each waiting match is claimed by a single guest, which starts the first turn.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DbErr`] if the update fails.
+    async fn join_waiting_versus(
+        &self,
+        session: &str,
+        since: NaiveDateTime,
+    ) -> Result<Option<VersusMatch>, DbErr>;
+
+    /// Updates the mutable columns of an existing match.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DbErr`] if the update fails.
+    async fn update_versus(&self, game: &VersusMatch) -> Result<(), DbErr>;
+
+    /// Gets a versus match by id, forfeiting the turn of an idle player first.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DbErr`] if the forfeit cannot be stored.
+    ///
+    /// See: [`VersusMatch::forfeit_idle_turn`]
+    async fn settle_versus(
+        &self,
+        id: &str,
+        session: &str,
+        turn_timeout: TimeDelta,
+    ) -> Result<Option<VersusMatch>, DbErr> {
+        let Some(game) = self.get_versus(id, session).await else {
+            return Ok(None);
+        };
+
+        settle(self, game, turn_timeout).await.map(Some)
+    }
+
+    /// Submits a word to the shared history of a versus match on the turn of the session. The turn
+    /// passes to the opponent, unless the word wins the match.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VersusError::NotFound`] if the session plays no such match,
+    /// [`VersusError::Waiting`] or [`VersusError::NotYourTurn`] if the session cannot play now,
+    /// [`VersusError::GameOver`] if the match is over or the turn has timed out, or
+    /// [`VersusError::Db`] if the submission fails.
+    async fn submit_to_versus(
+        &self,
+        id: &str,
+        session: &str,
+        answer: &PuzzleSolution,
+        turn_timeout: TimeDelta,
+    ) -> Result<VersusMatch, VersusError> {
+        tracing::info!("submitting {answer} to versus {id} with {session}…");
+        let Some(game) = self.get_versus(id, session).await else {
+            tracing::warn!("no versus {id} found with session {session}!");
+            return Err(VersusError::NotFound);
+        };
+
+        submit_versus(self, game, session, answer, turn_timeout).await
+    }
+}
+
+/// Forfeits the turn of an idle player of a match read by the repository.
+///
+/// # Errors
+///
+/// See: [`VersusRepo::settle_versus`]
+pub(crate) async fn settle<R>(
+    repo: &R,
+    mut game: VersusMatch,
+    turn_timeout: TimeDelta,
+) -> Result<VersusMatch, DbErr>
+where
+    R: VersusRepo + ?Sized,
+{
+    if game.forfeit_idle_turn(Utc::now().naive_utc(), turn_timeout) {
+        tracing::warn!(
+            "turn timed out for versus {}, won by {:?}!",
+            game.id,
+            game.winner
+        );
+        repo.update_versus(&game).await?;
+    }
+    Ok(game)
+}
+
+/// Submits a word to a match read by the repository on the turn of the session.
+///
+/// # Errors
+///
+/// See: [`VersusRepo::submit_to_versus`]
+pub(crate) async fn submit_versus<R>(
+    repo: &R,
+    game: VersusMatch,
+    session: &str,
+    answer: &PuzzleSolution,
+    turn_timeout: TimeDelta,
+) -> Result<VersusMatch, VersusError>
+where
+    R: VersusRepo + ?Sized,
+{
+    let mut game = settle(repo, game, turn_timeout).await?;
+    let id = game.id.clone();
+    if game.is_over() {
+        tracing::warn!("versus {id} is over for session {session}!");
+        return Err(VersusError::GameOver(game));
+    }
+    match game.turn() {
+        None => return Err(VersusError::Waiting),
+        Some(turn) if turn != session => return Err(VersusError::NotYourTurn),
+        Some(_) => {}
+    }
+
+    let now = Utc::now().naive_utc();
+    let word = SubmitWord::tint(answer, &game.solution);
+    let mut submit_history = game.submit_history.take().unwrap_or_default();
+    submit_history
+        .submit(word)
+        .map_err(|e| DbErr::Custom(e.to_string()))?;
+
+    let state = submit_history.game_state();
+    game.submit_history = Some(submit_history);
+    game.turn_started_at = now;
+    if state.is_over() {
+        game.finished_at = Some(now);
+        if matches!(state, GameState::Won { .. }) {
+            game.winner = Some(session.to_owned());
+        }
+    }
+    repo.update_versus(&game).await?;
+
+    tracing::info!("submitted {answer} to versus {id} with session {session}: {state}");
+    Ok(game)
+}
+
+/// The result for submitting a guess to a multi-board history.
+#[derive(Debug, Clone)]
+pub struct BoardsSubmitResult {
+    /// The updated submit history.
+    pub submit_history: BoardsHistory,
+    /// The game state after the submission.
+    pub state: GameState,
+    /// The solutions of the boards, which should only be revealed once solved or if
+    /// [`GameState::reveals_solution`].
+    pub solutions: BoardSolutions,
+}
+
+/// The errors that can occur when submitting a guess to a multi-board history.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum BoardsSubmitError {
+    /// The game is over and accepts no further guesses. Contains the final result.
+    GameOver(BoardsSubmitResult),
+    /// The storage failed.
+    Db(DbErr),
+}
+
+impl Display for BoardsSubmitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::GameOver(result) => write!(f, "the game is over: {}", result.state),
+            Self::Db(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for BoardsSubmitError {}
+
+impl From<DbErr> for BoardsSubmitError {
+    fn from(value: DbErr) -> Self {
+        Self::Db(value)
+    }
+}
+
+/// The repository of multi-board puzzles and their histories.
+#[async_trait]
+pub trait BoardRepo: Debug + Send + Sync {
+    /// Gets a multi-board puzzle by date and variant.
+    async fn get_board_puzzle(
+        &self,
+        date: &PuzzleDate,
+        variant: BoardVariant,
+    ) -> Option<BoardPuzzle>;
+
+    /// Inserts the solutions of a multi-board puzzle for a given date and variant, replacing the
+    /// existing ones.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DbErr`] if the insertion fails.
+    async fn insert_board_solutions(
+        &self,
+        date: &PuzzleDate,
+        variant: BoardVariant,
+        solutions: &BoardSolutions,
+    ) -> Result<(), DbErr>;
+
+    /// Gets a multi-board history by date, variant and session.
+    async fn get_board_history(
+        &self,
+        date: &PuzzleDate,
+        variant: BoardVariant,
+        session: &str,
+    ) -> Option<BoardHistory>;
+
+    /// Creates a new multi-board history.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DbErr`] if the insertion fails.
+    async fn create_board_history(
+        &self,
+        date: &PuzzleDate,
+        variant: BoardVariant,
+        session: &str,
+        solutions: &BoardSolutions,
+    ) -> Result<(), DbErr>;
+
+    /// Updates the submit history of an existing multi-board history.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DbErr`] if the update fails.
+    async fn update_board_history(&self, history: &BoardHistory) -> Result<(), DbErr>;
+
+    /// Submits a guess to every board of a multi-board history.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BoardsSubmitError::GameOver`] if the game is no longer in progress, or
+    /// [`BoardsSubmitError::Db`] if the history is missing or the submission fails.
+    async fn submit_to_board_history(
+        &self,
+        date: &PuzzleDate,
+        variant: BoardVariant,
+        session: &str,
+        answer: &PuzzleSolution,
+    ) -> Result<BoardsSubmitResult, BoardsSubmitError> {
+        tracing::info!("submitting {answer} to {variant} history at {date} with {session}…");
+        let Some(history) = self.get_board_history(date, variant, session).await else {
+            return Err(missing_board_history(date, variant, session).into());
+        };
+
+        submit_boards(self, history, answer).await
+    }
+}
+
+/// Returns the error for a session submitting to a multi-board date it has no history for.
+pub(crate) fn missing_board_history(
+    date: &PuzzleDate,
+    variant: BoardVariant,
+    session: &str,
+) -> DbErr {
+    tracing::error!("no {variant} history found for {date} with session {session}!");
+    DbErr::Custom(format!("session {session} has no history"))
+}
+
+/// Submits a guess to every board of a multi-board history read by the repository.
+///
+/// # Errors
+///
+/// See: [`BoardRepo::submit_to_board_history`]
+pub(crate) async fn submit_boards<R>(
+    repo: &R,
+    mut history: BoardHistory,
+    answer: &PuzzleSolution,
+) -> Result<BoardsSubmitResult, BoardsSubmitError>
+where
+    R: BoardRepo + ?Sized,
+{
+    let (date, variant, session) = (
+        history.date.clone(),
+        history.variant,
+        history.session.clone(),
+    );
+    let mut submit_history = history.boards_history();
+    let state = submit_history.game_state();
+    if state.is_over() {
+        tracing::warn!("game is {state} for {variant} at {date} with session {session}!");
+        return Err(BoardsSubmitError::GameOver(BoardsSubmitResult {
+            submit_history,
+            state,
+            solutions: history.solutions,
+        }));
+    }
+
+    submit_history
+        .submit(answer, &history.solutions.0)
+        .map_err(|e| DbErr::Custom(e.to_string()))?;
+
+    let state = submit_history.game_state();
+    history.submit_history = Some(submit_history.clone());
+    repo.update_board_history(&history).await?;
+
+    tracing::info!(
+        "submitted {answer} to {variant} history at {date} with session {session}: {state}"
+    );
+    Ok(BoardsSubmitResult {
+        submit_history,
+        state,
+        solutions: history.solutions,
+    })
+}
+
+/// The repository of sessions.
+#[async_trait]
+pub trait SessionRepo: Debug + Send + Sync {
+    /// Inserts or updates a session.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DbErr`] if the insertion fails.
+    async fn insert_or_update_session(&self, session: &str) -> Result<(), DbErr>;
+
+    /// Deletes a session.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DbErr`] if the deletion fails.
+    async fn delete_session(&self, session: &str) -> Result<(), DbErr>;
+}
+
+/// The repositories shared by the handlers as the router state.
+#[derive(Debug, Clone)]
+pub struct Repos {
+    /// The repository of puzzles.
+    pub puzzles: Arc<dyn PuzzleRepo>,
+    /// The repository of histories.
+    pub histories: Arc<dyn HistoryRepo>,
+    /// The repository of the aggregate results of puzzles.
+    pub results: Arc<dyn ResultRepo>,
+    /// The repository of practices.
+    pub practices: Arc<dyn PracticeRepo>,
+    /// The repository of multi-board puzzles and their histories.
+    pub boards: Arc<dyn BoardRepo>,
+    /// The repository of Absurdle games.
+    pub absurdles: Arc<dyn AbsurdleRepo>,
+    /// The repository of custom puzzles.
+    pub customs: Arc<dyn CustomRepo>,
+    /// The repository of friends groups.
+    pub groups: Arc<dyn GroupRepo>,
+    /// The repository of versus matches.
+    pub versus: Arc<dyn VersusRepo>,
+    /// The repository of sessions.
+    pub sessions: Arc<dyn SessionRepo>,
+}
+
+impl Repos {
+    /// Creates the repositories backed by a database connection.
+    pub fn sea_orm(db: DatabaseConnection) -> Self {
+        let db = Arc::new(db);
+        Self {
+            puzzles: db.clone(),
+            histories: db.clone(),
+            results: db.clone(),
+            practices: db.clone(),
+            boards: db.clone(),
+            absurdles: db.clone(),
+            customs: db.clone(),
+            groups: db.clone(),
+            versus: db.clone(),
+            sessions: db,
+        }
+    }
+
+    /// Creates the repositories backed by a fresh [`MemoryStore`].
+    pub fn memory() -> Self {
+        let store = Arc::new(MemoryStore::default());
+        Self {
+            puzzles: store.clone(),
+            histories: store.clone(),
+            results: store.clone(),
+            practices: store.clone(),
+            boards: store.clone(),
+            absurdles: store.clone(),
+            customs: store.clone(),
+            groups: store.clone(),
+            versus: store.clone(),
+            sessions: store,
+        }
+    }
+}
@@ -0,0 +1,107 @@
+//! Table `absurdles`.
+
+use crate::database::repo::AbsurdleRepo;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use entity::{
+    absurdles::{self, Model as AbsurdleGame},
+    prelude::*,
+};
+use sea_orm::{
+    ActiveModelTrait as _, ActiveValue, ColumnTrait as _, DatabaseConnection, DbErr,
+    EntityTrait as _, QueryFilter as _,
+};
+
+/// Gets an Absurdle game by id, if owned by the session.
+pub async fn get_absurdle(
+    db: &DatabaseConnection,
+    id: &str,
+    session: &str,
+) -> Option<AbsurdleGame> {
+    tracing::info!("getting absurdle {id} with session {session}…");
+    let game = Absurdles::find_by_id(id.to_owned())
+        .filter(absurdles::Column::Session.eq(session.to_owned()))
+        .one(db)
+        .await
+        .ok()
+        .flatten();
+
+    match &game {
+        Some(game) => tracing::info!("got absurdle {id} with session {session}: {game}"),
+        None => tracing::warn!("no absurdle {id} found with session {session}!"),
+    }
+    game
+}
+
+/// Creates a new Absurdle game.
+///
+/// # Errors
+///
+/// Returns [`DbErr`] if the insertion fails.
+pub async fn create_absurdle(
+    db: &DatabaseConnection,
+    id: &str,
+    session: &str,
+) -> Result<AbsurdleGame, DbErr> {
+    tracing::info!("creating absurdle {id} with session {session}…");
+    let active_game = absurdles::ActiveModel {
+        id: ActiveValue::Set(id.to_owned()),
+        session: ActiveValue::Set(session.to_owned()),
+        submit_history: ActiveValue::Set(None),
+        created_at: ActiveValue::Set(Utc::now().naive_utc()),
+    };
+
+    match active_game.insert(db).await {
+        Ok(game) => {
+            tracing::info!("created absurdle {id} with session {session}");
+            Ok(game)
+        }
+        Err(err) => {
+            tracing::error!("failed to create absurdle {id} with session {session}: {err}");
+            Err(err)
+        }
+    }
+}
+
+/// Updates the submit history of an existing Absurdle game.
+///
+/// # Errors
+///
+/// Returns [`DbErr`] if the update fails.
+pub async fn update_absurdle(db: &DatabaseConnection, game: &AbsurdleGame) -> Result<(), DbErr> {
+    let AbsurdleGame { id, session, .. } = game;
+    tracing::info!("updating absurdle {id} with session {session}…");
+
+    let active_game = absurdles::ActiveModel {
+        id: ActiveValue::Unchanged(id.to_owned()),
+        submit_history: ActiveValue::Set(game.submit_history.clone()),
+        ..Default::default()
+    };
+
+    match Absurdles::update(active_game).exec(db).await {
+        Ok(_) => {
+            tracing::info!("updated absurdle {id} with session {session}");
+            Ok(())
+        }
+        Err(err) => {
+            tracing::error!("failed to update absurdle {id} with session {session}: {err}");
+            Err(err)
+        }
+    }
+}
+
+#[async_trait]
+impl AbsurdleRepo for DatabaseConnection {
+    async fn get_absurdle(&self, id: &str, session: &str) -> Option<AbsurdleGame> {
+        get_absurdle(self, id, session).await
+    }
+
+    async fn create_absurdle(&self, id: &str, session: &str) -> Result<AbsurdleGame, DbErr> {
+        create_absurdle(self, id, session).await
+    }
+
+    async fn update_absurdle(&self, game: &AbsurdleGame) -> Result<(), DbErr> {
+        update_absurdle(self, game).await
+    }
+}
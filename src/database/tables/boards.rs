@@ -0,0 +1,265 @@
+//! Tables `board_puzzles` and `board_histories`.
+
+use crate::database::{
+    repo::{self, BoardRepo, BoardsSubmitError, BoardsSubmitResult},
+    tables::begin_write,
+};
+
+use std::fmt::Debug;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use entity::{
+    BoardSolutions, BoardVariant, PuzzleDate, PuzzleSolution,
+    board_histories::{self, Model as BoardHistory},
+    board_puzzles::{self, Model as BoardPuzzle},
+    prelude::*,
+};
+use migration::OnConflict;
+use sea_orm::{
+    ActiveValue, ConnectionTrait, DbErr, EntityTrait as _, QuerySelect as _, TransactionTrait,
+};
+
+/// Gets a multi-board puzzle by date and variant.
+pub async fn get_board_puzzle<C: ConnectionTrait>(
+    db: &C,
+    date: &PuzzleDate,
+    variant: BoardVariant,
+) -> Option<BoardPuzzle> {
+    tracing::info!("getting {variant} puzzle for {date}…");
+    let puzzle = BoardPuzzles::find_by_id((date.clone(), variant))
+        .one(db)
+        .await
+        .ok()
+        .flatten();
+
+    match &puzzle {
+        Some(puzzle) => tracing::info!("got {variant} puzzle for {date}: {puzzle}"),
+        None => tracing::warn!("no {variant} puzzles found for {date}!"),
+    }
+    puzzle
+}
+
+/// Inserts the solutions of a multi-board puzzle for a given date and variant.
+///
+/// # Errors
+///
+/// Returns [`DbErr`] if the insertion fails.
+pub async fn insert_board_solutions<C: ConnectionTrait>(
+    db: &C,
+    date: &PuzzleDate,
+    variant: BoardVariant,
+    solutions: &BoardSolutions,
+) -> Result<(), DbErr> {
+    tracing::info!("inserting {variant} puzzle for {date}…");
+
+    let active_puzzle = board_puzzles::ActiveModel {
+        date: ActiveValue::Set(date.clone()),
+        variant: ActiveValue::Set(variant),
+        solutions: ActiveValue::Set(solutions.clone()),
+    };
+
+    match BoardPuzzles::insert(active_puzzle)
+        .on_conflict(
+            OnConflict::columns([board_puzzles::Column::Date, board_puzzles::Column::Variant])
+                .update_columns([board_puzzles::Column::Solutions])
+                .to_owned(),
+        )
+        .exec(db)
+        .await
+    {
+        Ok(_) => {
+            tracing::info!("inserted {variant} solutions {:?} for {date}", solutions.0);
+            Ok(())
+        }
+        Err(err) => {
+            tracing::error!("failed to insert {variant} solutions for {date}: {err}");
+            Err(err)
+        }
+    }
+}
+
+/// Gets a multi-board history by date, variant and session.
+pub async fn get_board_history<C: ConnectionTrait>(
+    db: &C,
+    date: &PuzzleDate,
+    variant: BoardVariant,
+    session: &str,
+) -> Option<BoardHistory> {
+    tracing::info!("getting {variant} history for {date} with session {session}…");
+    let history = BoardHistories::find_by_id((date.clone(), variant, session.to_owned()))
+        .one(db)
+        .await
+        .ok()
+        .flatten();
+
+    match &history {
+        Some(history) => {
+            tracing::info!("got {variant} history for {date} with session {session}: {history}")
+        }
+        None => tracing::warn!("no {variant} histories found for {date} with session {session}!"),
+    }
+    history
+}
+
+/// Gets a multi-board history by date, variant and session, locking its row until the end of the
+/// transaction.
+///
+/// # Errors
+///
+/// Returns [`DbErr`] if the query fails.
+///
+/// See: [`begin_write`]
+pub async fn lock_board_history<C: ConnectionTrait>(
+    db: &C,
+    date: &PuzzleDate,
+    variant: BoardVariant,
+    session: &str,
+) -> Result<Option<BoardHistory>, DbErr> {
+    tracing::info!("locking {variant} history for {date} with session {session}…");
+    BoardHistories::find_by_id((date.clone(), variant, session.to_owned()))
+        .lock_exclusive()
+        .one(db)
+        .await
+}
+
+/// Creates a new multi-board history.
+///
+/// # Errors
+///
+/// Returns [`DbErr`] if the insertion fails.
+pub async fn create_board_history<C: ConnectionTrait>(
+    db: &C,
+    date: &PuzzleDate,
+    variant: BoardVariant,
+    session: &str,
+    solutions: &BoardSolutions,
+) -> Result<(), DbErr> {
+    tracing::info!("creating {variant} history for {date} with session {session}…");
+    let active_history = board_histories::ActiveModel {
+        date: ActiveValue::Set(date.clone()),
+        variant: ActiveValue::Set(variant),
+        session: ActiveValue::Set(session.to_owned()),
+        submit_history: ActiveValue::Set(None),
+        solutions: ActiveValue::Set(solutions.clone()),
+        uploaded_at: ActiveValue::Set(Utc::now().naive_utc()),
+    };
+
+    match BoardHistories::insert(active_history).exec(db).await {
+        Ok(_) => {
+            tracing::info!("created {variant} history for {date} with session {session}");
+            Ok(())
+        }
+        Err(err) => {
+            tracing::error!(
+                "failed to create {variant} history for {date} with session {session}: {err}"
+            );
+            Err(err)
+        }
+    }
+}
+
+/// Updates the submit history of an existing multi-board history.
+///
+/// # Errors
+///
+/// Returns [`DbErr`] if the update fails.
+pub async fn update_board_history<C: ConnectionTrait>(
+    db: &C,
+    history: &BoardHistory,
+) -> Result<(), DbErr> {
+    let BoardHistory {
+        date,
+        variant,
+        session,
+        ..
+    } = history;
+    tracing::info!("updating {variant} history for {date} with session {session}…");
+
+    let active_history = board_histories::ActiveModel {
+        date: ActiveValue::Unchanged(date.clone()),
+        variant: ActiveValue::Unchanged(*variant),
+        session: ActiveValue::Unchanged(session.clone()),
+        submit_history: ActiveValue::Set(history.submit_history.clone()),
+        ..Default::default()
+    };
+
+    match BoardHistories::update(active_history).exec(db).await {
+        Ok(_) => {
+            tracing::info!("updated {variant} history for {date} with session {session}");
+            Ok(())
+        }
+        Err(err) => {
+            tracing::error!(
+                "failed to update {variant} history for {date} with session {session}: {err}"
+            );
+            Err(err)
+        }
+    }
+}
+
+#[async_trait]
+impl<C> BoardRepo for C
+where
+    C: ConnectionTrait + TransactionTrait + Debug + Send + Sync,
+{
+    async fn get_board_puzzle(
+        &self,
+        date: &PuzzleDate,
+        variant: BoardVariant,
+    ) -> Option<BoardPuzzle> {
+        get_board_puzzle(self, date, variant).await
+    }
+
+    async fn insert_board_solutions(
+        &self,
+        date: &PuzzleDate,
+        variant: BoardVariant,
+        solutions: &BoardSolutions,
+    ) -> Result<(), DbErr> {
+        insert_board_solutions(self, date, variant, solutions).await
+    }
+
+    async fn get_board_history(
+        &self,
+        date: &PuzzleDate,
+        variant: BoardVariant,
+        session: &str,
+    ) -> Option<BoardHistory> {
+        get_board_history(self, date, variant, session).await
+    }
+
+    async fn create_board_history(
+        &self,
+        date: &PuzzleDate,
+        variant: BoardVariant,
+        session: &str,
+        solutions: &BoardSolutions,
+    ) -> Result<(), DbErr> {
+        create_board_history(self, date, variant, session, solutions).await
+    }
+
+    async fn update_board_history(&self, history: &BoardHistory) -> Result<(), DbErr> {
+        update_board_history(self, history).await
+    }
+
+    async fn submit_to_board_history(
+        &self,
+        date: &PuzzleDate,
+        variant: BoardVariant,
+        session: &str,
+        answer: &PuzzleSolution,
+    ) -> Result<BoardsSubmitResult, BoardsSubmitError> {
+        tracing::info!("submitting {answer} to {variant} history at {date} with {session}…");
+        let txn = begin_write(self).await?;
+        let Some(history) = lock_board_history(&txn, date, variant, session).await? else {
+            return Err(repo::missing_board_history(date, variant, session).into());
+        };
+
+        let result = repo::submit_boards(&txn, history, answer).await;
+        if let Ok(_) | Err(BoardsSubmitError::GameOver(_)) = result {
+            txn.commit().await?;
+        }
+        result
+    }
+}
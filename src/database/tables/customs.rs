@@ -0,0 +1,176 @@
+//! Tables `custom_puzzles` and `custom_histories`.
+
+use crate::database::repo::CustomRepo;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use entity::{
+    custom_histories::{self, Model as CustomHistory},
+    custom_puzzles::{self, Model as CustomPuzzle},
+    prelude::*,
+};
+use migration::OnConflict;
+use sea_orm::{
+    ActiveModelTrait as _, ActiveValue, ColumnTrait as _, DatabaseConnection, DbErr,
+    EntityTrait as _, QueryFilter as _,
+};
+
+/// Gets a custom puzzle by id.
+pub async fn get_custom_puzzle(db: &DatabaseConnection, id: &str) -> Option<CustomPuzzle> {
+    tracing::info!("getting custom puzzle {id}…");
+    let puzzle = CustomPuzzles::find_by_id(id.to_owned())
+        .one(db)
+        .await
+        .ok()
+        .flatten();
+
+    match &puzzle {
+        Some(puzzle) => tracing::info!("got custom puzzle {id}: {puzzle}"),
+        None => tracing::warn!("no custom puzzle {id} found!"),
+    }
+    puzzle
+}
+
+/// Creates a new custom puzzle owned by its creator.
+///
+/// # Errors
+///
+/// Returns [`DbErr`] if the insertion fails.
+pub async fn create_custom_puzzle(
+    db: &DatabaseConnection,
+    id: &str,
+    session: &str,
+    max_tries: usize,
+) -> Result<CustomPuzzle, DbErr> {
+    tracing::info!("creating custom puzzle {id} with session {session}…");
+    let active_puzzle = custom_puzzles::ActiveModel {
+        id: ActiveValue::Set(id.to_owned()),
+        session: ActiveValue::Set(session.to_owned()),
+        max_tries: ActiveValue::Set(
+            i32::try_from(max_tries).map_err(|err| DbErr::Custom(err.to_string()))?,
+        ),
+        created_at: ActiveValue::Set(Utc::now().naive_utc()),
+    };
+
+    match active_puzzle.insert(db).await {
+        Ok(puzzle) => {
+            tracing::info!("created custom puzzle {id} with session {session}");
+            Ok(puzzle)
+        }
+        Err(err) => {
+            tracing::error!("failed to create custom puzzle {id} with session {session}: {err}");
+            Err(err)
+        }
+    }
+}
+
+/// Gets a custom history by puzzle id and session.
+pub async fn get_custom_history(
+    db: &DatabaseConnection,
+    puzzle: &str,
+    session: &str,
+) -> Option<CustomHistory> {
+    tracing::info!("getting history for custom puzzle {puzzle} with session {session}…");
+    let history = CustomHistories::find_by_id((puzzle.to_owned(), session.to_owned()))
+        .one(db)
+        .await
+        .ok()
+        .flatten();
+
+    match &history {
+        Some(history) => tracing::info!(
+            "got history for custom puzzle {puzzle} with session {session}: {history}"
+        ),
+        None => {
+            tracing::warn!("no histories found for custom puzzle {puzzle} with session {session}!")
+        }
+    }
+    history
+}
+
+/// Gets all histories of a custom puzzle.
+pub async fn get_custom_histories(db: &DatabaseConnection, puzzle: &str) -> Vec<CustomHistory> {
+    tracing::info!("getting histories for custom puzzle {puzzle}…");
+    let histories = CustomHistories::find()
+        .filter(custom_histories::Column::Puzzle.eq(puzzle.to_owned()))
+        .all(db)
+        .await
+        .unwrap_or(Vec::new());
+
+    tracing::trace!("got histories for custom puzzle {puzzle}: {histories:?}");
+    histories
+}
+
+/// Inserts a custom history, or updates its submit history if it exists.
+///
+/// # Errors
+///
+/// Returns [`DbErr`] if the insertion fails.
+pub async fn insert_or_update_custom_history(
+    db: &DatabaseConnection,
+    history: &CustomHistory,
+) -> Result<(), DbErr> {
+    let CustomHistory {
+        puzzle, session, ..
+    } = history;
+    tracing::info!("upserting history for custom puzzle {puzzle} with session {session}…");
+
+    let active_history = custom_histories::ActiveModel {
+        puzzle: ActiveValue::Set(puzzle.clone()),
+        session: ActiveValue::Set(session.clone()),
+        submit_history: ActiveValue::Set(history.submit_history.clone()),
+        uploaded_at: ActiveValue::Set(history.uploaded_at),
+    };
+
+    match CustomHistories::insert(active_history)
+        .on_conflict(
+            OnConflict::columns([
+                custom_histories::Column::Puzzle,
+                custom_histories::Column::Session,
+            ])
+            .update_columns([custom_histories::Column::SubmitHistory])
+            .to_owned(),
+        )
+        .exec(db)
+        .await
+    {
+        Ok(_) => {
+            tracing::info!("upserted history for custom puzzle {puzzle} with session {session}");
+            Ok(())
+        }
+        Err(err) => {
+            tracing::error!(
+                "failed to upsert history for custom puzzle {puzzle} with session {session}: {err}"
+            );
+            Err(err)
+        }
+    }
+}
+
+#[async_trait]
+impl CustomRepo for DatabaseConnection {
+    async fn get_custom_puzzle(&self, id: &str) -> Option<CustomPuzzle> {
+        get_custom_puzzle(self, id).await
+    }
+
+    async fn create_custom_puzzle(
+        &self,
+        id: &str,
+        session: &str,
+        max_tries: usize,
+    ) -> Result<CustomPuzzle, DbErr> {
+        create_custom_puzzle(self, id, session, max_tries).await
+    }
+
+    async fn get_custom_history(&self, puzzle: &str, session: &str) -> Option<CustomHistory> {
+        get_custom_history(self, puzzle, session).await
+    }
+
+    async fn get_custom_histories(&self, puzzle: &str) -> Vec<CustomHistory> {
+        get_custom_histories(self, puzzle).await
+    }
+
+    async fn insert_or_update_custom_history(&self, history: &CustomHistory) -> Result<(), DbErr> {
+        insert_or_update_custom_history(self, history).await
+    }
+}
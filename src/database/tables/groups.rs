@@ -0,0 +1,181 @@
+//! Tables `groups` and `group_members`.
+
+use crate::database::repo::GroupRepo;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use entity::{
+    group_members::{self, Model as GroupMember},
+    groups::{self, Model as Group},
+    prelude::*,
+};
+use migration::OnConflict;
+use sea_orm::{
+    ActiveModelTrait as _, ActiveValue, ColumnTrait as _, DatabaseConnection, DbErr,
+    EntityTrait as _, QueryFilter as _, TransactionTrait as _,
+};
+
+/// Gets a group by id.
+pub async fn get_group(db: &DatabaseConnection, id: &str) -> Option<Group> {
+    tracing::info!("getting group {id}…");
+    let group = Groups::find_by_id(id.to_owned())
+        .one(db)
+        .await
+        .ok()
+        .flatten();
+
+    match &group {
+        Some(group) => tracing::info!("got group {id}: {group}"),
+        None => tracing::warn!("no group {id} found!"),
+    }
+    group
+}
+
+/// Gets a group by its invite code.
+pub async fn get_group_by_invite_code(db: &DatabaseConnection, invite_code: &str) -> Option<Group> {
+    tracing::info!("getting group by invite code {invite_code}…");
+    let group = Groups::find()
+        .filter(groups::Column::InviteCode.eq(invite_code.to_owned()))
+        .one(db)
+        .await
+        .ok()
+        .flatten();
+
+    match &group {
+        Some(group) => tracing::info!("got group by invite code {invite_code}: {group}"),
+        None => tracing::warn!("no group found by invite code {invite_code}!"),
+    }
+    group
+}
+
+/// Creates a new group owned by the session, which joins it under a nickname.
+///
+/// # Errors
+///
+/// Returns [`DbErr`] if the insertion fails.
+pub async fn create_group(
+    db: &DatabaseConnection,
+    id: &str,
+    name: &str,
+    invite_code: &str,
+    session: &str,
+    nickname: &str,
+) -> Result<Group, DbErr> {
+    tracing::info!("creating group {id} with session {session}…");
+    let now = Utc::now().naive_utc();
+    let active_group = groups::ActiveModel {
+        id: ActiveValue::Set(id.to_owned()),
+        name: ActiveValue::Set(name.to_owned()),
+        invite_code: ActiveValue::Set(invite_code.to_owned()),
+        owner: ActiveValue::Set(session.to_owned()),
+        created_at: ActiveValue::Set(now),
+    };
+    let active_member = group_members::ActiveModel {
+        group: ActiveValue::Set(id.to_owned()),
+        session: ActiveValue::Set(session.to_owned()),
+        nickname: ActiveValue::Set(nickname.to_owned()),
+        joined_at: ActiveValue::Set(now),
+    };
+
+    let txn = db.begin().await?;
+    let created = match active_group.insert(&txn).await {
+        Ok(group) => active_member.insert(&txn).await.map(|_| group),
+        Err(err) => Err(err),
+    };
+
+    match created {
+        Ok(group) => {
+            txn.commit().await?;
+            tracing::info!("created group {id} with session {session}");
+            Ok(group)
+        }
+        Err(err) => {
+            tracing::error!("failed to create group {id} with session {session}: {err}");
+            txn.rollback().await?;
+            Err(err)
+        }
+    }
+}
+
+/// Gets all members of a group.
+pub async fn get_group_members(db: &DatabaseConnection, id: &str) -> Vec<GroupMember> {
+    tracing::info!("getting members of group {id}…");
+    let members = GroupMembers::find()
+        .filter(group_members::Column::Group.eq(id.to_owned()))
+        .all(db)
+        .await
+        .unwrap_or(Vec::new());
+
+    tracing::trace!("got members of group {id}: {members:?}");
+    members
+}
+
+/// Inserts a member of a group, or updates its nickname if it exists.
+///
+/// # Errors
+///
+/// Returns [`DbErr`] if the insertion fails.
+pub async fn insert_or_update_group_member(
+    db: &DatabaseConnection,
+    member: &GroupMember,
+) -> Result<(), DbErr> {
+    let GroupMember { group, session, .. } = member;
+    tracing::info!("upserting member of group {group} with session {session}…");
+    let active_member = group_members::ActiveModel {
+        group: ActiveValue::Set(group.clone()),
+        session: ActiveValue::Set(session.clone()),
+        nickname: ActiveValue::Set(member.nickname.clone()),
+        joined_at: ActiveValue::Set(member.joined_at),
+    };
+
+    match GroupMembers::insert(active_member)
+        .on_conflict(
+            OnConflict::columns([group_members::Column::Group, group_members::Column::Session])
+                .update_columns([group_members::Column::Nickname])
+                .to_owned(),
+        )
+        .exec(db)
+        .await
+    {
+        Ok(_) => {
+            tracing::info!("upserted member of group {group} with session {session}");
+            Ok(())
+        }
+        Err(err) => {
+            tracing::error!(
+                "failed to upsert member of group {group} with session {session}: {err}"
+            );
+            Err(err)
+        }
+    }
+}
+
+#[async_trait]
+impl GroupRepo for DatabaseConnection {
+    async fn get_group(&self, id: &str) -> Option<Group> {
+        get_group(self, id).await
+    }
+
+    async fn get_group_by_invite_code(&self, invite_code: &str) -> Option<Group> {
+        get_group_by_invite_code(self, invite_code).await
+    }
+
+    async fn create_group(
+        &self,
+        id: &str,
+        name: &str,
+        invite_code: &str,
+        session: &str,
+        nickname: &str,
+    ) -> Result<Group, DbErr> {
+        create_group(self, id, name, invite_code, session, nickname).await
+    }
+
+    async fn get_group_members(&self, id: &str) -> Vec<GroupMember> {
+        get_group_members(self, id).await
+    }
+
+    async fn insert_or_update_group_member(&self, member: &GroupMember) -> Result<(), DbErr> {
+        insert_or_update_group_member(self, member).await
+    }
+}
@@ -1,17 +1,33 @@
 //! Table `histories`.
 
+use crate::{
+    database::{
+        repo::{self, ArchivePage, HintError, HistoryRepo, SubmitError, SubmitResult},
+        tables::begin_write,
+    },
+    live::LIVE,
+};
+
+use std::fmt::Debug;
+
+use async_trait::async_trait;
 use chrono::Utc;
 use entity::{
-    PuzzleDate, PuzzleSolution, SubmitHistory, SubmitWord,
+    GameState, Hint, Hints, PuzzleDate, PuzzleSolution, Timing,
     histories::{self, Model as History},
     prelude::*,
+    puzzles,
+};
+use sea_orm::{
+    ActiveValue, ColumnTrait as _, ConnectionTrait, DbErr, EntityTrait as _, JoinType,
+    PaginatorTrait as _, QueryFilter as _, QueryOrder as _, QuerySelect as _, RelationTrait as _,
+    TransactionTrait,
+    sea_query::{Expr, IntoCondition as _},
 };
-use migration::OnConflict;
-use sea_orm::{ActiveValue, DatabaseConnection, DbErr, EntityTrait as _, QuerySelect as _};
 
 /// Gets a history by date and session.
-pub async fn get_history(
-    db: &DatabaseConnection,
+pub async fn get_history<C: ConnectionTrait>(
+    db: &C,
     date: &PuzzleDate,
     session: &str,
 ) -> Option<History> {
@@ -29,22 +45,143 @@ pub async fn get_history(
     history
 }
 
+/// Gets a history by date and session, locking its row until the end of the transaction. The row
+/// is not locked on SQLite, where the transaction must hold the write lock from its start instead.
+///
+/// See: [`begin_write`]
+///
+/// # Errors
+///
+/// Returns [`DbErr`] if the query fails.
+pub async fn lock_history<C: ConnectionTrait>(
+    db: &C,
+    date: &PuzzleDate,
+    session: &str,
+) -> Result<Option<History>, DbErr> {
+    tracing::info!("locking history for {date} with session {session}…");
+    Histories::find_by_id((date.to_owned(), session.to_owned()))
+        .lock_exclusive()
+        .one(db)
+        .await
+}
+
+/// Gets all histories of a date.
+pub async fn get_histories<C: ConnectionTrait>(db: &C, date: &PuzzleDate) -> Vec<History> {
+    tracing::info!("getting histories for {date}…");
+    let histories = Histories::find()
+        .filter(histories::Column::Date.eq(date.to_owned()))
+        .all(db)
+        .await
+        .unwrap_or(Vec::new());
+
+    tracing::trace!("got histories for {date}: {histories:?}");
+    histories
+}
+
+/// Gets the histories of some sessions since a date, included.
+pub async fn get_session_histories<C: ConnectionTrait>(
+    db: &C,
+    sessions: &[String],
+    since: &PuzzleDate,
+) -> Vec<History> {
+    tracing::info!(
+        "getting histories for {} sessions since {since}…",
+        sessions.len()
+    );
+    let histories = Histories::find()
+        .filter(histories::Column::Session.is_in(sessions.iter().cloned()))
+        .filter(histories::Column::Date.gte(since.clone()))
+        .all(db)
+        .await
+        .unwrap_or(Vec::new());
+
+    tracing::trace!("got histories for sessions: {histories:?}");
+    histories
+}
+
+/// Gets the histories of a date last submitted from an address.
+pub async fn get_address_histories<C: ConnectionTrait>(
+    db: &C,
+    date: &PuzzleDate,
+    address: &str,
+) -> Vec<History> {
+    tracing::info!("getting histories for {date} with address {address}…");
+    let histories = Histories::find()
+        .filter(histories::Column::Date.eq(date.clone()))
+        .filter(histories::Column::Address.eq(address))
+        .all(db)
+        .await
+        .unwrap_or(Vec::new());
+
+    tracing::trace!("got histories for address: {histories:?}");
+    histories
+}
+
+/// Gets all histories flagged as suspected of cheating, latest date first.
+pub async fn get_flagged_histories<C: ConnectionTrait>(db: &C) -> Vec<History> {
+    tracing::info!("getting flagged histories…");
+    let histories = Histories::find()
+        .filter(histories::Column::Flags.is_not_null())
+        .order_by_desc(histories::Column::Date)
+        .all(db)
+        .await
+        .unwrap_or(Vec::new());
+
+    tracing::trace!("got flagged histories: {histories:?}");
+    histories
+}
+
+/// Gets a page of the puzzles left joined with the histories of a session, latest date first.
+pub async fn get_archive<C: ConnectionTrait>(
+    db: &C,
+    session: &str,
+    page: u64,
+    per_page: u64,
+) -> ArchivePage {
+    tracing::info!("getting archive page {page} with session {session}…");
+    let joined_session = session.to_owned();
+    let paginator = Puzzles::find()
+        .select_also(Histories)
+        .join(
+            JoinType::LeftJoin,
+            puzzles::Relation::Histories
+                .def()
+                .on_condition(move |_, right| {
+                    Expr::col((right, histories::Column::Session))
+                        .eq(joined_session.clone())
+                        .into_condition()
+                }),
+        )
+        .order_by_desc(puzzles::Column::Date)
+        .paginate(db, per_page);
+
+    let archive = ArchivePage {
+        total: paginator.num_items().await.unwrap_or_default(),
+        entries: paginator.fetch_page(page).await.unwrap_or_default(),
+    };
+    tracing::trace!("got archive page {page} with session {session}: {archive:?}");
+    archive
+}
+
 /// Creates a new history.
 ///
 /// # Errors
 ///
 /// Returns [`DbErr`] if the insertion fails.
-pub async fn create_history(
-    db: &DatabaseConnection,
+pub async fn create_history<C: ConnectionTrait>(
+    db: &C,
     date: &PuzzleDate,
     session: &str,
     solution: &PuzzleSolution,
+    timing: &Timing,
 ) -> Result<(), DbErr> {
     tracing::info!("creating history for {date} with session {session}…");
     let active_history = histories::ActiveModel {
         date: ActiveValue::Set(date.to_owned()),
         session: ActiveValue::Set(session.to_owned()),
         solution: ActiveValue::Set(solution.to_owned()),
+        game_state: ActiveValue::Set(Some(GameState::InProgress)),
+        timing: ActiveValue::Set(Some(timing.to_owned())),
         uploaded_at: ActiveValue::Set(Utc::now().naive_utc()),
         ..Default::default()
     };
@@ -65,96 +202,119 @@ pub async fn create_history(
     }
 }
 
-/// The result for submitting a word to history.
-#[derive(Debug, Clone)]
-pub struct SubmitResult {
-    /// The updated submit history.
-    pub submit_history: SubmitHistory,
-    /// Whether the puzzle has been completed.
-    pub is_completed: bool,
-}
-
-/// Submits a word to history.
+/// Updates the submit history, completion, game state, hints, timing, address and flags of an
+/// existing history.
 ///
 /// # Errors
 ///
-/// Returns [`DbErr`] if the submission fails.
-pub async fn submit_to_history(
-    db: &DatabaseConnection,
-    date: &PuzzleDate,
-    session: &str,
-    answer: &PuzzleSolution,
-) -> Result<SubmitResult, DbErr> {
-    tracing::info!("submitting {answer} to history at {date} with {session}…");
-
-    let (mut submit_history, solution) =
-        match Histories::find_by_id((date.to_owned(), session.to_owned()))
-            .select_only()
-            .columns([
-                histories::Column::SubmitHistory,
-                histories::Column::IsCompleted,
-                histories::Column::Solution,
-            ])
-            .into_tuple::<(Option<SubmitHistory>, bool, PuzzleSolution)>()
-            .one(db)
-            .await
-            .ok()
-            .flatten()
-        {
-            Some((submit_history, true, _)) => {
-                tracing::warn!("history is completed for {date} with session {session}!");
-                return Ok(SubmitResult {
-                    submit_history: submit_history.unwrap_or_default(),
-                    is_completed: true,
-                });
-            }
-            Some((submit_history, false, solution)) => {
-                (submit_history.unwrap_or_default(), solution)
-            }
-            None => {
-                tracing::error!("no history found for {date} with session {session}!");
-                return Err(DbErr::Custom(format!("session {session} has no history")));
-            }
-        };
-
-    let word = SubmitWord::tint(answer, &solution);
-    submit_history
-        .submit(word)
-        .map_err(|e| DbErr::Custom(e.to_string()))?;
+/// Returns [`DbErr`] if the update fails.
+pub async fn update_history<C: ConnectionTrait>(db: &C, history: &History) -> Result<(), DbErr> {
+    let History { date, session, .. } = history;
+    tracing::info!("updating history for {date} with session {session}…");
 
     let active_history = histories::ActiveModel {
         date: ActiveValue::Unchanged(date.to_owned()),
         session: ActiveValue::Unchanged(session.to_owned()),
-        submit_history: ActiveValue::Set(Some(submit_history.clone())),
-        solution: ActiveValue::Unchanged(solution),
-        is_completed: ActiveValue::Set(word.all_matches()),
-        uploaded_at: ActiveValue::Unchanged(Utc::now().naive_utc()),
+        submit_history: ActiveValue::Set(history.submit_history.clone()),
+        is_completed: ActiveValue::Set(history.is_completed),
+        game_state: ActiveValue::Set(history.game_state),
+        hints: ActiveValue::Set(history.hints.clone()),
+        timing: ActiveValue::Set(history.timing.clone()),
+        address: ActiveValue::Set(history.address.clone()),
+        flags: ActiveValue::Set(history.flags.clone()),
+        ..Default::default()
     };
 
-    match Histories::insert(active_history)
-        .on_conflict(
-            OnConflict::columns([histories::Column::Date, histories::Column::Session])
-                .update_columns([
-                    histories::Column::SubmitHistory,
-                    histories::Column::IsCompleted,
-                ])
-                .to_owned(),
-        )
-        .exec(db)
-        .await
-    {
+    match Histories::update(active_history).exec(db).await {
         Ok(_) => {
-            tracing::info!("submitted {answer} to history at {date} with session {session}");
-            Ok(SubmitResult {
-                submit_history,
-                is_completed: word.all_matches(),
-            })
+            tracing::info!("updated history for {date} with session {session}");
+            Ok(())
         }
         Err(err) => {
-            tracing::error!(
-                "failed to submit {answer} to history at {date} with session {session}: {err}"
-            );
+            tracing::error!("failed to update history for {date} with session {session}: {err}");
             Err(err)
         }
     }
 }
+
+#[async_trait]
+impl<C> HistoryRepo for C
+where
+    C: ConnectionTrait + TransactionTrait + Debug + Send + Sync,
+{
+    async fn get_history(&self, date: &PuzzleDate, session: &str) -> Option<History> {
+        get_history(self, date, session).await
+    }
+
+    async fn get_histories(&self, date: &PuzzleDate) -> Vec<History> {
+        get_histories(self, date).await
+    }
+
+    async fn get_session_histories(&self, sessions: &[String], since: &PuzzleDate) -> Vec<History> {
+        get_session_histories(self, sessions, since).await
+    }
+
+    async fn get_address_histories(&self, date: &PuzzleDate, address: &str) -> Vec<History> {
+        get_address_histories(self, date, address).await
+    }
+
+    async fn get_flagged_histories(&self) -> Vec<History> {
+        get_flagged_histories(self).await
+    }
+
+    async fn get_archive(&self, session: &str, page: u64, per_page: u64) -> ArchivePage {
+        get_archive(self, session, page, per_page).await
+    }
+
+    async fn create_history(
+        &self,
+        date: &PuzzleDate,
+        session: &str,
+        solution: &PuzzleSolution,
+        timing: &Timing,
+    ) -> Result<(), DbErr> {
+        create_history(self, date, session, solution, timing).await
+    }
+
+    async fn update_history(&self, history: &History) -> Result<(), DbErr> {
+        update_history(self, history).await
+    }
+
+    async fn submit_to_history(
+        &self,
+        date: &PuzzleDate,
+        session: &str,
+        answer: &PuzzleSolution,
+        address: &str,
+    ) -> Result<SubmitResult, SubmitError> {
+        tracing::info!("submitting {answer} to history at {date} with {session}…");
+        let mut outbox = LIVE.outbox(date).await;
+        let txn = begin_write(self).await?;
+        let Some(history) = lock_history(&txn, date, session).await? else {
+            return Err(repo::missing_history(date, session).into());
+        };
+
+        let result = repo::submit(&txn, history, answer, address, &mut outbox).await;
+        if let Ok(_) | Err(SubmitError::GameOver(_)) = result {
+            txn.commit().await?;
+            outbox.publish();
+        }
+        result
+    }
+
+    async fn use_hint(&self, date: &PuzzleDate, session: &str) -> Result<(Hint, Hints), HintError> {
+        tracing::info!("using hint at {date} with {session}…");
+        let mut outbox = LIVE.outbox(date).await;
+        let txn = begin_write(self).await?;
+        let Some(history) = lock_history(&txn, date, session).await? else {
+            return Err(repo::missing_history(date, session).into());
+        };
+
+        let result = repo::reveal_hint(&txn, history, &mut outbox).await;
+        if let Ok(_) | Err(HintError::GameOver(_)) = result {
+            txn.commit().await?;
+            outbox.publish();
+        }
+        result
+    }
+}
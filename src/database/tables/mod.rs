@@ -1,5 +1,33 @@
 //! The tables available in the database.
 
+use sea_orm::{ConnectionTrait as _, DatabaseTransaction, DbBackend, DbErr, TransactionTrait};
+
+pub mod absurdles;
+pub mod boards;
+pub mod customs;
+pub mod groups;
 pub mod histories;
+pub mod practices;
 pub mod puzzles;
+pub mod results;
 pub mod sessions;
+pub mod versus;
+
+/// Begins a transaction that reads rows to update them, which locks them with `FOR UPDATE`.
+///
+/// SQLite cannot lock rows, and a deferred transaction upgrading from reading to writing fails
+/// with `SQLITE_BUSY` instead of waiting for the other writer. So on SQLite the write lock on the
+/// database is taken up front by a no-op write, like `BEGIN IMMEDIATE` does, and the transactions
+/// wait for each other within the busy timeout.
+///
+/// # Errors
+///
+/// Returns [`DbErr`] if the transaction cannot begin or the write lock is not acquired in time.
+pub async fn begin_write<C: TransactionTrait + Sync>(db: &C) -> Result<DatabaseTransaction, DbErr> {
+    let txn = db.begin().await?;
+    if txn.get_database_backend() == DbBackend::Sqlite {
+        txn.execute_unprepared("UPDATE histories SET session = session WHERE 0")
+            .await?;
+    }
+    Ok(txn)
+}
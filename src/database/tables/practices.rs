@@ -0,0 +1,159 @@
+//! Table `practices`.
+
+use crate::database::{
+    repo::{self, PracticeRepo, SubmitError, SubmitResult},
+    tables::begin_write,
+};
+
+use std::fmt::Debug;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use entity::{
+    PuzzleSolution,
+    practices::{self, Model as Practice},
+    prelude::*,
+};
+use sea_orm::{
+    ActiveModelTrait as _, ActiveValue, ColumnTrait as _, ConnectionTrait, DbErr, EntityTrait as _,
+    QueryFilter as _, QuerySelect as _, TransactionTrait,
+};
+
+/// Gets a practice by id, if owned by the session.
+pub async fn get_practice<C: ConnectionTrait>(db: &C, id: &str, session: &str) -> Option<Practice> {
+    tracing::info!("getting practice {id} with session {session}…");
+    let practice = Practices::find_by_id(id.to_owned())
+        .filter(practices::Column::Session.eq(session.to_owned()))
+        .one(db)
+        .await
+        .ok()
+        .flatten();
+
+    match &practice {
+        Some(practice) => tracing::info!("got practice {id} with session {session}: {practice}"),
+        None => tracing::warn!("no practice {id} found with session {session}!"),
+    }
+    practice
+}
+
+/// Gets a practice by id, if owned by the session, locking its row until the end of the
+/// transaction.
+///
+/// # Errors
+///
+/// Returns [`DbErr`] if the query fails.
+///
+/// See: [`begin_write`]
+pub async fn lock_practice<C: ConnectionTrait>(
+    db: &C,
+    id: &str,
+    session: &str,
+) -> Result<Option<Practice>, DbErr> {
+    tracing::info!("locking practice {id} with session {session}…");
+    Practices::find_by_id(id.to_owned())
+        .filter(practices::Column::Session.eq(session.to_owned()))
+        .lock_exclusive()
+        .one(db)
+        .await
+}
+
+/// Creates a new practice.
+///
+/// # Errors
+///
+/// Returns [`DbErr`] if the insertion fails.
+pub async fn create_practice<C: ConnectionTrait>(
+    db: &C,
+    id: &str,
+    session: &str,
+    solution: &PuzzleSolution,
+) -> Result<Practice, DbErr> {
+    tracing::info!("creating practice {id} with session {session}…");
+    let active_practice = practices::ActiveModel {
+        id: ActiveValue::Set(id.to_owned()),
+        session: ActiveValue::Set(session.to_owned()),
+        submit_history: ActiveValue::Set(None),
+        solution: ActiveValue::Set(solution.to_owned()),
+        created_at: ActiveValue::Set(Utc::now().naive_utc()),
+    };
+
+    match active_practice.insert(db).await {
+        Ok(practice) => {
+            tracing::info!("created practice {id} with session {session} and solution {solution}");
+            Ok(practice)
+        }
+        Err(err) => {
+            tracing::error!("failed to create practice {id} with session {session}: {err}");
+            Err(err)
+        }
+    }
+}
+
+/// Updates the submit history of an existing practice.
+///
+/// # Errors
+///
+/// Returns [`DbErr`] if the update fails.
+pub async fn update_practice<C: ConnectionTrait>(db: &C, practice: &Practice) -> Result<(), DbErr> {
+    let Practice { id, session, .. } = practice;
+    tracing::info!("updating practice {id} with session {session}…");
+
+    let active_practice = practices::ActiveModel {
+        id: ActiveValue::Unchanged(id.to_owned()),
+        submit_history: ActiveValue::Set(practice.submit_history.clone()),
+        ..Default::default()
+    };
+
+    match Practices::update(active_practice).exec(db).await {
+        Ok(_) => {
+            tracing::info!("updated practice {id} with session {session}");
+            Ok(())
+        }
+        Err(err) => {
+            tracing::error!("failed to update practice {id} with session {session}: {err}");
+            Err(err)
+        }
+    }
+}
+
+#[async_trait]
+impl<C> PracticeRepo for C
+where
+    C: ConnectionTrait + TransactionTrait + Debug + Send + Sync,
+{
+    async fn get_practice(&self, id: &str, session: &str) -> Option<Practice> {
+        get_practice(self, id, session).await
+    }
+
+    async fn create_practice(
+        &self,
+        id: &str,
+        session: &str,
+        solution: &PuzzleSolution,
+    ) -> Result<Practice, DbErr> {
+        create_practice(self, id, session, solution).await
+    }
+
+    async fn update_practice(&self, practice: &Practice) -> Result<(), DbErr> {
+        update_practice(self, practice).await
+    }
+
+    async fn submit_to_practice(
+        &self,
+        practice: Practice,
+        answer: &PuzzleSolution,
+    ) -> Result<SubmitResult, SubmitError> {
+        let Practice { id, session, .. } = &practice;
+        tracing::info!("submitting {answer} to practice {id} with {session}…");
+        let txn = begin_write(self).await?;
+        let Some(practice) = lock_practice(&txn, id, session).await? else {
+            return Err(DbErr::Custom(format!("session {session} has no practice {id}")).into());
+        };
+
+        let result = repo::submit_practice(&txn, practice, answer).await;
+        if let Ok(_) | Err(SubmitError::GameOver(_)) = result {
+            txn.commit().await?;
+        }
+        result
+    }
+}
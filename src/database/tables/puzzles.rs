@@ -1,9 +1,15 @@
 //! Table `puzzles`.
 
+use crate::database::repo::PuzzleRepo;
+
+use async_trait::async_trait;
 use entity::puzzles::Model as Puzzle;
 use entity::{PuzzleDate, PuzzleSolution, prelude::*, puzzles};
 use migration::OnConflict;
-use sea_orm::{ActiveValue, DatabaseConnection, DbErr, EntityTrait as _, QuerySelect as _};
+use sea_orm::{
+    ActiveValue, ColumnTrait as _, DatabaseConnection, DbErr, EntityTrait as _, QueryFilter as _,
+    QuerySelect as _, sea_query::Expr,
+};
 
 /// Gets all puzzle dates.
 pub async fn get_dates(db: &DatabaseConnection) -> Vec<PuzzleDate> {
@@ -46,7 +52,21 @@ pub async fn get_puzzle(db: &DatabaseConnection, date: &PuzzleDate) -> Option<Pu
     puzzle
 }
 
-/// Inserts a puzzle solution for a given date.
+/// Gets the puzzles before a date whose difficulty has not been blended with the play data.
+pub async fn get_unblended_puzzles(db: &DatabaseConnection, before: &PuzzleDate) -> Vec<Puzzle> {
+    tracing::info!("getting unblended puzzles before {before}…");
+    let p = Puzzles::find()
+        .filter(puzzles::Column::Date.lt(before.clone()))
+        .filter(puzzles::Column::IsDifficultyBlended.eq(false))
+        .all(db)
+        .await
+        .unwrap_or(Vec::new());
+
+    tracing::trace!("got unblended puzzles: {p:?}");
+    p
+}
+
+/// Inserts a puzzle solution with its predicted difficulty for a given date.
 ///
 /// # Errors
 ///
@@ -55,18 +75,25 @@ pub async fn insert_solution(
     db: &DatabaseConnection,
     date: &PuzzleDate,
     solution: &PuzzleSolution,
+    difficulty: Option<f64>,
 ) -> Result<(), DbErr> {
     tracing::info!("inserting puzzle for {date}…");
 
     let active_puzzle = puzzles::ActiveModel {
         date: ActiveValue::Set(date.clone()),
         solution: ActiveValue::Set(solution.clone()),
+        difficulty: ActiveValue::Set(difficulty),
+        is_difficulty_blended: ActiveValue::Set(false),
     };
 
     match Puzzles::insert(active_puzzle)
         .on_conflict(
             OnConflict::column(puzzles::Column::Date)
-                .update_columns([puzzles::Column::Solution])
+                .update_columns([
+                    puzzles::Column::Solution,
+                    puzzles::Column::Difficulty,
+                    puzzles::Column::IsDifficultyBlended,
+                ])
                 .to_owned(),
         )
         .exec(db)
@@ -82,3 +109,108 @@ pub async fn insert_solution(
         }
     }
 }
+
+/// Stores the predicted difficulty of a puzzle, unless its solution has been replaced or its
+/// difficulty has already been blended with the play data.
+///
+/// # Errors
+///
+/// Returns [`DbErr`] if the update fails.
+pub async fn update_predicted_difficulty(
+    db: &DatabaseConnection,
+    date: &PuzzleDate,
+    solution: &PuzzleSolution,
+    difficulty: f64,
+) -> Result<(), DbErr> {
+    tracing::info!("updating predicted difficulty for {date}…");
+
+    match Puzzles::update_many()
+        .col_expr(puzzles::Column::Difficulty, Expr::value(difficulty))
+        .filter(puzzles::Column::Date.eq(date.clone()))
+        .filter(puzzles::Column::Solution.eq(solution.clone()))
+        .filter(puzzles::Column::IsDifficultyBlended.eq(false))
+        .exec(db)
+        .await
+    {
+        Ok(_) => {
+            tracing::info!("updated predicted difficulty for {date} to {difficulty:.2}");
+            Ok(())
+        }
+        Err(err) => {
+            tracing::error!("failed to update predicted difficulty for {date}: {err}");
+            Err(err)
+        }
+    }
+}
+
+/// Stores the difficulty of a puzzle blended with the play data.
+///
+/// # Errors
+///
+/// Returns [`DbErr`] if the puzzle is missing or the update fails.
+pub async fn update_difficulty(
+    db: &DatabaseConnection,
+    date: &PuzzleDate,
+    difficulty: f64,
+) -> Result<(), DbErr> {
+    tracing::info!("updating difficulty for {date}…");
+
+    let active_puzzle = puzzles::ActiveModel {
+        date: ActiveValue::Unchanged(date.clone()),
+        difficulty: ActiveValue::Set(Some(difficulty)),
+        is_difficulty_blended: ActiveValue::Set(true),
+        ..Default::default()
+    };
+
+    match Puzzles::update(active_puzzle).exec(db).await {
+        Ok(_) => {
+            tracing::info!("updated difficulty for {date} to {difficulty:.2}");
+            Ok(())
+        }
+        Err(err) => {
+            tracing::error!("failed to update difficulty for {date}: {err}");
+            Err(err)
+        }
+    }
+}
+
+#[async_trait]
+impl PuzzleRepo for DatabaseConnection {
+    async fn get_dates(&self) -> Vec<PuzzleDate> {
+        get_dates(self).await
+    }
+
+    async fn get_puzzles(&self) -> Vec<Puzzle> {
+        get_puzzles(self).await
+    }
+
+    async fn get_puzzle(&self, date: &PuzzleDate) -> Option<Puzzle> {
+        get_puzzle(self, date).await
+    }
+
+    async fn get_unblended_puzzles(&self, before: &PuzzleDate) -> Vec<Puzzle> {
+        get_unblended_puzzles(self, before).await
+    }
+
+    async fn insert_solution(
+        &self,
+        date: &PuzzleDate,
+        solution: &PuzzleSolution,
+        difficulty: Option<f64>,
+    ) -> Result<(), DbErr> {
+        insert_solution(self, date, solution, difficulty).await
+    }
+
+    async fn update_predicted_difficulty(
+        &self,
+        date: &PuzzleDate,
+        solution: &PuzzleSolution,
+        difficulty: f64,
+    ) -> Result<(), DbErr> {
+        update_predicted_difficulty(self, date, solution, difficulty).await
+    }
+
+    async fn update_difficulty(&self, date: &PuzzleDate, difficulty: f64) -> Result<(), DbErr> {
+        update_difficulty(self, date, difficulty).await
+    }
+}
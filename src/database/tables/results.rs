@@ -0,0 +1,208 @@
+//! Tables `result_counts` and `first_guess_counts`.
+
+use crate::database::repo::ResultRepo;
+
+use std::fmt::Debug;
+
+use async_trait::async_trait;
+use entity::{
+    GameState, PuzzleDate, SubmitWord,
+    first_guess_counts::{self, Model as FirstGuessCount},
+    prelude::*,
+    result_counts::{self, Model as ResultCount},
+};
+use migration::{Expr, OnConflict};
+use sea_orm::{
+    ActiveValue, ColumnTrait as _, ConnectionTrait, DbErr, EntityTrait as _, QueryFilter as _,
+    QueryOrder as _, QuerySelect as _,
+};
+
+/// Gets the counts of the finished games of a date by outcome and number of guesses.
+pub async fn get_result_counts<C: ConnectionTrait>(db: &C, date: &PuzzleDate) -> Vec<ResultCount> {
+    tracing::info!("getting result counts for {date}…");
+    ResultCounts::find()
+        .filter(result_counts::Column::Date.eq(date.clone()))
+        .all(db)
+        .await
+        .unwrap_or_default()
+}
+
+/// Gets the most common first guesses of the finished games of a date, most common first.
+pub async fn get_first_guess_counts<C: ConnectionTrait>(
+    db: &C,
+    date: &PuzzleDate,
+    limit: usize,
+) -> Vec<FirstGuessCount> {
+    tracing::info!("getting first guess counts for {date}…");
+    FirstGuessCounts::find()
+        .filter(first_guess_counts::Column::Date.eq(date.clone()))
+        .order_by_desc(first_guess_counts::Column::Count)
+        .order_by_asc(first_guess_counts::Column::Word)
+        .limit(u64::try_from(limit).ok())
+        .all(db)
+        .await
+        .unwrap_or_default()
+}
+
+/// Counts a finished game of a date with its first guess. The counts are incremented in place, so
+/// concurrent games are never lost.
+///
+/// # Errors
+///
+/// Returns [`DbErr`] if the puzzle is missing or the update fails.
+pub async fn record_result<C: ConnectionTrait>(
+    db: &C,
+    date: &PuzzleDate,
+    state: GameState,
+    tries: usize,
+    first_guess: Option<&SubmitWord>,
+) -> Result<(), DbErr> {
+    tracing::info!("recording result {state} for {date}…");
+    let active_count = result_counts::ActiveModel {
+        date: ActiveValue::Set(date.clone()),
+        is_won: ActiveValue::Set(matches!(state, GameState::Won { .. })),
+        tries: ActiveValue::Set(
+            i32::try_from(tries).map_err(|err| DbErr::Custom(err.to_string()))?,
+        ),
+        count: ActiveValue::Set(1),
+    };
+
+    if let Err(err) = ResultCounts::insert(active_count)
+        .on_conflict(
+            OnConflict::columns([
+                result_counts::Column::Date,
+                result_counts::Column::IsWon,
+                result_counts::Column::Tries,
+            ])
+            .value(
+                result_counts::Column::Count,
+                Expr::col((ResultCounts, result_counts::Column::Count)).add(1),
+            )
+            .to_owned(),
+        )
+        .exec(db)
+        .await
+    {
+        tracing::error!("failed to record result {state} for {date}: {err}");
+        return Err(err);
+    }
+
+    if let Some(first_guess) = first_guess {
+        let active_count = first_guess_counts::ActiveModel {
+            date: ActiveValue::Set(date.clone()),
+            word: ActiveValue::Set(first_guess.word()),
+            count: ActiveValue::Set(1),
+        };
+
+        if let Err(err) = FirstGuessCounts::insert(active_count)
+            .on_conflict(
+                OnConflict::columns([
+                    first_guess_counts::Column::Date,
+                    first_guess_counts::Column::Word,
+                ])
+                .value(
+                    first_guess_counts::Column::Count,
+                    Expr::col((FirstGuessCounts, first_guess_counts::Column::Count)).add(1),
+                )
+                .to_owned(),
+            )
+            .exec(db)
+            .await
+        {
+            tracing::error!("failed to record first guess {first_guess} for {date}: {err}");
+            return Err(err);
+        }
+    }
+
+    tracing::info!("recorded result {state} for {date}");
+    Ok(())
+}
+
+/// Uncounts a finished game of a date with its first guess, once it is flagged after being
+/// counted. The counts are decremented in place, like they are incremented.
+///
+/// # Errors
+///
+/// Returns [`DbErr`] if the update fails.
+pub async fn discount_result<C: ConnectionTrait>(
+    db: &C,
+    date: &PuzzleDate,
+    state: GameState,
+    tries: usize,
+    first_guess: Option<&SubmitWord>,
+) -> Result<(), DbErr> {
+    tracing::info!("discounting result {state} for {date}…");
+    let tries = i32::try_from(tries).map_err(|err| DbErr::Custom(err.to_string()))?;
+    if let Err(err) = ResultCounts::update_many()
+        .col_expr(
+            result_counts::Column::Count,
+            Expr::col(result_counts::Column::Count).sub(1),
+        )
+        .filter(result_counts::Column::Date.eq(date.clone()))
+        .filter(result_counts::Column::IsWon.eq(matches!(state, GameState::Won { .. })))
+        .filter(result_counts::Column::Tries.eq(tries))
+        .filter(result_counts::Column::Count.gt(0))
+        .exec(db)
+        .await
+    {
+        tracing::error!("failed to discount result {state} for {date}: {err}");
+        return Err(err);
+    }
+
+    if let Some(first_guess) = first_guess
+        && let Err(err) = FirstGuessCounts::update_many()
+            .col_expr(
+                first_guess_counts::Column::Count,
+                Expr::col(first_guess_counts::Column::Count).sub(1),
+            )
+            .filter(first_guess_counts::Column::Date.eq(date.clone()))
+            .filter(first_guess_counts::Column::Word.eq(first_guess.word()))
+            .filter(first_guess_counts::Column::Count.gt(0))
+            .exec(db)
+            .await
+    {
+        tracing::error!("failed to discount first guess {first_guess} for {date}: {err}");
+        return Err(err);
+    }
+
+    tracing::info!("discounted result {state} for {date}");
+    Ok(())
+}
+
+#[async_trait]
+impl<C> ResultRepo for C
+where
+    C: ConnectionTrait + Debug + Send + Sync,
+{
+    async fn get_result_counts(&self, date: &PuzzleDate) -> Vec<ResultCount> {
+        get_result_counts(self, date).await
+    }
+
+    async fn get_first_guess_counts(
+        &self,
+        date: &PuzzleDate,
+        limit: usize,
+    ) -> Vec<FirstGuessCount> {
+        get_first_guess_counts(self, date, limit).await
+    }
+
+    async fn record_result(
+        &self,
+        date: &PuzzleDate,
+        state: GameState,
+        tries: usize,
+        first_guess: Option<&SubmitWord>,
+    ) -> Result<(), DbErr> {
+        record_result(self, date, state, tries, first_guess).await
+    }
+
+    async fn discount_result(
+        &self,
+        date: &PuzzleDate,
+        state: GameState,
+        tries: usize,
+        first_guess: Option<&SubmitWord>,
+    ) -> Result<(), DbErr> {
+        discount_result(self, date, state, tries, first_guess).await
+    }
+}
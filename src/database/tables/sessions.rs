@@ -1,5 +1,8 @@
 //! Table `sessions`.
 
+use crate::database::repo::SessionRepo;
+
+use async_trait::async_trait;
 use chrono::Utc;
 use entity::{prelude::*, sessions};
 use migration::OnConflict;
@@ -56,3 +59,14 @@ pub async fn delete_session(db: &DatabaseConnection, session: &str) -> Result<()
         }
     }
 }
+
+#[async_trait]
+impl SessionRepo for DatabaseConnection {
+    async fn insert_or_update_session(&self, session: &str) -> Result<(), DbErr> {
+        insert_or_update_session(self, session).await
+    }
+
+    async fn delete_session(&self, session: &str) -> Result<(), DbErr> {
+        delete_session(self, session).await
+    }
+}
@@ -0,0 +1,282 @@
+//! Table `versus_matches`.
+
+use crate::database::{
+    repo::{self, VersusError, VersusRepo},
+    tables::begin_write,
+};
+
+use std::fmt::Debug;
+
+use async_trait::async_trait;
+use chrono::{NaiveDateTime, TimeDelta, Utc};
+use entity::{
+    PuzzleSolution,
+    prelude::*,
+    versus_matches::{self, Model as VersusMatch},
+};
+use migration::Expr;
+use sea_orm::{
+    ActiveModelTrait as _, ActiveValue, ColumnTrait as _, Condition, ConnectionTrait, DbErr,
+    EntityTrait as _, QueryFilter as _, QueryOrder as _, QuerySelect as _, TransactionTrait,
+};
+
+/// The number of waiting matches tried at once when joining the lobby, as other guests may claim
+/// them first.
+const JOIN_CANDIDATES: u64 = 8;
+
+/// Gets a versus match by id, if played by the session.
+pub async fn get_versus<C: ConnectionTrait>(
+    db: &C,
+    id: &str,
+    session: &str,
+) -> Option<VersusMatch> {
+    tracing::info!("getting versus {id} with session {session}…");
+    let game = VersusMatches::find_by_id(id.to_owned())
+        .filter(
+            Condition::any()
+                .add(versus_matches::Column::Host.eq(session.to_owned()))
+                .add(versus_matches::Column::Guest.eq(session.to_owned())),
+        )
+        .one(db)
+        .await
+        .ok()
+        .flatten();
+
+    match &game {
+        Some(game) => tracing::info!("got versus {id} with session {session}: {game}"),
+        None => tracing::warn!("no versus {id} found with session {session}!"),
+    }
+    game
+}
+
+/// Gets a versus match by id, if played by the session, locking its row until the end of the
+/// transaction.
+///
+/// # Errors
+///
+/// Returns [`DbErr`] if the query fails.
+///
+/// See: [`begin_write`]
+pub async fn lock_versus<C: ConnectionTrait>(
+    db: &C,
+    id: &str,
+    session: &str,
+) -> Result<Option<VersusMatch>, DbErr> {
+    tracing::info!("locking versus {id} with session {session}…");
+    VersusMatches::find_by_id(id.to_owned())
+        .filter(
+            Condition::any()
+                .add(versus_matches::Column::Host.eq(session.to_owned()))
+                .add(versus_matches::Column::Guest.eq(session.to_owned())),
+        )
+        .lock_exclusive()
+        .one(db)
+        .await
+}
+
+/// Gets the latest match hosted by the session still waiting in the lobby, if queued since a given
+/// time.
+pub async fn get_waiting_versus<C: ConnectionTrait>(
+    db: &C,
+    session: &str,
+    since: NaiveDateTime,
+) -> Option<VersusMatch> {
+    tracing::info!("getting waiting versus with session {session}…");
+    VersusMatches::find()
+        .filter(versus_matches::Column::Host.eq(session.to_owned()))
+        .filter(versus_matches::Column::Guest.is_null())
+        .filter(versus_matches::Column::FinishedAt.is_null())
+        .filter(versus_matches::Column::CreatedAt.gte(since))
+        .order_by_desc(versus_matches::Column::CreatedAt)
+        .one(db)
+        .await
+        .ok()
+        .flatten()
+}
+
+/// Creates a new match hosted by the session, waiting in the lobby for a guest.
+///
+/// # Errors
+///
+/// Returns [`DbErr`] if the insertion fails.
+pub async fn create_versus<C: ConnectionTrait>(
+    db: &C,
+    id: &str,
+    session: &str,
+    solution: &PuzzleSolution,
+) -> Result<VersusMatch, DbErr> {
+    tracing::info!("creating versus {id} with session {session}…");
+    let now = Utc::now().naive_utc();
+    let active_game = versus_matches::ActiveModel {
+        id: ActiveValue::Set(id.to_owned()),
+        host: ActiveValue::Set(session.to_owned()),
+        guest: ActiveValue::Set(None),
+        submit_history: ActiveValue::Set(None),
+        solution: ActiveValue::Set(solution.clone()),
+        winner: ActiveValue::Set(None),
+        turn_started_at: ActiveValue::Set(now),
+        finished_at: ActiveValue::Set(None),
+        created_at: ActiveValue::Set(now),
+    };
+
+    match active_game.insert(db).await {
+        Ok(game) => {
+            tracing::info!("created versus {id} with session {session}");
+            Ok(game)
+        }
+        Err(err) => {
+            tracing::error!("failed to create versus {id} with session {session}: {err}");
+            Err(err)
+        }
+    }
+}
+
+/// Joins the session as the guest of the oldest match hosted by someone else, if queued since a
+/// given time.
+///
+/// # Errors
+///
+/// Returns [`DbErr`] if the update fails.
+pub async fn join_waiting_versus<C: ConnectionTrait>(
+    db: &C,
+    session: &str,
+    since: NaiveDateTime,
+) -> Result<Option<VersusMatch>, DbErr> {
+    tracing::info!("joining waiting versus with session {session}…");
+    let candidates = VersusMatches::find()
+        .filter(versus_matches::Column::Host.ne(session.to_owned()))
+        .filter(versus_matches::Column::Guest.is_null())
+        .filter(versus_matches::Column::FinishedAt.is_null())
+        .filter(versus_matches::Column::CreatedAt.gte(since))
+        .order_by_asc(versus_matches::Column::CreatedAt)
+        .limit(JOIN_CANDIDATES)
+        .all(db)
+        .await?;
+
+    for mut game in candidates {
+        let now = Utc::now().naive_utc();
+        // the guest is claimed only if nobody else claimed it meanwhile
+        let claimed = VersusMatches::update_many()
+            .col_expr(
+                versus_matches::Column::Guest,
+                Expr::value(session.to_owned()),
+            )
+            .col_expr(versus_matches::Column::TurnStartedAt, Expr::value(now))
+            .filter(versus_matches::Column::Id.eq(game.id.clone()))
+            .filter(versus_matches::Column::Guest.is_null())
+            .exec(db)
+            .await?;
+
+        if claimed.rows_affected == 1 {
+            tracing::info!("joined versus {} with session {session}", game.id);
+            game.guest = Some(session.to_owned());
+            game.turn_started_at = now;
+            return Ok(Some(game));
+        }
+    }
+
+    tracing::info!("no waiting versus to join with session {session}");
+    Ok(None)
+}
+
+/// Updates the mutable columns of an existing match.
+///
+/// # Errors
+///
+/// Returns [`DbErr`] if the update fails.
+pub async fn update_versus<C: ConnectionTrait>(db: &C, game: &VersusMatch) -> Result<(), DbErr> {
+    let VersusMatch { id, .. } = game;
+    tracing::info!("updating versus {id}…");
+
+    let active_game = versus_matches::ActiveModel {
+        id: ActiveValue::Unchanged(id.to_owned()),
+        submit_history: ActiveValue::Set(game.submit_history.clone()),
+        winner: ActiveValue::Set(game.winner.clone()),
+        turn_started_at: ActiveValue::Set(game.turn_started_at),
+        finished_at: ActiveValue::Set(game.finished_at),
+        ..Default::default()
+    };
+
+    match VersusMatches::update(active_game).exec(db).await {
+        Ok(_) => {
+            tracing::info!("updated versus {id}");
+            Ok(())
+        }
+        Err(err) => {
+            tracing::error!("failed to update versus {id}: {err}");
+            Err(err)
+        }
+    }
+}
+
+#[async_trait]
+impl<C> VersusRepo for C
+where
+    C: ConnectionTrait + TransactionTrait + Debug + Send + Sync,
+{
+    async fn get_versus(&self, id: &str, session: &str) -> Option<VersusMatch> {
+        get_versus(self, id, session).await
+    }
+
+    async fn get_waiting_versus(&self, session: &str, since: NaiveDateTime) -> Option<VersusMatch> {
+        get_waiting_versus(self, session, since).await
+    }
+
+    async fn create_versus(
+        &self,
+        id: &str,
+        session: &str,
+        solution: &PuzzleSolution,
+    ) -> Result<VersusMatch, DbErr> {
+        create_versus(self, id, session, solution).await
+    }
+
+    async fn join_waiting_versus(
+        &self,
+        session: &str,
+        since: NaiveDateTime,
+    ) -> Result<Option<VersusMatch>, DbErr> {
+        join_waiting_versus(self, session, since).await
+    }
+
+    async fn update_versus(&self, game: &VersusMatch) -> Result<(), DbErr> {
+        update_versus(self, game).await
+    }
+
+    async fn settle_versus(
+        &self,
+        id: &str,
+        session: &str,
+        turn_timeout: TimeDelta,
+    ) -> Result<Option<VersusMatch>, DbErr> {
+        let txn = begin_write(self).await?;
+        let Some(game) = lock_versus(&txn, id, session).await? else {
+            return Ok(None);
+        };
+
+        let game = repo::settle(&txn, game, turn_timeout).await?;
+        txn.commit().await?;
+        Ok(Some(game))
+    }
+
+    async fn submit_to_versus(
+        &self,
+        id: &str,
+        session: &str,
+        answer: &PuzzleSolution,
+        turn_timeout: TimeDelta,
+    ) -> Result<VersusMatch, VersusError> {
+        tracing::info!("submitting {answer} to versus {id} with {session}…");
+        let txn = begin_write(self).await?;
+        let Some(game) = lock_versus(&txn, id, session).await? else {
+            tracing::warn!("no versus {id} found with session {session}!");
+            return Err(VersusError::NotFound);
+        };
+
+        let result = repo::submit_versus(&txn, game, session, answer, turn_timeout).await;
+        if let Ok(_) | Err(VersusError::GameOver(_)) = result {
+            txn.commit().await?;
+        }
+        result
+    }
+}
@@ -0,0 +1,97 @@
+//! Rates the difficulty of puzzles.
+//!
+//! A puzzle is rated in the background once inserted, then blended with the play data once its
+//! day has ended.
+
+use crate::{SOLVER, database::repo::Repos, env::DIFFICULTY_BLEND_INTERVAL};
+
+use chrono::Utc;
+use entity::{
+    PuzzleDate, PuzzleSolution, blend_difficulty, histories::Model as History, observed_difficulty,
+};
+use tokio::task::JoinHandle;
+
+/// Predicts the difficulty of a solution against [`SOLVER`], or returns [`None`] if the rating
+/// task fails.
+///
+/// See: [`Solver::difficulty`](entity::Solver::difficulty)
+pub async fn rate(solution: &PuzzleSolution) -> Option<f64> {
+    let solution = solution.clone();
+    match tokio::task::spawn_blocking(move || SOLVER.difficulty(&solution).score()).await {
+        Ok(difficulty) => Some(difficulty),
+        Err(err) => {
+            tracing::error!("failed to rate difficulty: {err}");
+            None
+        }
+    }
+}
+
+/// Spawns a task rating a newly inserted puzzle and storing its predicted difficulty, so that the
+/// request inserting it does not wait for the solver. Puzzles left unrated are rated when blended.
+///
+/// See: [`rate`]
+pub fn spawn_rating(repos: Repos, date: PuzzleDate, solution: PuzzleSolution) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        if let Some(difficulty) = rate(&solution).await {
+            repos
+                .puzzles
+                .update_predicted_difficulty(&date, &solution, difficulty)
+                .await
+                .ok();
+        }
+    })
+}
+
+/// Blends the difficulties of the puzzles whose day has ended with their play data, returning the
+/// number of blended puzzles. Puzzles without finished games are left to a later blend.
+///
+/// Games flagged as suspected of cheating are left out of the play data.
+pub async fn blend_ended(repos: &Repos) -> usize {
+    let today = PuzzleDate::new(Utc::now().date_naive());
+    tracing::info!("blending difficulties before {today}…");
+
+    let mut blended = 0;
+    for puzzle in repos.puzzles.get_unblended_puzzles(&today).await {
+        let histories = repos.histories.get_histories(&puzzle.date).await;
+        let Some((observed, plays)) = observed_difficulty(
+            histories
+                .iter()
+                .filter(|history| !history.is_flagged())
+                .map(History::game_state),
+        ) else {
+            continue;
+        };
+
+        let predicted = match puzzle.difficulty {
+            Some(difficulty) => difficulty,
+            None => match rate(&puzzle.solution).await {
+                Some(difficulty) => difficulty,
+                None => continue,
+            },
+        };
+
+        let difficulty = blend_difficulty(predicted, observed, plays);
+        if repos
+            .puzzles
+            .update_difficulty(&puzzle.date, difficulty)
+            .await
+            .is_ok()
+        {
+            blended += 1;
+        }
+    }
+
+    tracing::info!("blended {blended} difficulties before {today}");
+    blended
+}
+
+/// Spawns a task calling [`blend_ended`] every [`DIFFICULTY_BLEND_INTERVAL`].
+pub fn spawn_blending(repos: Repos) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(*DIFFICULTY_BLEND_INTERVAL);
+        loop {
+            interval.tick().await;
+            blend_ended(&repos).await;
+        }
+    })
+}
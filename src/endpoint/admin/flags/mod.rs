@@ -0,0 +1,111 @@
+//! Endpoint `/admin/flags`.
+
+use crate::{database::repo::Repos, puzzle_number};
+
+use axum::{
+    Json,
+    extract::{Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use entity::{CheatFlag, GameState, PuzzleDate, histories::Model as History};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// The parameters for the get request.
+#[derive(Debug, Clone, Deserialize, utoipa::IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct GetParams {
+    /// The date of the puzzle to review, or all dates if neither it nor `number` is specified.
+    pub date: Option<String>,
+    /// The sequential number of the puzzle to review, instead of its date.
+    pub number: Option<u64>,
+}
+
+/// A game flagged as suspected of cheating.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct FlaggedHistory {
+    /// The date of the puzzle.
+    pub date: PuzzleDate,
+    /// The sequential number of the puzzle, if numbered.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub number: Option<u64>,
+    /// The session token of the player.
+    pub session: String,
+    /// The game state.
+    pub state: GameState,
+    /// The reasons the game is suspected of cheating.
+    pub flags: Vec<CheatFlag>,
+    /// The time taken to finish the game in milliseconds, as timed by the server.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed_in_millis: Option<i64>,
+    /// The keyed hash of the IP address of the latest submission.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
+}
+
+impl From<History> for FlaggedHistory {
+    fn from(value: History) -> Self {
+        Self {
+            state: value.game_state(),
+            completed_in_millis: value
+                .timing
+                .as_ref()
+                .and_then(|timing| timing.completed_in_millis),
+            number: puzzle_number::number_of(&value.date),
+            date: value.date,
+            session: value.session,
+            flags: value.flags.map(|flags| flags.0).unwrap_or_default(),
+            address: value.address,
+        }
+    }
+}
+
+/// The response for the get request.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[schema(as = FlagsResponse)]
+pub struct GetResponse {
+    /// The number of flagged games.
+    pub count: usize,
+    /// The flagged games, latest date first.
+    pub histories: Vec<FlaggedHistory>,
+}
+
+/// The admin reviews the games flagged as suspected of cheating, which are excluded from the
+/// aggregate results.
+#[utoipa::path(
+    get,
+    path = "/admin/flags",
+    operation_id = "get_flags",
+    tag = "admin",
+    params(GetParams),
+    responses(
+        (status = OK, description = "The flagged games.", body = GetResponse),
+        (status = BAD_REQUEST, description = "The date or the number is invalid.", body = String),
+        (status = UNAUTHORIZED, description = "The PASETO token is missing or invalid."),
+    ),
+    security(("paseto" = [])),
+)]
+pub async fn get(State(repos): State<Repos>, Query(params): Query<GetParams>) -> impl IntoResponse {
+    let date = match (params.date.is_some() || params.number.is_some())
+        .then(|| puzzle_number::resolve(params.date.as_deref(), params.number))
+        .transpose()
+    {
+        Ok(date) => date,
+        Err(err) => return (StatusCode::BAD_REQUEST, err.to_string()).into_response(),
+    };
+
+    let histories: Vec<FlaggedHistory> = repos
+        .histories
+        .get_flagged_histories()
+        .await
+        .into_iter()
+        .filter(|history| date.as_ref().is_none_or(|date| history.date == *date))
+        .map(FlaggedHistory::from)
+        .collect();
+    let response = GetResponse {
+        count: histories.len(),
+        histories,
+    };
+    (StatusCode::OK, Json(response)).into_response()
+}
@@ -0,0 +1,5 @@
+//! Endpoint `/admin`.
+//!
+//! The admin endpoints are authorized with a PASETO token, like inserting puzzles.
+
+pub mod flags;
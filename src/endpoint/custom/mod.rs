@@ -0,0 +1,217 @@
+//! Endpoint `/custom`.
+//!
+//! Custom puzzles are created by players to challenge their friends. The solution is never stored:
+//! it is carried by the puzzle code, a PASETO local token encrypted with a server key, so the code
+//! is both opaque and tamper-proof.
+
+use crate::{
+    WORDS, database::repo::Repos, endpoint::play::practice::generate_practice_id,
+    env::CUSTOM_SYMMETRIC_KEY, middleware::session::SessionToken,
+};
+
+use axum::{Extension, Json, extract::State, http::StatusCode, response::IntoResponse};
+use entity::{
+    CUSTOM_MAX_TRIES, GameState, HISTORY_MAX_TRIES, Keyboard, PUZZLE_LETTERS_COUNT, PuzzleSolution,
+    SubmitHistory, SubmitWord,
+};
+use rusty_paseto::{
+    core::{ImplicitAssertion, Key, Local, PasetoSymmetricKey, V4},
+    prelude::{CustomClaim, PasetoBuilder, PasetoParser},
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+pub mod results;
+pub mod start;
+pub mod submit;
+
+/// The implicit assertion binding the puzzle codes, so they are never accepted as any other
+/// PASETO token sharing the key.
+const IMPLICIT_ASSERTION: &str = "wordle-custom-puzzle";
+
+/// The content of a custom puzzle code.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CustomCode {
+    /// The opaque puzzle id.
+    pub id: String,
+    /// The solution.
+    pub solution: PuzzleSolution,
+    /// The maximum number of tries allowed.
+    pub max_tries: usize,
+    /// Whether any word is accepted, rather than only the words of the dictionary.
+    pub allow_any_word: bool,
+}
+
+impl CustomCode {
+    /// Encrypts the puzzle into a code without expiration.
+    ///
+    /// # Panics
+    ///
+    /// Panics if unable to generate a PASETO token.
+    ///
+    /// See: [`CUSTOM_SYMMETRIC_KEY`]
+    pub fn encrypt(&self) -> String {
+        let key: PasetoSymmetricKey<_, _> = Key::from(*CUSTOM_SYMMETRIC_KEY).into();
+
+        PasetoBuilder::<V4, Local>::default()
+            .set_claim(CustomClaim::try_from(("id", self.id.clone())).unwrap())
+            .set_claim(CustomClaim::try_from(("solution", self.solution.to_string())).unwrap())
+            .set_claim(CustomClaim::try_from(("max_tries", self.max_tries)).unwrap())
+            .set_claim(CustomClaim::try_from(("allow_any_word", self.allow_any_word)).unwrap())
+            .set_implicit_assertion(ImplicitAssertion::from(IMPLICIT_ASSERTION))
+            .set_no_expiration_danger_acknowledged()
+            .build(&key)
+            .unwrap()
+    }
+
+    /// Decrypts a code, or returns [`None`] if it is invalid or has been tampered with.
+    pub fn decrypt(code: &str) -> Option<Self> {
+        let key: PasetoSymmetricKey<_, _> = Key::from(*CUSTOM_SYMMETRIC_KEY).into();
+        let claims = PasetoParser::<V4, Local>::default()
+            .set_implicit_assertion(ImplicitAssertion::from(IMPLICIT_ASSERTION))
+            .parse(code, &key)
+            .ok()?;
+
+        Some(Self {
+            id: claims["id"].as_str()?.to_owned(),
+            solution: PuzzleSolution::try_from(claims["solution"].as_str()?).ok()?,
+            max_tries: usize::try_from(claims["max_tries"].as_u64()?).ok()?,
+            allow_any_word: claims["allow_any_word"].as_bool()?,
+        })
+    }
+
+    /// Returns whether a word is accepted as an answer.
+    pub fn accepts(&self, answer: &PuzzleSolution) -> bool {
+        let answer = answer.to_string();
+        self.allow_any_word
+            || WORDS.contains(&&answer[..])
+            || answer.eq_ignore_ascii_case(&self.solution.to_string())
+    }
+}
+
+/// The state of a custom puzzle game.
+#[derive(Debug, Clone, Default, Serialize, ToSchema)]
+#[schema(as = CustomGameResponse)]
+pub struct CustomResponse {
+    /// The number of letters in the word.
+    pub letters_count: usize,
+    /// The number of remaining tries.
+    pub remaining_tries: usize,
+    /// The game state.
+    pub state: GameState,
+    /// The solution, revealed once the game is lost.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub solution: Option<PuzzleSolution>,
+    /// The history of submitted words.
+    pub history: Vec<SubmitWord>,
+    /// The letter states known from the history.
+    pub keyboard: Keyboard,
+}
+
+impl CustomResponse {
+    /// Creates a response from the submit history of a custom puzzle.
+    pub fn new(
+        code: &CustomCode,
+        history: &SubmitHistory<PUZZLE_LETTERS_COUNT, CUSTOM_MAX_TRIES>,
+    ) -> Self {
+        let state = history.game_state_within(code.max_tries);
+        Self {
+            letters_count: history.letters_count(),
+            remaining_tries: code.max_tries.saturating_sub(history.len()),
+            state,
+            solution: state.reveals_solution().then(|| code.solution.clone()),
+            history: history.0.clone(),
+            keyboard: history.keyboard(),
+        }
+    }
+}
+
+/// The payload for the post request.
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+#[schema(as = CustomPayload)]
+pub struct PostPayload {
+    /// The solution of the puzzle.
+    pub word: String,
+    /// The maximum number of tries allowed, from 1 to 12. Defaults to 6.
+    pub max_tries: Option<usize>,
+    /// Whether any word is accepted as the solution and the answers, rather than only the words
+    /// of the dictionary.
+    #[serde(default)]
+    pub allow_any_word: bool,
+}
+
+/// The response for the post request.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[schema(as = CustomCreateResponse)]
+pub struct PostResponse {
+    /// The opaque puzzle code to share.
+    pub code: String,
+    /// The maximum number of tries allowed.
+    pub max_tries: usize,
+}
+
+/// The client creates a custom puzzle to share.
+#[utoipa::path(
+    post,
+    path = "/custom",
+    operation_id = "create_custom_puzzle",
+    tag = "custom",
+    request_body = PostPayload,
+    responses(
+        (status = CREATED, description = "The puzzle has been created.", body = PostResponse),
+        (status = BAD_REQUEST, description = "The word or the number of tries is invalid."),
+        (status = NOT_FOUND, description = "The session token is missing or invalid."),
+        (status = INTERNAL_SERVER_ERROR, description = "A database error occurred.", body = String),
+    ),
+    security(("session" = [])),
+)]
+pub async fn post(
+    State(repos): State<Repos>,
+    session: Option<Extension<SessionToken>>,
+    Json(payload): Json<PostPayload>,
+) -> impl IntoResponse {
+    let session = match session {
+        Some(Extension(SessionToken(session))) => session,
+        None => return (StatusCode::NOT_FOUND).into_response(),
+    };
+
+    let max_tries = payload.max_tries.unwrap_or(HISTORY_MAX_TRIES);
+    let solution = match PuzzleSolution::try_from(&payload.word[..]) {
+        Ok(solution)
+            if (1..=CUSTOM_MAX_TRIES).contains(&max_tries)
+                && (payload.allow_any_word || WORDS.contains(&&solution.to_string()[..])) =>
+        {
+            solution
+        }
+        _ => return (StatusCode::BAD_REQUEST).into_response(),
+    };
+
+    match repos.sessions.insert_or_update_session(&session).await {
+        Ok(_) => {}
+        Err(err) => return (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()).into_response(),
+    }
+
+    match repos
+        .customs
+        .create_custom_puzzle(&generate_practice_id(), &session, max_tries)
+        .await
+    {
+        Ok(puzzle) => {
+            let code = CustomCode {
+                id: puzzle.id,
+                solution,
+                max_tries,
+                allow_any_word: payload.allow_any_word,
+            };
+            (
+                StatusCode::CREATED,
+                Json(PostResponse {
+                    code: code.encrypt(),
+                    max_tries,
+                }),
+            )
+                .into_response()
+        }
+        Err(err) => (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()).into_response(),
+    }
+}
@@ -0,0 +1,99 @@
+//! Endpoint `/custom/{code}/results`.
+
+use crate::{
+    database::repo::Repos, endpoint::custom::CustomCode, middleware::session::SessionToken,
+};
+
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use entity::{GameState, PuzzleSolution};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// The response for the get request.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[schema(as = CustomResultsResponse)]
+pub struct GetResponse {
+    /// The solution of the puzzle.
+    pub solution: PuzzleSolution,
+    /// The maximum number of tries allowed.
+    pub max_tries: usize,
+    /// The number of players who started the puzzle.
+    pub players: usize,
+    /// The number of games still in progress.
+    pub in_progress: usize,
+    /// The number of games won.
+    pub won: usize,
+    /// The number of games lost.
+    pub lost: usize,
+    /// The number of games won in each number of tries, starting from 1.
+    pub distribution: Vec<usize>,
+}
+
+/// The creator requests the aggregate results of a custom puzzle.
+#[utoipa::path(
+    get,
+    path = "/custom/{code}/results",
+    operation_id = "get_custom_results",
+    tag = "custom",
+    params(("code" = String, Path, description = "The puzzle code.")),
+    responses(
+        (status = OK, description = "The aggregate results of the puzzle.", body = GetResponse),
+        (status = BAD_REQUEST, description = "The puzzle code is invalid."),
+        (status = FORBIDDEN, description = "The session did not create the puzzle."),
+        (status = NOT_FOUND, description = "The session token is missing or invalid, or the puzzle no longer exists."),
+    ),
+    security(("session" = [])),
+)]
+pub async fn get(
+    State(repos): State<Repos>,
+    session: Option<Extension<SessionToken>>,
+    Path(code): Path<String>,
+) -> impl IntoResponse {
+    let session = match session {
+        Some(Extension(SessionToken(session))) => session,
+        None => return (StatusCode::NOT_FOUND).into_response(),
+    };
+
+    let Some(code) = CustomCode::decrypt(&code) else {
+        return (StatusCode::BAD_REQUEST).into_response();
+    };
+
+    match repos.customs.get_custom_puzzle(&code.id).await {
+        Some(puzzle) if puzzle.session == session => {}
+        Some(_) => return (StatusCode::FORBIDDEN).into_response(),
+        None => return (StatusCode::NOT_FOUND).into_response(),
+    }
+
+    let mut response = GetResponse {
+        solution: code.solution,
+        max_tries: code.max_tries,
+        players: 0,
+        in_progress: 0,
+        won: 0,
+        lost: 0,
+        distribution: vec![0; code.max_tries],
+    };
+    for history in repos.customs.get_custom_histories(&code.id).await {
+        response.players += 1;
+        match history.game_state(code.max_tries) {
+            GameState::InProgress => response.in_progress += 1,
+            GameState::Won { tries } => {
+                response.won += 1;
+                if let Some(count) = tries
+                    .checked_sub(1)
+                    .and_then(|index| response.distribution.get_mut(index))
+                {
+                    *count += 1;
+                }
+            }
+            GameState::Lost => response.lost += 1,
+        }
+    }
+
+    (StatusCode::OK, Json(response)).into_response()
+}
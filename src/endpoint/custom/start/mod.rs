@@ -0,0 +1,80 @@
+//! Endpoint `/custom/{code}/start`.
+
+use crate::{
+    database::repo::Repos,
+    endpoint::custom::{CustomCode, CustomResponse},
+    middleware::session::SessionToken,
+};
+
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use chrono::Utc;
+use entity::custom_histories::Model as CustomHistory;
+
+/// The client requests to start a custom puzzle.
+#[utoipa::path(
+    get,
+    path = "/custom/{code}/start",
+    operation_id = "start_custom_puzzle",
+    tag = "custom",
+    params(("code" = String, Path, description = "The puzzle code.")),
+    responses(
+        (status = OK, description = "The puzzle has already been started.", body = CustomResponse),
+        (status = CREATED, description = "The puzzle has been started.", body = CustomResponse),
+        (status = BAD_REQUEST, description = "The puzzle code is invalid."),
+        (status = NOT_FOUND, description = "The session token is missing or invalid, or the puzzle no longer exists."),
+        (status = INTERNAL_SERVER_ERROR, description = "A database error occurred.", body = String),
+    ),
+    security(("session" = [])),
+)]
+pub async fn get(
+    State(repos): State<Repos>,
+    session: Option<Extension<SessionToken>>,
+    Path(code): Path<String>,
+) -> impl IntoResponse {
+    let session = match session {
+        Some(Extension(SessionToken(session))) => session,
+        None => return (StatusCode::NOT_FOUND).into_response(),
+    };
+
+    let Some(code) = CustomCode::decrypt(&code) else {
+        return (StatusCode::BAD_REQUEST).into_response();
+    };
+
+    if repos.customs.get_custom_puzzle(&code.id).await.is_none() {
+        return (StatusCode::NOT_FOUND).into_response();
+    }
+
+    match repos.sessions.insert_or_update_session(&session).await {
+        Ok(_) => {}
+        Err(err) => return (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()).into_response(),
+    }
+
+    if let Some(history) = repos.customs.get_custom_history(&code.id, &session).await {
+        let history = history.submit_history.unwrap_or_default();
+        return (StatusCode::OK, Json(CustomResponse::new(&code, &history))).into_response();
+    }
+
+    let history = CustomHistory {
+        puzzle: code.id.clone(),
+        session,
+        submit_history: None,
+        uploaded_at: Utc::now().naive_utc(),
+    };
+    match repos
+        .customs
+        .insert_or_update_custom_history(&history)
+        .await
+    {
+        Ok(()) => (
+            StatusCode::CREATED,
+            Json(CustomResponse::new(&code, &Default::default())),
+        )
+            .into_response(),
+        Err(err) => (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()).into_response(),
+    }
+}
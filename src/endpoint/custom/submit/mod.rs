@@ -0,0 +1,83 @@
+//! Endpoint `/custom/{code}/submit`.
+
+use crate::{
+    database::repo::Repos,
+    endpoint::{
+        custom::{CustomCode, CustomResponse},
+        play::submit::PostPayload,
+    },
+    middleware::session::SessionToken,
+};
+
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use entity::{PuzzleSolution, SubmitWord};
+
+/// The client submits a word to solve a custom puzzle.
+#[utoipa::path(
+    post,
+    path = "/custom/{code}/submit",
+    operation_id = "submit_custom_answer",
+    tag = "custom",
+    params(("code" = String, Path, description = "The puzzle code.")),
+    request_body = PostPayload,
+    responses(
+        (status = ACCEPTED, description = "The answer has been submitted.", body = CustomResponse),
+        (status = BAD_REQUEST, description = "The puzzle code or the answer is invalid."),
+        (status = CONFLICT, description = "The game is over and accepts no further answers.", body = CustomResponse),
+        (status = NOT_FOUND, description = "The session token is missing or invalid, or the puzzle has not been started."),
+        (status = INTERNAL_SERVER_ERROR, description = "A database error occurred.", body = String),
+    ),
+    security(("session" = [])),
+)]
+pub async fn post(
+    State(repos): State<Repos>,
+    session: Option<Extension<SessionToken>>,
+    Path(code): Path<String>,
+    Json(payload): Json<PostPayload>,
+) -> impl IntoResponse {
+    let session = match session {
+        Some(Extension(SessionToken(session))) => session,
+        None => return (StatusCode::NOT_FOUND).into_response(),
+    };
+
+    let Some(code) = CustomCode::decrypt(&code) else {
+        return (StatusCode::BAD_REQUEST).into_response();
+    };
+    let answer = match PuzzleSolution::try_from(&payload.answer[..]) {
+        Ok(answer) if code.accepts(&answer) => answer,
+        _ => return (StatusCode::BAD_REQUEST).into_response(),
+    };
+
+    let Some(mut history) = repos.customs.get_custom_history(&code.id, &session).await else {
+        return (StatusCode::NOT_FOUND).into_response();
+    };
+
+    let mut submit_history = history.submit_history.take().unwrap_or_default();
+    if submit_history.game_state_within(code.max_tries).is_over() {
+        return (
+            StatusCode::CONFLICT,
+            Json(CustomResponse::new(&code, &submit_history)),
+        )
+            .into_response();
+    }
+
+    if let Err(err) = submit_history.submit(SubmitWord::tint(&answer, &code.solution)) {
+        return (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()).into_response();
+    }
+    let response = CustomResponse::new(&code, &submit_history);
+    history.submit_history = Some(submit_history);
+
+    match repos
+        .customs
+        .insert_or_update_custom_history(&history)
+        .await
+    {
+        Ok(()) => (StatusCode::ACCEPTED, Json(response)).into_response(),
+        Err(err) => (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()).into_response(),
+    }
+}
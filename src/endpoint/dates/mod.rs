@@ -1,30 +1,62 @@
 //! Endpoint `/dates`.
 
-use crate::database::{self, tables::puzzles::get_dates};
+use crate::{database::repo::Repos, puzzle_number};
 
-use axum::{Json, http::StatusCode, response::IntoResponse};
+use axum::{Json, extract::State, http::StatusCode, response::IntoResponse};
 use entity::PuzzleDate;
 use serde::Serialize;
+use utoipa::ToSchema;
+
+/// An available puzzle date.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct DateItem {
+    /// The date of the puzzle.
+    pub date: PuzzleDate,
+    /// The sequential number of the puzzle, if numbered.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub number: Option<u64>,
+}
+
+impl From<PuzzleDate> for DateItem {
+    fn from(date: PuzzleDate) -> Self {
+        Self {
+            number: puzzle_number::number_of(&date),
+            date,
+        }
+    }
+}
 
 /// The response for the get request.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[schema(as = DatesResponse)]
 pub struct GetResponse {
     /// The total count of available dates.
     pub count: usize,
     /// The available puzzle dates.
     pub dates: Vec<PuzzleDate>,
+    /// The available puzzle dates with their numbers, in the same order as `dates`.
+    pub items: Vec<DateItem>,
 }
 
 /// The client gets the available puzzle dates.
-pub async fn get() -> impl IntoResponse {
-    let db = database::acquire_or_response!();
-
-    let dates: Vec<PuzzleDate> = get_dates(&db).await;
+#[utoipa::path(
+    get,
+    path = "/dates",
+    operation_id = "get_dates",
+    tag = "puzzles",
+    responses(
+        (status = OK, description = "The available puzzle dates.", body = GetResponse),
+    ),
+)]
+pub async fn get(State(repos): State<Repos>) -> impl IntoResponse {
+    let dates: Vec<PuzzleDate> = repos.puzzles.get_dates().await;
+    let items = dates.iter().cloned().map(DateItem::from).collect();
     (
         StatusCode::OK,
         Json(GetResponse {
             count: dates.len(),
             dates,
+            items,
         }),
     )
         .into_response()
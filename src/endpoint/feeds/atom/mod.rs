@@ -0,0 +1,76 @@
+//! Endpoint `/feed.atom`.
+
+use crate::{
+    config::{Config as _, feeds::FeedsConfig},
+    database::repo::Repos,
+    endpoint::feeds::{FeedEntry, entries, escape_xml},
+};
+
+use axum::{
+    extract::State,
+    http::{StatusCode, header},
+    response::IntoResponse,
+};
+use chrono::{SecondsFormat, Utc};
+
+/// The content type of Atom feeds.
+pub const CONTENT_TYPE: &str = "application/atom+xml; charset=utf-8";
+
+/// The client gets the puzzle schedule as an Atom feed.
+#[utoipa::path(
+    get,
+    path = "/feed.atom",
+    operation_id = "get_feed_atom",
+    tag = "puzzles",
+    responses(
+        (status = OK, description = "The puzzle schedule.", body = String, content_type = "application/atom+xml"),
+    ),
+)]
+pub async fn get(State(repos): State<Repos>) -> impl IntoResponse {
+    tracing::info!("rendering atom feed…");
+    let config = FeedsConfig::read().unwrap_or_default();
+    let entries = entries(&repos, &config).await;
+    (
+        StatusCode::OK,
+        [(header::CONTENT_TYPE, CONTENT_TYPE)],
+        render(&config, &entries),
+    )
+}
+
+/// Renders the entries as an Atom document.
+pub fn render(config: &FeedsConfig, entries: &[FeedEntry]) -> String {
+    let updated = entries
+        .first()
+        .map_or_else(Utc::now, FeedEntry::published)
+        .to_rfc3339_opts(SecondsFormat::Secs, true);
+
+    let mut atom = String::from("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n");
+    atom.push_str("<feed xmlns=\"http://www.w3.org/2005/Atom\">\n");
+    atom.push_str(&format!("<title>{}</title>\n", escape_xml(&config.title)));
+    atom.push_str(&format!(
+        "<id>urn:{}:feed</id>\n",
+        escape_xml(clap::crate_name!())
+    ));
+    if let Some(site) = &config.site {
+        atom.push_str(&format!("<link href=\"{}\"/>\n", escape_xml(site)));
+    }
+    atom.push_str(&format!("<updated>{updated}</updated>\n"));
+    atom.push_str(&format!(
+        "<author><name>{}</name></author>\n",
+        escape_xml(&config.title)
+    ));
+    for entry in entries {
+        let published = entry.published().to_rfc3339_opts(SecondsFormat::Secs, true);
+        atom.push_str("<entry>\n");
+        atom.push_str(&format!("<title>{}</title>\n", escape_xml(&entry.title)));
+        atom.push_str(&format!("<id>{}</id>\n", escape_xml(&entry.id())));
+        if let Some(link) = &entry.link {
+            atom.push_str(&format!("<link href=\"{}\"/>\n", escape_xml(link)));
+        }
+        atom.push_str(&format!("<published>{published}</published>\n"));
+        atom.push_str(&format!("<updated>{published}</updated>\n"));
+        atom.push_str("</entry>\n");
+    }
+    atom.push_str("</feed>\n");
+    atom
+}
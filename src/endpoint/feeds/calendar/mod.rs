@@ -0,0 +1,108 @@
+//! Endpoint `/calendar.ics`.
+
+use crate::{
+    config::{Config as _, feeds::FeedsConfig},
+    database::repo::Repos,
+    endpoint::feeds::{FeedEntry, entries},
+};
+
+use axum::{
+    extract::State,
+    http::{StatusCode, header},
+    response::IntoResponse,
+};
+use chrono::{Days, Utc};
+
+/// The content type of iCalendar documents.
+pub const CONTENT_TYPE: &str = "text/calendar; charset=utf-8";
+
+/// The maximum length of a content line in octets, excluding the line break.
+const MAX_LINE_LENGTH: usize = 75;
+
+/// The client gets the puzzle schedule as an iCalendar document of all-day events.
+#[utoipa::path(
+    get,
+    path = "/calendar.ics",
+    operation_id = "get_calendar",
+    tag = "puzzles",
+    responses(
+        (status = OK, description = "The puzzle schedule.", body = String, content_type = "text/calendar"),
+    ),
+)]
+pub async fn get(State(repos): State<Repos>) -> impl IntoResponse {
+    tracing::info!("rendering calendar…");
+    let config = FeedsConfig::read().unwrap_or_default();
+    let mut entries = entries(&repos, &config).await;
+    entries.reverse();
+    (
+        StatusCode::OK,
+        [(header::CONTENT_TYPE, CONTENT_TYPE)],
+        render(&config, &entries),
+    )
+}
+
+/// Renders the entries as an iCalendar document.
+pub fn render(config: &FeedsConfig, entries: &[FeedEntry]) -> String {
+    let stamp = Utc::now().format("%Y%m%dT%H%M%SZ").to_string();
+
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_owned(),
+        "VERSION:2.0".to_owned(),
+        format!("PRODID:-//KessokuTeaTime//{}//EN", clap::crate_name!()),
+        "CALSCALE:GREGORIAN".to_owned(),
+        format!("X-WR-CALNAME:{}", escape_text(&config.title)),
+    ];
+    for entry in entries {
+        let date = entry.date.inner();
+        lines.push("BEGIN:VEVENT".to_owned());
+        lines.push(format!("UID:{}", entry.id()));
+        lines.push(format!("DTSTAMP:{stamp}"));
+        lines.push(format!("DTSTART;VALUE=DATE:{}", date.format("%Y%m%d")));
+        if let Some(end) = date.checked_add_days(Days::new(1)) {
+            lines.push(format!("DTEND;VALUE=DATE:{}", end.format("%Y%m%d")));
+        }
+        lines.push(format!("SUMMARY:{}", escape_text(&entry.title)));
+        if let Some(link) = &entry.link {
+            lines.push(format!("URL:{link}"));
+        }
+        lines.push("TRANSP:TRANSPARENT".to_owned());
+        lines.push("END:VEVENT".to_owned());
+    }
+    lines.push("END:VCALENDAR".to_owned());
+
+    lines.iter().map(|line| fold(line)).collect()
+}
+
+/// Escapes text for iCalendar property values.
+fn escape_text(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' | ';' | ',' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '\n' => escaped.push_str("\\n"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Folds a content line into lines of at most [`MAX_LINE_LENGTH`] octets, each terminated by
+/// CRLF, without splitting characters.
+fn fold(line: &str) -> String {
+    let mut folded = String::with_capacity(line.len() + 2);
+    let mut length = 0;
+    for c in line.chars() {
+        if length + c.len_utf8() > MAX_LINE_LENGTH {
+            folded.push_str("\r\n ");
+            // the leading space of the continuation counts towards its length
+            length = 1;
+        }
+        folded.push(c);
+        length += c.len_utf8();
+    }
+    folded.push_str("\r\n");
+    folded
+}
@@ -0,0 +1,77 @@
+//! Endpoints `/feed.rss`, `/feed.atom` and `/calendar.ics`.
+//!
+//! The feeds list the past and upcoming puzzles by date and number for community sites to link
+//! to, and never include the solutions.
+
+use crate::{config::feeds::FeedsConfig, database::repo::Repos, puzzle_number};
+
+use chrono::{DateTime, NaiveTime, Utc};
+use entity::PuzzleDate;
+
+pub mod atom;
+pub mod calendar;
+pub mod rss;
+
+/// A puzzle listed in the feeds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeedEntry {
+    /// The date of the puzzle.
+    pub date: PuzzleDate,
+    /// The sequential number of the puzzle, if numbered.
+    pub number: Option<u64>,
+    /// The title of the entry.
+    pub title: String,
+    /// The link to the puzzle, if a link template is configured.
+    pub link: Option<String>,
+}
+
+impl FeedEntry {
+    /// The time the puzzle is published: the start of its date in UTC.
+    pub fn published(&self) -> DateTime<Utc> {
+        self.date.inner().and_time(NaiveTime::MIN).and_utc()
+    }
+
+    /// A stable identifier of the entry.
+    pub fn id(&self) -> String {
+        format!("urn:{}:puzzle:{}", clap::crate_name!(), self.date)
+    }
+}
+
+/// Lists the puzzles from [`PuzzleRepo::get_dates`](crate::database::repo::PuzzleRepo::get_dates),
+/// latest first.
+async fn entries(repos: &Repos, config: &FeedsConfig) -> Vec<FeedEntry> {
+    let mut dates = repos.puzzles.get_dates().await;
+    dates.sort_unstable_by(|a, b| b.cmp(a));
+    dates
+        .into_iter()
+        .map(|date| {
+            let number = puzzle_number::number_of(&date);
+            let title = match number {
+                Some(number) => format!("{} #{number} ({date})", config.title),
+                None => format!("{} ({date})", config.title),
+            };
+            FeedEntry {
+                link: config.link(&date, number),
+                date,
+                number,
+                title,
+            }
+        })
+        .collect()
+}
+
+/// Escapes text for XML content and attribute values.
+fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
@@ -0,0 +1,75 @@
+//! Endpoint `/feed.rss`.
+
+use crate::{
+    config::{Config as _, feeds::FeedsConfig},
+    database::repo::Repos,
+    endpoint::feeds::{FeedEntry, entries, escape_xml},
+};
+
+use axum::{
+    extract::State,
+    http::{StatusCode, header},
+    response::IntoResponse,
+};
+
+/// The content type of RSS feeds.
+pub const CONTENT_TYPE: &str = "application/rss+xml; charset=utf-8";
+
+/// The client gets the puzzle schedule as an RSS 2.0 feed.
+#[utoipa::path(
+    get,
+    path = "/feed.rss",
+    operation_id = "get_feed_rss",
+    tag = "puzzles",
+    responses(
+        (status = OK, description = "The puzzle schedule.", body = String, content_type = "application/rss+xml"),
+    ),
+)]
+pub async fn get(State(repos): State<Repos>) -> impl IntoResponse {
+    tracing::info!("rendering rss feed…");
+    let config = FeedsConfig::read().unwrap_or_default();
+    let entries = entries(&repos, &config).await;
+    (
+        StatusCode::OK,
+        [(header::CONTENT_TYPE, CONTENT_TYPE)],
+        render(&config, &entries),
+    )
+}
+
+/// Renders the entries as an RSS 2.0 document.
+pub fn render(config: &FeedsConfig, entries: &[FeedEntry]) -> String {
+    let mut rss = String::from("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n");
+    rss.push_str("<rss version=\"2.0\">\n<channel>\n");
+    rss.push_str(&format!("<title>{}</title>\n", escape_xml(&config.title)));
+    if let Some(site) = &config.site {
+        rss.push_str(&format!("<link>{}</link>\n", escape_xml(site)));
+    }
+    rss.push_str(&format!(
+        "<description>{}</description>\n",
+        escape_xml(&format!("The puzzle schedule of {}.", config.title))
+    ));
+    if let Some(entry) = entries.first() {
+        rss.push_str(&format!(
+            "<lastBuildDate>{}</lastBuildDate>\n",
+            entry.published().to_rfc2822()
+        ));
+    }
+    for entry in entries {
+        rss.push_str("<item>\n");
+        rss.push_str(&format!("<title>{}</title>\n", escape_xml(&entry.title)));
+        if let Some(link) = &entry.link {
+            rss.push_str(&format!("<link>{}</link>\n", escape_xml(link)));
+        }
+        rss.push_str(&format!(
+            "<guid isPermaLink=\"false\">{}</guid>\n",
+            escape_xml(&entry.id())
+        ));
+        rss.push_str(&format!(
+            "<pubDate>{}</pubDate>\n",
+            entry.published().to_rfc2822()
+        ));
+        rss.push_str("</item>\n");
+    }
+    rss.push_str("</channel>\n</rss>\n");
+    rss
+}
@@ -0,0 +1,74 @@
+//! Endpoint `/groups/join`.
+
+use crate::{
+    database::repo::Repos,
+    endpoint::groups::{GroupResponse, validate_name},
+    middleware::session::SessionToken,
+};
+
+use axum::{Extension, Json, extract::State, http::StatusCode, response::IntoResponse};
+use chrono::Utc;
+use entity::group_members::Model as GroupMember;
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+/// The payload for the post request.
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+#[schema(as = JoinGroupPayload)]
+pub struct PostPayload {
+    /// The invite code of the group, which is case-insensitive.
+    pub invite_code: String,
+    /// The nickname the player is shown under in the group.
+    pub nickname: String,
+}
+
+/// The player joins a friends group with its invite code. Joining again changes the nickname.
+#[utoipa::path(
+    post,
+    path = "/groups/join",
+    operation_id = "join_group",
+    tag = "groups",
+    request_body = PostPayload,
+    responses(
+        (status = OK, description = "The player is a member of the group.", body = GroupResponse),
+        (status = BAD_REQUEST, description = "The nickname is empty or too long."),
+        (status = NOT_FOUND, description = "The session token is missing or invalid, or no group has the invite code."),
+        (status = INTERNAL_SERVER_ERROR, description = "A database error occurred.", body = String),
+    ),
+    security(("session" = [])),
+)]
+pub async fn post(
+    State(repos): State<Repos>,
+    session: Option<Extension<SessionToken>>,
+    Json(payload): Json<PostPayload>,
+) -> impl IntoResponse {
+    let session = match session {
+        Some(Extension(SessionToken(session))) => session,
+        None => return (StatusCode::NOT_FOUND).into_response(),
+    };
+
+    let Some(nickname) = validate_name(&payload.nickname) else {
+        return (StatusCode::BAD_REQUEST).into_response();
+    };
+
+    let invite_code = payload.invite_code.trim().to_uppercase();
+    let Some(group) = repos.groups.get_group_by_invite_code(&invite_code).await else {
+        return (StatusCode::NOT_FOUND).into_response();
+    };
+
+    match repos.sessions.insert_or_update_session(&session).await {
+        Ok(_) => {}
+        Err(err) => return (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()).into_response(),
+    }
+
+    let member = GroupMember {
+        group: group.id.clone(),
+        session,
+        nickname: nickname.to_owned(),
+        joined_at: Utc::now().naive_utc(),
+    };
+    match repos.groups.insert_or_update_group_member(&member).await {
+        Ok(()) => (StatusCode::OK, Json(GroupResponse::from(group))).into_response(),
+        Err(err) => (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()).into_response(),
+    }
+}
@@ -0,0 +1,223 @@
+//! Endpoint `/groups/{id}/leaderboard`.
+//!
+//! The leaderboard is computed from the daily histories of the members. A game won in `t` tries
+//! scores `HISTORY_MAX_TRIES + 1 - t` points, so a first-try win scores the most, and a lost game
+//! scores nothing. Only the histories of the month, the week and the last [`STREAK_MAX_DAYS`] are
+//! read, so longer streaks are capped.
+
+use std::collections::{BTreeSet, HashMap};
+
+use crate::{database::repo::Repos, middleware::session::SessionToken, puzzle_number};
+
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use chrono::{Datelike as _, Days, NaiveDate, Utc};
+use entity::{
+    GameState, HISTORY_MAX_TRIES, PuzzleDate, group_members::Model as GroupMember,
+    histories::Model as History,
+};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// The maximum number of consecutive days counted in a streak.
+pub const STREAK_MAX_DAYS: u64 = 366;
+
+/// Returns the first date of the histories a leaderboard of a day is computed from.
+pub fn window_start(today: NaiveDate) -> NaiveDate {
+    let week_start = today - Days::new(today.weekday().num_days_from_monday().into());
+    let month_start = today.with_day(1).unwrap_or(today);
+    let streak_start = today - Days::new(STREAK_MAX_DAYS);
+    week_start.min(month_start).min(streak_start)
+}
+
+/// Returns the score of a finished game, or `None` if it is in progress.
+pub fn score(state: GameState) -> Option<usize> {
+    match state {
+        GameState::InProgress => None,
+        GameState::Won { tries } => Some((HISTORY_MAX_TRIES + 1).saturating_sub(tries)),
+        GameState::Lost => Some(0),
+    }
+}
+
+/// A member of a group and their results.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct LeaderboardEntry {
+    /// The nickname of the member in the group.
+    pub nickname: String,
+    /// Whether the member is the requesting player.
+    pub is_you: bool,
+    /// The game state of today's puzzle, which is missing if it has not been started.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub today_state: Option<GameState>,
+    /// The score of today's puzzle, once it has been finished.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub today_score: Option<usize>,
+    /// The total score since Monday.
+    pub weekly_score: usize,
+    /// The total score since the first day of the month.
+    pub monthly_score: usize,
+    /// The average number of guesses of the games won since the first day of the month.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub average_guesses: Option<f64>,
+    /// The number of consecutive days won, up to today or, if today is not finished, yesterday,
+    /// capped at [`STREAK_MAX_DAYS`].
+    pub streak: usize,
+}
+
+impl LeaderboardEntry {
+    /// Computes the entry of a member from their histories.
+    pub fn new(
+        member: &GroupMember,
+        histories: &[&History],
+        today: NaiveDate,
+        session: &str,
+    ) -> Self {
+        let week_start = today - Days::new(today.weekday().num_days_from_monday().into());
+        let month_start = today.with_day(1).unwrap_or(today);
+
+        let mut today_state = None;
+        let (mut weekly_score, mut monthly_score) = (0, 0);
+        let (mut won_count, mut won_tries) = (0_usize, 0_usize);
+        let mut won_dates = BTreeSet::new();
+        for history in histories {
+            let date = history.date.0;
+            let state = history.game_state();
+            if date == today {
+                today_state = Some(state);
+            }
+            if let GameState::Won { .. } = state {
+                won_dates.insert(date);
+            }
+            let points = score(state).unwrap_or_default();
+            if (week_start..=today).contains(&date) {
+                weekly_score += points;
+            }
+            if (month_start..=today).contains(&date) {
+                monthly_score += points;
+                if let GameState::Won { tries } = state {
+                    won_count += 1;
+                    won_tries += tries;
+                }
+            }
+        }
+
+        let mut day = match today_state {
+            Some(GameState::InProgress) | None => today.pred_opt(),
+            Some(_) => Some(today),
+        };
+        let mut streak = 0;
+        while let Some(date) = day
+            && won_dates.contains(&date)
+            && streak < STREAK_MAX_DAYS as usize
+        {
+            streak += 1;
+            day = date.pred_opt();
+        }
+
+        Self {
+            nickname: member.nickname.clone(),
+            is_you: member.session == session,
+            today_state,
+            today_score: today_state.and_then(score),
+            weekly_score,
+            monthly_score,
+            average_guesses: (won_count > 0).then(|| won_tries as f64 / won_count as f64),
+            streak,
+        }
+    }
+}
+
+/// The response for the get request.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[schema(as = LeaderboardResponse)]
+pub struct GetResponse {
+    /// The display name of the group.
+    pub name: String,
+    /// The date of today's puzzle.
+    pub date: PuzzleDate,
+    /// The sequential number of today's puzzle, if numbered.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub number: Option<u64>,
+    /// The members, sorted by weekly score, then monthly score, then nickname.
+    pub entries: Vec<LeaderboardEntry>,
+}
+
+/// The member of a group gets its leaderboard.
+#[utoipa::path(
+    get,
+    path = "/groups/{id}/leaderboard",
+    operation_id = "get_group_leaderboard",
+    tag = "groups",
+    params(("id" = String, Path, description = "The opaque id of the group.")),
+    responses(
+        (status = OK, description = "The leaderboard of the group.", body = GetResponse),
+        (status = FORBIDDEN, description = "The player is not a member of the group."),
+        (status = NOT_FOUND, description = "The session token is missing or invalid, or the group does not exist."),
+    ),
+    security(("session" = [])),
+)]
+pub async fn get(
+    State(repos): State<Repos>,
+    session: Option<Extension<SessionToken>>,
+    Path(id): Path<String>,
+) -> impl IntoResponse {
+    let session = match session {
+        Some(Extension(SessionToken(session))) => session,
+        None => return (StatusCode::NOT_FOUND).into_response(),
+    };
+
+    let Some(group) = repos.groups.get_group(&id).await else {
+        return (StatusCode::NOT_FOUND).into_response();
+    };
+    let members = repos.groups.get_group_members(&id).await;
+    if !members.iter().any(|member| member.session == session) {
+        return (StatusCode::FORBIDDEN).into_response();
+    }
+
+    let sessions: Vec<String> = members
+        .iter()
+        .map(|member| member.session.clone())
+        .collect();
+    let today = Utc::now().date_naive();
+    let since = PuzzleDate::new(window_start(today));
+    let histories = repos
+        .histories
+        .get_session_histories(&sessions, &since)
+        .await;
+    let mut by_session: HashMap<&str, Vec<&History>> = HashMap::new();
+    for history in &histories {
+        by_session
+            .entry(&history.session)
+            .or_default()
+            .push(history);
+    }
+
+    let mut entries: Vec<_> = members
+        .iter()
+        .map(|member| {
+            let histories = by_session
+                .get(member.session.as_str())
+                .map_or(&[][..], Vec::as_slice);
+            LeaderboardEntry::new(member, histories, today, &session)
+        })
+        .collect();
+    entries.sort_by(|a, b| {
+        b.weekly_score
+            .cmp(&a.weekly_score)
+            .then(b.monthly_score.cmp(&a.monthly_score))
+            .then_with(|| a.nickname.cmp(&b.nickname))
+    });
+
+    let date = PuzzleDate::new(today);
+    let response = GetResponse {
+        name: group.name,
+        number: puzzle_number::number_of(&date),
+        date,
+        entries,
+    };
+    (StatusCode::OK, Json(response)).into_response()
+}
@@ -0,0 +1,137 @@
+//! Endpoint `/groups`.
+//!
+//! Friends groups are private: players join them with an invite code and compare their daily
+//! histories on a leaderboard, under a nickname chosen per group.
+
+use crate::{database::repo::Repos, middleware::session::SessionToken};
+
+use axum::{Extension, Json, extract::State, http::StatusCode, response::IntoResponse};
+use entity::groups::Model as Group;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use super::play::practice::generate_practice_id;
+
+pub mod join;
+pub mod leaderboard;
+
+/// The maximum number of characters in a group name or a nickname.
+pub const MAX_NAME_LENGTH: usize = 32;
+
+/// The number of invite codes tried before giving up on creating a group.
+pub const INVITE_CODE_ATTEMPTS: usize = 3;
+
+/// Generates an invite code of 16 uppercase hexadecimal digits.
+pub fn generate_invite_code() -> String {
+    hex::encode_upper(rand::random::<[u8; 8]>())
+}
+
+/// Returns the trimmed name if it is neither empty nor longer than [`MAX_NAME_LENGTH`].
+pub fn validate_name(name: &str) -> Option<&str> {
+    let name = name.trim();
+    (!name.is_empty() && name.chars().count() <= MAX_NAME_LENGTH).then_some(name)
+}
+
+/// The payload for the post request.
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+#[schema(as = GroupPayload)]
+pub struct PostPayload {
+    /// The display name of the group.
+    pub name: String,
+    /// The nickname the creator is shown under in the group.
+    pub nickname: String,
+}
+
+/// The response for a group.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct GroupResponse {
+    /// The opaque id of the group.
+    pub id: String,
+    /// The display name of the group.
+    pub name: String,
+    /// The code to share for friends to join the group.
+    pub invite_code: String,
+}
+
+impl From<Group> for GroupResponse {
+    fn from(value: Group) -> Self {
+        Self {
+            id: value.id,
+            name: value.name,
+            invite_code: value.invite_code,
+        }
+    }
+}
+
+/// The player creates a friends group.
+#[utoipa::path(
+    post,
+    path = "/groups",
+    operation_id = "create_group",
+    tag = "groups",
+    request_body = PostPayload,
+    responses(
+        (status = CREATED, description = "The group has been created.", body = GroupResponse),
+        (status = BAD_REQUEST, description = "The name or the nickname is empty or too long."),
+        (status = NOT_FOUND, description = "The session token is missing or invalid."),
+        (status = INTERNAL_SERVER_ERROR, description = "A database error occurred.", body = String),
+    ),
+    security(("session" = [])),
+)]
+pub async fn post(
+    State(repos): State<Repos>,
+    session: Option<Extension<SessionToken>>,
+    Json(payload): Json<PostPayload>,
+) -> impl IntoResponse {
+    let session = match session {
+        Some(Extension(SessionToken(session))) => session,
+        None => return (StatusCode::NOT_FOUND).into_response(),
+    };
+
+    let (Some(name), Some(nickname)) = (
+        validate_name(&payload.name),
+        validate_name(&payload.nickname),
+    ) else {
+        return (StatusCode::BAD_REQUEST).into_response();
+    };
+
+    match repos.sessions.insert_or_update_session(&session).await {
+        Ok(_) => {}
+        Err(err) => return (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()).into_response(),
+    }
+
+    let mut attempts = 1;
+    loop {
+        let invite_code = generate_invite_code();
+        let created = repos
+            .groups
+            .create_group(
+                &generate_practice_id(),
+                name,
+                &invite_code,
+                &session,
+                nickname,
+            )
+            .await;
+        match created {
+            Ok(group) => {
+                return (StatusCode::CREATED, Json(GroupResponse::from(group))).into_response();
+            }
+            // another group has been given the same invite code
+            Err(_)
+                if attempts < INVITE_CODE_ATTEMPTS
+                    && repos
+                        .groups
+                        .get_group_by_invite_code(&invite_code)
+                        .await
+                        .is_some() =>
+            {
+                tracing::warn!("invite code {invite_code} is taken, retrying!");
+                attempts += 1;
+            }
+            Err(err) => {
+                return (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()).into_response();
+            }
+        }
+    }
+}
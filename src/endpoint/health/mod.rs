@@ -5,6 +5,13 @@ use std::net::SocketAddr;
 use axum::{extract::ConnectInfo, http::StatusCode, response::IntoResponse};
 
 /// Responds with [`StatusCode::OK`].
+#[utoipa::path(
+    get,
+    path = "/health",
+    operation_id = "get_health",
+    tag = "service",
+    responses((status = OK, description = "The service is healthy.")),
+)]
 pub async fn get(ConnectInfo(addr): ConnectInfo<SocketAddr>) -> impl IntoResponse {
     tracing::info!(
         "service {} is healthy. responding to {addr}…",
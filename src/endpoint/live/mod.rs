@@ -0,0 +1,76 @@
+//! Endpoint `/live/{date}`.
+//!
+//! See: [`LIVE`]
+
+use crate::{
+    database::repo::Repos,
+    endpoint::play::is_playable,
+    live::{LIVE, LiveCounts, LiveUpdate},
+    puzzle_number,
+};
+
+use std::convert::Infallible;
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::{
+        IntoResponse,
+        sse::{Event, KeepAlive, Sse},
+    },
+};
+use chrono::Utc;
+use tokio_stream::StreamExt as _;
+
+/// The client follows the live activity of a daily puzzle as Server-Sent Events.
+///
+/// The feed starts with a `counts` event holding the running counts of the day, followed by
+/// anonymised `started`, `won` and `lost` events each carrying the updated counts. Events missed
+/// by a slow client are skipped, as the next event catches up on the counts. Only the dates that
+/// may be played now can be followed.
+#[utoipa::path(
+    get,
+    path = "/live/{date}",
+    operation_id = "follow_live_puzzle",
+    tag = "puzzles",
+    params(("date" = String, Path, description = "The date of the puzzle in `YYYY-MM-DD` format, or its sequential number.")),
+    responses(
+        (status = OK, description = "The feed of live activity.", body = LiveUpdate, content_type = "text/event-stream"),
+        (status = BAD_REQUEST, description = "The date or the number is invalid.", body = String),
+        (status = NOT_FOUND, description = "The puzzle does not exist or may not be played now."),
+    ),
+)]
+pub async fn get(State(repos): State<Repos>, Path(date): Path<String>) -> impl IntoResponse {
+    let date = match puzzle_number::parse(&date) {
+        Ok(date) => date,
+        Err(err) => return (StatusCode::BAD_REQUEST, err.to_string()).into_response(),
+    };
+
+    if !is_playable(&date, Utc::now()) || repos.puzzles.get_puzzle(&date).await.is_none() {
+        return (StatusCode::NOT_FOUND).into_response();
+    }
+
+    let subscription = LIVE
+        .subscribe(&date, async || {
+            LiveCounts::from_histories(&repos.histories.get_histories(&date).await)
+        })
+        .await;
+
+    let initial = tokio_stream::once(LiveUpdate {
+        event: None,
+        counts: subscription.counts(),
+    });
+    let events = initial.chain(subscription).map(|update| {
+        let name = update.event.as_ref().map_or("counts", |event| event.name());
+        Ok::<_, Infallible>(
+            Event::default()
+                .event(name)
+                .json_data(update)
+                .unwrap_or_default(),
+        )
+    });
+
+    Sse::new(events)
+        .keep_alive(KeepAlive::default())
+        .into_response()
+}
@@ -1,50 +1,82 @@
 //! The API endpoints.
 
-use crate::middleware::{self, auth::authorize_paseto_token, session::validate_session_token};
+use crate::{
+    database::repo::Repos,
+    middleware::{
+        self,
+        version::{ApiVersion, count_requests, deprecate_unversioned},
+    },
+};
 
 use axum::{
     Router,
-    middleware::from_fn,
-    routing::{get, post},
+    middleware::{from_fn, from_fn_with_state},
+    routing::get,
 };
 use tower_http::trace::TraceLayer;
 
+pub mod admin;
+pub mod custom;
 pub mod dates;
+pub mod feeds;
+pub mod groups;
 pub mod health;
+pub mod live;
+pub mod openapi;
 pub mod play;
+pub mod puzzles;
+pub mod rooms;
 pub mod root;
+pub mod today;
+pub mod v1;
 pub mod validate;
 
+#[cfg(test)]
+mod tests;
+
 /// Routes an [`Router`] with the endpoints defined by this module.
-pub fn route_from(mut app: Router) -> Router {
-    app = route_gets(app);
-    app = route_posts(app);
+///
+/// Each API version is mounted under its prefix, while the unversioned routes alias to
+/// [`ApiVersion::LATEST`] and are marked as deprecated. Requests to the unversioned routes are
+/// counted apart from the versioned ones. The handlers access the storage through
+/// the [`Repos`] state.
+pub fn route_from(mut app: Router<Repos>) -> Router<Repos> {
+    app = route_version(app, ApiVersion::V1, v1::router());
+    app = route_unversioned(app, v1::router());
+    app = route_service(app);
+    app = route_docs(app);
     app.layer(TraceLayer::new_for_http())
         .layer(middleware::cors::layers::CORS.to_owned())
 }
 
-fn route_gets(app: Router) -> Router {
-    app.route("/", get(root::get))
-        .route("/health", get(health::get))
-        .route("/dates", get(dates::get))
-        .route("/validate", get(validate::get))
-        .route(
-            "/play/session",
-            get(play::session::get).route_layer(from_fn(validate_session_token)),
-        )
-        .route(
-            "/play/start",
-            get(play::start::get).route_layer(from_fn(validate_session_token)),
-        )
+fn route_version(app: Router<Repos>, version: ApiVersion, router: Router<Repos>) -> Router<Repos> {
+    app.nest(
+        version.prefix(),
+        router.layer(from_fn_with_state(version, count_requests)),
+    )
 }
 
-fn route_posts(app: Router) -> Router {
-    app.route(
-        "/",
-        post(root::post).route_layer(from_fn(authorize_paseto_token)),
-    )
-    .route(
-        "/play/submit",
-        post(play::submit::post).route_layer(from_fn(validate_session_token)),
-    )
+fn route_unversioned(app: Router<Repos>, router: Router<Repos>) -> Router<Repos> {
+    app.merge(router.layer(from_fn(deprecate_unversioned)))
+}
+
+fn route_service(app: Router<Repos>) -> Router<Repos> {
+    app.route("/health", get(health::get))
+        .route("/feed.rss", get(feeds::rss::get))
+        .route("/feed.atom", get(feeds::atom::get))
+        .route("/calendar.ics", get(feeds::calendar::get))
+        .route("/openapi.json", get(openapi::get))
+}
+
+#[cfg(feature = "scalar")]
+fn route_docs(app: Router<Repos>) -> Router<Repos> {
+    use utoipa::OpenApi as _;
+    use utoipa_scalar::{Scalar, Servable as _};
+
+    app.merge(Scalar::with_url("/scalar", openapi::ApiDoc::openapi()))
+}
+
+#[cfg(not(feature = "scalar"))]
+fn route_docs(app: Router<Repos>) -> Router<Repos> {
+    app
 }
@@ -0,0 +1,90 @@
+//! Endpoint `/openapi.json`.
+
+use crate::{
+    cookies,
+    endpoint::{feeds, health, v1::ApiDoc as V1ApiDoc},
+};
+
+use axum::{Json, http::StatusCode, response::IntoResponse};
+use utoipa::{
+    Modify, OpenApi as _,
+    openapi::security::{ApiKey, ApiKeyValue, HttpAuthScheme, HttpBuilder, SecurityScheme},
+};
+
+/// The OpenAPI document of the endpoints.
+#[derive(Debug, utoipa::OpenApi)]
+#[openapi(
+    info(
+        title = "api-wordle",
+        description = "KessokuTeaTime API backend for the wordle game.",
+        license(name = "GPL-3.0-only"),
+    ),
+    paths(
+        health::get,
+        feeds::rss::get,
+        feeds::atom::get,
+        feeds::calendar::get,
+    ),
+    nest((path = "/v1", api = V1ApiDoc)),
+    modifiers(&SecurityAddon),
+    tags(
+        (name = "puzzles", description = "Puzzle scheduling and listing."),
+        (name = "play", description = "Playing puzzles with a session."),
+        (name = "custom", description = "Custom puzzles created by players."),
+        (name = "rooms", description = "Race rooms played over WebSockets."),
+        (name = "groups", description = "Friends groups and their leaderboards."),
+        (name = "words", description = "The word dictionary."),
+        (name = "admin", description = "Moderation of the games played."),
+        (name = "service", description = "Service status."),
+    ),
+)]
+pub struct ApiDoc;
+
+/// Registers the security schemes referenced by the endpoints.
+#[derive(Debug)]
+struct SecurityAddon;
+
+impl Modify for SecurityAddon {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let components = openapi.components.get_or_insert_with(Default::default);
+        components.add_security_scheme(
+            "paseto",
+            SecurityScheme::Http(
+                HttpBuilder::new()
+                    .scheme(HttpAuthScheme::Bearer)
+                    .bearer_format("PASETO")
+                    .build(),
+            ),
+        );
+        components.add_security_scheme(
+            "session",
+            SecurityScheme::ApiKey(ApiKey::Cookie(ApiKeyValue::new(cookies::SESSION_TOKEN))),
+        );
+    }
+}
+
+/// The client gets the OpenAPI document.
+pub async fn get() -> impl IntoResponse {
+    (StatusCode::OK, Json(ApiDoc::openapi()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ApiDoc;
+
+    use utoipa::OpenApi as _;
+
+    /// The committed OpenAPI document. Regenerate it with
+    /// `cargo run -- openapi > openapi.json` after changing the API.
+    const SNAPSHOT: &str = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/openapi.json"));
+
+    #[test]
+    fn snapshot() {
+        let spec = ApiDoc::openapi().to_pretty_json().unwrap();
+        assert_eq!(
+            spec.trim_end(),
+            SNAPSHOT.trim_end(),
+            "the OpenAPI document has drifted from `openapi.json`"
+        );
+    }
+}
@@ -0,0 +1,8 @@
+//! Endpoint `/play/absurdle`.
+//!
+//! Absurdle games have no fixed solution: every guess is tinted to keep the most candidates.
+//!
+//! See: [`Absurdle`](entity::Absurdle)
+
+pub mod start;
+pub mod submit;
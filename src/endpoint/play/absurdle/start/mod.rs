@@ -0,0 +1,71 @@
+//! Endpoint `/play/absurdle/start`.
+
+use crate::{
+    ANSWERS, database::repo::Repos, endpoint::play::practice::generate_practice_id,
+    middleware::session::SessionToken,
+};
+
+use axum::{Extension, Json, extract::State, http::StatusCode, response::IntoResponse};
+use entity::GameState;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// The response for the post request.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[schema(as = AbsurdleStartResponse)]
+pub struct PostResponse {
+    /// The opaque id of the game.
+    pub id: String,
+    /// The number of letters in the word.
+    pub letters_count: usize,
+    /// The number of candidate solutions left.
+    pub remaining_candidates: usize,
+    /// The game state.
+    pub state: GameState,
+}
+
+/// The client requests to start an Absurdle game.
+#[utoipa::path(
+    post,
+    path = "/play/absurdle/start",
+    operation_id = "start_absurdle",
+    tag = "play",
+    responses(
+        (status = CREATED, description = "The game has been started.", body = PostResponse),
+        (status = NOT_FOUND, description = "The session token is missing or invalid."),
+        (status = INTERNAL_SERVER_ERROR, description = "A database error occurred.", body = String),
+    ),
+    security(("session" = [])),
+)]
+pub async fn post(
+    State(repos): State<Repos>,
+    session: Option<Extension<SessionToken>>,
+) -> impl IntoResponse {
+    let session = match session {
+        Some(Extension(SessionToken(session))) => session,
+        None => return (StatusCode::NOT_FOUND).into_response(),
+    };
+
+    match repos.sessions.insert_or_update_session(&session).await {
+        Ok(_) => {}
+        Err(err) => return (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()).into_response(),
+    }
+
+    match repos
+        .absurdles
+        .create_absurdle(&generate_practice_id(), &session)
+        .await
+    {
+        Ok(game) => (
+            StatusCode::CREATED,
+            Json(PostResponse {
+                letters_count: game.letters_count(),
+                remaining_candidates: ANSWERS.len(),
+                state: game.game_state(),
+                id: game.id,
+            }),
+        )
+            .into_response(),
+        Err(err) => (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()).into_response(),
+    }
+}
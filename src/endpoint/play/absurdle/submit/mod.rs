@@ -0,0 +1,125 @@
+//! Endpoint `/play/absurdle/{id}/submit`.
+
+use crate::{
+    ANSWERS, WORDS, database::repo::Repos, endpoint::play::submit::PostPayload,
+    middleware::session::SessionToken,
+};
+
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use entity::{
+    ABSURDLE_MAX_TRIES, Absurdle, GameState, Keyboard, PUZZLE_LETTERS_COUNT, PuzzleSolution,
+    SubmitHistory, SubmitWord,
+};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// The response for the post request.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[schema(as = AbsurdleSubmitResponse)]
+pub struct PostResponse {
+    /// The number of letters in the word.
+    pub letters_count: usize,
+    /// The number of candidate solutions left.
+    pub remaining_candidates: usize,
+    /// The game state.
+    pub state: GameState,
+    /// The history of submitted words.
+    pub history: Vec<SubmitWord>,
+    /// The letter states known from the history.
+    pub keyboard: Keyboard,
+}
+
+impl PostResponse {
+    /// Creates a response from the submit history and the number of candidates left.
+    pub fn new(
+        history: &SubmitHistory<PUZZLE_LETTERS_COUNT, ABSURDLE_MAX_TRIES>,
+        remaining_candidates: usize,
+    ) -> Self {
+        Self {
+            letters_count: PUZZLE_LETTERS_COUNT,
+            remaining_candidates,
+            state: history.game_state(),
+            history: history.0.clone(),
+            keyboard: Keyboard::new(history),
+        }
+    }
+}
+
+/// The client submits a word to an Absurdle game, which is tinted to keep the most candidates.
+#[utoipa::path(
+    post,
+    path = "/play/absurdle/{id}/submit",
+    operation_id = "submit_absurdle_answer",
+    tag = "play",
+    params(("id" = String, Path, description = "The opaque id of the game.")),
+    request_body = PostPayload,
+    responses(
+        (status = ACCEPTED, description = "The answer has been submitted.", body = PostResponse),
+        (status = BAD_REQUEST, description = "The answer is invalid."),
+        (status = CONFLICT, description = "The game is won and accepts no further answers.", body = PostResponse),
+        (status = NOT_FOUND, description = "The session token is missing or invalid, or the session has no such game."),
+        (status = INTERNAL_SERVER_ERROR, description = "A database error occurred.", body = String),
+    ),
+    security(("session" = [])),
+)]
+pub async fn post(
+    State(repos): State<Repos>,
+    session: Option<Extension<SessionToken>>,
+    Path(id): Path<String>,
+    Json(payload): Json<PostPayload>,
+) -> impl IntoResponse {
+    let session = match session {
+        Some(Extension(SessionToken(session))) => session,
+        None => return (StatusCode::NOT_FOUND).into_response(),
+    };
+
+    let answer = match PuzzleSolution::try_from(&payload.answer[..]) {
+        Ok(answer) if WORDS.contains(&&answer.to_string()[..]) => answer,
+        _ => return (StatusCode::BAD_REQUEST).into_response(),
+    };
+
+    let Some(mut game) = repos.absurdles.get_absurdle(&id, &session).await else {
+        return (StatusCode::NOT_FOUND).into_response();
+    };
+
+    let mut history = game.submit_history.take().unwrap_or_default();
+    if history.game_state().is_over() {
+        return (StatusCode::CONFLICT, Json(PostResponse::new(&history, 1))).into_response();
+    }
+
+    let tinted = {
+        let history = history.clone();
+        tokio::task::spawn_blocking(move || {
+            let candidates = Absurdle::candidates(&ANSWERS, &history);
+            Absurdle::tint(&answer, &candidates)
+        })
+        .await
+    };
+    let (word, remaining) = match tinted {
+        Ok(Some(tinted)) => tinted,
+        Ok(None) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "no candidates left".to_owned(),
+            )
+                .into_response();
+        }
+        Err(err) => return (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()).into_response(),
+    };
+
+    if let Err(err) = history.submit(word) {
+        return (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()).into_response();
+    }
+    let response = PostResponse::new(&history, remaining);
+    game.submit_history = Some(history);
+
+    match repos.absurdles.update_absurdle(&game).await {
+        Ok(()) => (StatusCode::ACCEPTED, Json(response)).into_response(),
+        Err(err) => (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()).into_response(),
+    }
+}
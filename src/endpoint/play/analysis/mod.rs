@@ -0,0 +1,89 @@
+//! Endpoint `/play/analysis`.
+
+use crate::{SOLVER, database::repo::Repos, middleware::session::SessionToken, puzzle_number};
+
+use axum::{
+    Extension, Json,
+    extract::{Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use entity::{GuessAnalysis, PuzzleSolution};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// The parameters for the get request.
+#[derive(Debug, Clone, Deserialize, utoipa::IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct GetParams {
+    /// The date of the puzzle in `YYYY-MM-DD` format, unless given by `number`.
+    pub date: Option<String>,
+    /// The sequential number of the puzzle, unless given by `date`.
+    pub number: Option<u64>,
+}
+
+/// The response for the get request.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[schema(as = AnalysisResponse)]
+pub struct GetResponse {
+    /// The solution of the puzzle.
+    pub solution: PuzzleSolution,
+    /// The analysis of each submitted guess.
+    pub guesses: Vec<GuessAnalysis>,
+}
+
+/// The client requests the analysis of a finished puzzle.
+#[utoipa::path(
+    get,
+    path = "/play/analysis",
+    operation_id = "analyze_puzzle",
+    tag = "play",
+    params(GetParams),
+    responses(
+        (status = OK, description = "The analysis of the submitted guesses.", body = GetResponse),
+        (status = BAD_REQUEST, description = "The date or the number is invalid.", body = String),
+        (status = NOT_FOUND, description = "The session token is missing or invalid, or the puzzle has not been started."),
+        (status = CONFLICT, description = "The puzzle is still in progress."),
+        (status = INTERNAL_SERVER_ERROR, description = "The analysis failed.", body = String),
+    ),
+    security(("session" = [])),
+)]
+pub async fn get(
+    State(repos): State<Repos>,
+    session: Option<Extension<SessionToken>>,
+    Query(params): Query<GetParams>,
+) -> impl IntoResponse {
+    let session = match session {
+        Some(Extension(SessionToken(session))) => session,
+        None => return (StatusCode::NOT_FOUND).into_response(),
+    };
+
+    let date = match puzzle_number::resolve(params.date.as_deref(), params.number) {
+        Ok(date) => date,
+        Err(err) => return (StatusCode::BAD_REQUEST, err.to_string()).into_response(),
+    };
+
+    let history = match repos.histories.get_history(&date, &session).await {
+        Some(history) if history.game_state().is_in_progress() => {
+            return (StatusCode::CONFLICT).into_response();
+        }
+        Some(history) => history,
+        None => return (StatusCode::NOT_FOUND).into_response(),
+    };
+
+    tracing::info!("analyzing history for {date} with session {session}…");
+    let solution = history.solution;
+    let submit_history = history.submit_history.unwrap_or_default();
+    let analyzed = {
+        let solution = solution.clone();
+        tokio::task::spawn_blocking(move || SOLVER.analyze(&solution, &submit_history)).await
+    };
+
+    match analyzed {
+        Ok(guesses) => {
+            tracing::info!("analyzed history for {date} with session {session}");
+            (StatusCode::OK, Json(GetResponse { solution, guesses })).into_response()
+        }
+        Err(err) => (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()).into_response(),
+    }
+}
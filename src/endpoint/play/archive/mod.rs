@@ -0,0 +1,167 @@
+//! Endpoint `/play/archive`.
+
+use crate::{
+    database::repo::Repos, endpoint::play::is_playable, middleware::session::SessionToken,
+    puzzle_number,
+};
+
+use axum::{
+    Extension, Json,
+    extract::{Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use chrono::Utc;
+use entity::{GameState, PuzzleDate, histories::Model as History};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// The number of dates per page if not specified.
+pub const DEFAULT_PER_PAGE: u64 = 31;
+
+/// The maximum number of dates per page.
+pub const MAX_PER_PAGE: u64 = 100;
+
+/// The parameters for the get request.
+#[derive(Debug, Clone, Deserialize, utoipa::IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct GetParams {
+    /// The page to get, starting from 1. Defaults to the first page, which has the latest dates.
+    pub page: Option<u64>,
+    /// The number of dates per page, up to [`MAX_PER_PAGE`]. Defaults to [`DEFAULT_PER_PAGE`].
+    pub per_page: Option<u64>,
+}
+
+/// The state of the game of a date for the session.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(tag = "status", rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum ArchiveState {
+    /// The puzzle has not been started.
+    NotStarted,
+    /// The puzzle accepts further submissions.
+    InProgress,
+    /// The puzzle has been solved.
+    Won {
+        /// The number of tries taken to solve the puzzle.
+        tries: usize,
+    },
+    /// The puzzle has run out of tries without being solved.
+    Lost,
+}
+
+impl From<Option<&History>> for ArchiveState {
+    fn from(value: Option<&History>) -> Self {
+        match value.map(History::game_state) {
+            None => Self::NotStarted,
+            Some(GameState::InProgress) => Self::InProgress,
+            Some(GameState::Won { tries }) => Self::Won { tries },
+            Some(GameState::Lost) => Self::Lost,
+        }
+    }
+}
+
+/// A date of the archive.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ArchiveDate {
+    /// The date of the puzzle.
+    pub date: PuzzleDate,
+    /// The sequential number of the puzzle, if numbered.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub number: Option<u64>,
+    /// The state of the game for the session.
+    pub state: ArchiveState,
+    /// Whether the puzzle may be started now in some client timezone.
+    ///
+    /// See: [`is_playable`]
+    pub playable: bool,
+}
+
+/// The dates of the archive in a month.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ArchiveMonth {
+    /// The month in `YYYY-MM` format.
+    pub month: String,
+    /// The dates of the month on the page, latest first.
+    pub dates: Vec<ArchiveDate>,
+}
+
+/// The response for the get request.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[schema(as = ArchiveResponse)]
+pub struct GetResponse {
+    /// The page, starting from 1.
+    pub page: u64,
+    /// The number of dates per page.
+    pub per_page: u64,
+    /// The total number of pages.
+    pub pages: u64,
+    /// The total number of dates.
+    pub total: u64,
+    /// The dates of the page grouped by month, latest first.
+    pub months: Vec<ArchiveMonth>,
+}
+
+/// The player browses the daily puzzles with the state of their games.
+#[utoipa::path(
+    get,
+    path = "/play/archive",
+    operation_id = "get_archive",
+    tag = "play",
+    params(GetParams),
+    responses(
+        (status = OK, description = "A page of the archive.", body = GetResponse),
+        (status = BAD_REQUEST, description = "The page or the number of dates per page is invalid."),
+        (status = NOT_FOUND, description = "The session token is missing or invalid."),
+    ),
+    security(("session" = [])),
+)]
+pub async fn get(
+    State(repos): State<Repos>,
+    session: Option<Extension<SessionToken>>,
+    Query(params): Query<GetParams>,
+) -> impl IntoResponse {
+    let session = match session {
+        Some(Extension(SessionToken(session))) => session,
+        None => return (StatusCode::NOT_FOUND).into_response(),
+    };
+
+    let page = params.page.unwrap_or(1);
+    let per_page = params.per_page.unwrap_or(DEFAULT_PER_PAGE);
+    if page == 0 || !(1..=MAX_PER_PAGE).contains(&per_page) {
+        return (StatusCode::BAD_REQUEST).into_response();
+    }
+
+    let archive = repos
+        .histories
+        .get_archive(&session, page - 1, per_page)
+        .await;
+    let now = Utc::now();
+
+    let mut months: Vec<ArchiveMonth> = Vec::new();
+    for (puzzle, history) in &archive.entries {
+        let month = puzzle.date.0.format("%Y-%m").to_string();
+        let date = ArchiveDate {
+            number: puzzle_number::number_of(&puzzle.date),
+            state: ArchiveState::from(history.as_ref()),
+            playable: is_playable(&puzzle.date, now),
+            date: puzzle.date.clone(),
+        };
+        match months.last_mut() {
+            Some(last) if last.month == month => last.dates.push(date),
+            _ => months.push(ArchiveMonth {
+                month,
+                dates: vec![date],
+            }),
+        }
+    }
+
+    let response = GetResponse {
+        page,
+        per_page,
+        pages: archive.total.div_ceil(per_page),
+        total: archive.total,
+        months,
+    };
+    (StatusCode::OK, Json(response)).into_response()
+}
@@ -0,0 +1,108 @@
+//! Endpoint `/play/boards`.
+//!
+//! Multi-board variants tint every guess against several solutions at once.
+//!
+//! See: [`BoardVariant`]
+
+use crate::puzzle_number;
+
+use entity::{
+    BoardSolutions, BoardVariant, BoardsHistory, GameState, Keyboard, PuzzleDate, PuzzleSolution,
+    SubmitWord,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+pub mod start;
+pub mod submit;
+
+/// The parameters shared by the multi-board requests.
+#[derive(Debug, Clone, Deserialize, utoipa::IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct BoardsParams {
+    /// The date of the puzzle in `YYYY-MM-DD` format, unless given by `number`.
+    pub date: Option<String>,
+    /// The sequential number of the puzzle, unless given by `date`.
+    pub number: Option<u64>,
+    /// The multi-board variant.
+    pub variant: BoardVariant,
+}
+
+/// The state of a single board.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[schema(as = BoardResponse)]
+pub struct Board {
+    /// The words tinted on the board, up to the guess solving it.
+    pub history: Vec<SubmitWord>,
+    /// The number of guesses taken to solve the board, if solved.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub solved_in: Option<usize>,
+    /// The letter states known on the board.
+    pub keyboard: Keyboard,
+    /// The solution of the board, revealed once the game is lost.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub solution: Option<PuzzleSolution>,
+}
+
+/// The response for the multi-board requests.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[schema(as = BoardsResponse)]
+pub struct BoardsResponse {
+    /// The multi-board variant.
+    pub variant: BoardVariant,
+    /// The number of letters in each word.
+    pub letters_count: usize,
+    /// The number of remaining tries.
+    pub remaining_tries: usize,
+    /// Whether every board has been solved.
+    pub is_completed: bool,
+    /// Whether the game is over, either won or lost.
+    pub is_over: bool,
+    /// The game state, which is won once every board is solved.
+    pub state: GameState,
+    /// The state of each board.
+    pub boards: Vec<Board>,
+    /// The text to share showing every board, headed by the number of the puzzle, once the game is
+    /// over.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub share: Option<String>,
+}
+
+impl BoardsResponse {
+    /// Creates the response from a submit history and the solutions of its boards.
+    pub fn new(date: &PuzzleDate, history: &BoardsHistory, solutions: &BoardSolutions) -> Self {
+        let state = history.game_state();
+        let variant = history.variant;
+        Self {
+            variant,
+            letters_count: history.letters_count(),
+            remaining_tries: history.remaining_tries(),
+            is_completed: state.is_won(),
+            is_over: state.is_over(),
+            state,
+            boards: solutions
+                .0
+                .iter()
+                .enumerate()
+                .map(|(board, solution)| Board {
+                    history: history.board(board),
+                    solved_in: history.solved_in(board),
+                    keyboard: history.keyboard(board),
+                    solution: state.reveals_solution().then(|| solution.clone()),
+                })
+                .collect(),
+            share: state.is_over().then(|| {
+                let tries = match state {
+                    GameState::Won { tries } => tries.to_string(),
+                    _ => "X".to_owned(),
+                };
+                format!(
+                    "{variant} {} {tries}/{}\n\n{}",
+                    puzzle_number::label_of(date),
+                    variant.max_tries(),
+                    history.share()
+                )
+            }),
+        }
+    }
+}
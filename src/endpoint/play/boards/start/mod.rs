@@ -0,0 +1,123 @@
+//! Endpoint `/play/boards/start`.
+
+use crate::{
+    ANSWERS,
+    database::repo::Repos,
+    endpoint::play::boards::{BoardsParams, BoardsResponse},
+    middleware::session::SessionToken,
+    puzzle_number,
+};
+
+use axum::{
+    Extension, Json,
+    extract::{Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use entity::{BoardSolutions, BoardVariant, BoardsHistory, board_puzzles::Model as BoardPuzzle};
+use rand::seq::IndexedRandom as _;
+
+/// The client requests to start a multi-board puzzle, with solutions drawn from [`ANSWERS`] the
+/// first time the puzzle of the date is played.
+#[utoipa::path(
+    get,
+    path = "/play/boards/start",
+    operation_id = "start_boards",
+    tag = "play",
+    params(BoardsParams),
+    responses(
+        (status = OK, description = "The puzzle has already been started.", body = BoardsResponse),
+        (status = CREATED, description = "The puzzle has been started.", body = BoardsResponse),
+        (status = BAD_REQUEST, description = "The date or the variant is invalid.", body = String),
+        (status = NOT_FOUND, description = "The session token is missing or invalid."),
+        (status = INTERNAL_SERVER_ERROR, description = "A database error occurred.", body = String),
+    ),
+    security(("session" = [])),
+)]
+pub async fn get(
+    State(repos): State<Repos>,
+    session: Option<Extension<SessionToken>>,
+    Query(params): Query<BoardsParams>,
+) -> impl IntoResponse {
+    let session = match session {
+        Some(Extension(SessionToken(session))) => session,
+        None => return (StatusCode::NOT_FOUND).into_response(),
+    };
+
+    let date = match puzzle_number::resolve(params.date.as_deref(), params.number) {
+        Ok(date) => date,
+        Err(err) => return (StatusCode::BAD_REQUEST, err.to_string()).into_response(),
+    };
+    let variant = params.variant;
+
+    match repos.sessions.insert_or_update_session(&session).await {
+        Ok(_) => {}
+        Err(err) => return (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()).into_response(),
+    }
+
+    if let Some(history) = repos
+        .boards
+        .get_board_history(&date, variant, &session)
+        .await
+    {
+        return (
+            StatusCode::OK,
+            Json(BoardsResponse::new(
+                &date,
+                &history.boards_history(),
+                &history.solutions,
+            )),
+        )
+            .into_response();
+    }
+
+    let solutions = match repos.boards.get_board_puzzle(&date, variant).await {
+        Some(BoardPuzzle { solutions, .. }) => solutions,
+        None => {
+            let Some(solutions) = random_solutions(variant) else {
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "not enough answers to draw from",
+                )
+                    .into_response();
+            };
+
+            match repos
+                .boards
+                .insert_board_solutions(&date, variant, &solutions)
+                .await
+            {
+                Ok(_) => solutions,
+                Err(err) => {
+                    return (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()).into_response();
+                }
+            }
+        }
+    };
+
+    match repos
+        .boards
+        .create_board_history(&date, variant, &session, &solutions)
+        .await
+    {
+        Ok(_) => (
+            StatusCode::CREATED,
+            Json(BoardsResponse::new(
+                &date,
+                &BoardsHistory::new(variant),
+                &solutions,
+            )),
+        )
+            .into_response(),
+        Err(err) => (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()).into_response(),
+    }
+}
+
+/// Draws distinct solutions from [`ANSWERS`] for every board of a variant, if there are enough.
+fn random_solutions(variant: BoardVariant) -> Option<BoardSolutions> {
+    let solutions: Vec<_> = ANSWERS
+        .choose_multiple(&mut rand::rng(), variant.boards())
+        .cloned()
+        .collect();
+    (solutions.len() == variant.boards()).then_some(BoardSolutions(solutions))
+}
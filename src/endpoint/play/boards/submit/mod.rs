@@ -0,0 +1,83 @@
+//! Endpoint `/play/boards/submit`.
+
+use crate::{
+    WORDS,
+    database::repo::{BoardsSubmitError, Repos},
+    endpoint::play::{
+        boards::{BoardsParams, BoardsResponse},
+        submit::PostPayload,
+    },
+    middleware::session::SessionToken,
+    puzzle_number,
+};
+
+use axum::{
+    Extension, Json,
+    extract::{Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use entity::PuzzleSolution;
+
+/// The client submits a word to solve every board of a multi-board puzzle.
+#[utoipa::path(
+    post,
+    path = "/play/boards/submit",
+    operation_id = "submit_boards_answer",
+    tag = "play",
+    params(BoardsParams),
+    request_body = PostPayload,
+    responses(
+        (status = ACCEPTED, description = "The answer has been submitted.", body = BoardsResponse),
+        (status = BAD_REQUEST, description = "The date, the variant or the answer is invalid."),
+        (status = CONFLICT, description = "The game is over and accepts no further answers.", body = BoardsResponse),
+        (status = NOT_FOUND, description = "The session token is missing or invalid."),
+        (status = INTERNAL_SERVER_ERROR, description = "A database error occurred.", body = String),
+    ),
+    security(("session" = [])),
+)]
+pub async fn post(
+    State(repos): State<Repos>,
+    session: Option<Extension<SessionToken>>,
+    Query(params): Query<BoardsParams>,
+    Json(payload): Json<PostPayload>,
+) -> impl IntoResponse {
+    let session = match session {
+        Some(Extension(SessionToken(session))) => session,
+        None => return (StatusCode::NOT_FOUND).into_response(),
+    };
+
+    let (date, answer) = match (
+        puzzle_number::resolve(params.date.as_deref(), params.number),
+        PuzzleSolution::try_from(&payload.answer[..]),
+    ) {
+        (Ok(date), Ok(answer)) if WORDS.contains(&&answer.to_string()[..]) => (date, answer),
+        _ => return (StatusCode::BAD_REQUEST).into_response(),
+    };
+
+    match repos
+        .boards
+        .submit_to_board_history(&date, params.variant, &session, &answer)
+        .await
+    {
+        Ok(result) => (
+            StatusCode::ACCEPTED,
+            Json(BoardsResponse::new(
+                &date,
+                &result.submit_history,
+                &result.solutions,
+            )),
+        )
+            .into_response(),
+        Err(BoardsSubmitError::GameOver(result)) => (
+            StatusCode::CONFLICT,
+            Json(BoardsResponse::new(
+                &date,
+                &result.submit_history,
+                &result.solutions,
+            )),
+        )
+            .into_response(),
+        Err(err) => (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()).into_response(),
+    }
+}
@@ -0,0 +1,88 @@
+//! Endpoint `/play/hint`.
+
+use crate::{
+    database::repo::{HintError, Repos},
+    middleware::session::SessionToken,
+    puzzle_number,
+};
+
+use axum::{
+    Extension, Json,
+    extract::{Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use entity::Hint;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// The parameters for the post request.
+#[derive(Debug, Clone, Deserialize, utoipa::IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct PostParams {
+    /// The date of the puzzle in `YYYY-MM-DD` format, unless given by `number`.
+    pub date: Option<String>,
+    /// The sequential number of the puzzle, unless given by `date`.
+    pub number: Option<u64>,
+}
+
+/// The response for the post request.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[schema(as = HintResponse)]
+pub struct PostResponse {
+    /// The revealed letter.
+    pub hint: Hint,
+    /// All hints used for the puzzle, including the revealed one.
+    pub hints: Vec<Hint>,
+    /// The number of remaining hints.
+    pub remaining_hints: usize,
+}
+
+/// The client requests a letter of the solution to be revealed.
+#[utoipa::path(
+    post,
+    path = "/play/hint",
+    operation_id = "use_hint",
+    tag = "play",
+    params(PostParams),
+    responses(
+        (status = CREATED, description = "A letter has been revealed.", body = PostResponse),
+        (status = BAD_REQUEST, description = "The date or the number is invalid.", body = String),
+        (status = NOT_FOUND, description = "The session token is missing or invalid."),
+        (status = CONFLICT, description = "The game is over, the hints are exhausted, or every letter is already known.", body = String),
+        (status = INTERNAL_SERVER_ERROR, description = "A database error occurred.", body = String),
+    ),
+    security(("session" = [])),
+)]
+pub async fn post(
+    State(repos): State<Repos>,
+    session: Option<Extension<SessionToken>>,
+    Query(params): Query<PostParams>,
+) -> impl IntoResponse {
+    let session = match session {
+        Some(Extension(SessionToken(session))) => session,
+        None => return (StatusCode::NOT_FOUND).into_response(),
+    };
+
+    let date = match puzzle_number::resolve(params.date.as_deref(), params.number) {
+        Ok(date) => date,
+        Err(err) => return (StatusCode::BAD_REQUEST, err.to_string()).into_response(),
+    };
+
+    match repos.histories.use_hint(&date, &session).await {
+        Ok((hint, hints)) => (
+            StatusCode::CREATED,
+            Json(PostResponse {
+                hint,
+                remaining_hints: hints.remaining(),
+                hints: hints.into_vec(),
+            }),
+        )
+            .into_response(),
+        Err(
+            err
+            @ (HintError::GameOver(_) | HintError::Exhausted(_) | HintError::NothingToReveal(_)),
+        ) => (StatusCode::CONFLICT, err.to_string()).into_response(),
+        Err(err) => (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()).into_response(),
+    }
+}
@@ -1,5 +1,75 @@
 //! Endpoint `/play`.
 
+use crate::{
+    env::{PLAY_WINDOW_DAYS, TIMEZONE_MAX_OFFSET, TIMEZONE_MIN_OFFSET},
+    puzzle_number,
+};
+
+use chrono::{DateTime, Days, FixedOffset, NaiveDate, Utc};
+use entity::{GameState, HISTORY_MAX_TRIES, Hints, PuzzleDate, SubmitHistory};
+
+pub mod absurdle;
+pub mod analysis;
+pub mod archive;
+pub mod boards;
+pub mod hint;
+pub mod practice;
 pub mod session;
 pub mod start;
 pub mod submit;
+pub mod versus;
+
+/// Returns whether the daily puzzle of a date may be started now in some client timezone.
+///
+/// The date must have started at [`TIMEZONE_MAX_OFFSET`] and must not be older than
+/// [`PLAY_WINDOW_DAYS`] at [`TIMEZONE_MIN_OFFSET`].
+pub fn is_playable(date: &PuzzleDate, now: DateTime<Utc>) -> bool {
+    let latest = date_at_offset(now, *TIMEZONE_MAX_OFFSET);
+    let oldest = PLAY_WINDOW_DAYS.map_or(NaiveDate::MIN, |days| {
+        date_at_offset(now, *TIMEZONE_MIN_OFFSET)
+            .checked_sub_days(Days::new(days))
+            .unwrap_or(NaiveDate::MIN)
+    });
+    (oldest..=latest).contains(&date.0)
+}
+
+/// Returns whether the daily puzzle of a date is over in every client timezone, that is, at
+/// [`TIMEZONE_MIN_OFFSET`], so its solution may be revealed.
+pub fn has_ended(date: &PuzzleDate, now: DateTime<Utc>) -> bool {
+    date.0 < date_at_offset(now, *TIMEZONE_MIN_OFFSET)
+}
+
+/// Returns the date at an instant in a UTC offset in hours, falling back to UTC if out of range.
+fn date_at_offset(now: DateTime<Utc>, hours: i32) -> NaiveDate {
+    match FixedOffset::east_opt(hours * 3600) {
+        Some(offset) => PuzzleDate::at(&now, &offset).0,
+        None => now.date_naive(),
+    }
+}
+
+/// Returns the text to share for a daily puzzle once the game is over, headed by its number and
+/// marking each hint used with a 💡 after the score, which already includes their cost.
+///
+/// See: [`Hints::charge`], [`puzzle_number::label_of`]
+pub fn share(
+    date: &PuzzleDate,
+    state: GameState,
+    submit_history: &SubmitHistory,
+    hints: &Hints,
+) -> Option<String> {
+    state.is_over().then(|| {
+        let tries = match state {
+            GameState::Won { tries } => tries.to_string(),
+            _ => "X".to_owned(),
+        };
+        let bulbs = "💡".repeat(hints.len());
+        let label = puzzle_number::label_of(date);
+        let header = format!("Wordle {label} {tries}/{HISTORY_MAX_TRIES}");
+        let header = if bulbs.is_empty() {
+            header
+        } else {
+            format!("{header} {bulbs}")
+        };
+        format!("{header}\n\n{}", submit_history.share())
+    })
+}
@@ -0,0 +1,12 @@
+//! Endpoint `/play/practice`.
+//!
+//! Practices are unlimited games with random solutions, kept apart from the daily puzzles so they
+//! never count towards daily stats.
+
+pub mod start;
+pub mod submit;
+
+/// Generates an opaque practice id of 32 hexadecimal digits.
+pub fn generate_practice_id() -> String {
+    hex::encode(rand::random::<[u8; 16]>())
+}
@@ -0,0 +1,76 @@
+//! Endpoint `/play/practice/start`.
+
+use crate::{
+    ANSWERS, database::repo::Repos, endpoint::play::practice::generate_practice_id,
+    middleware::session::SessionToken,
+};
+
+use axum::{Extension, Json, extract::State, http::StatusCode, response::IntoResponse};
+use entity::GameState;
+use rand::seq::IndexedRandom as _;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// The response for the post request.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[schema(as = PracticeStartResponse)]
+pub struct PostResponse {
+    /// The opaque id of the practice.
+    pub id: String,
+    /// The number of letters in the word.
+    pub letters_count: usize,
+    /// The number of remaining tries.
+    pub remaining_tries: usize,
+    /// The game state.
+    pub state: GameState,
+}
+
+/// The client requests to start a practice with a solution drawn from [`ANSWERS`].
+#[utoipa::path(
+    post,
+    path = "/play/practice/start",
+    operation_id = "start_practice",
+    tag = "play",
+    responses(
+        (status = CREATED, description = "The practice has been started.", body = PostResponse),
+        (status = NOT_FOUND, description = "The session token is missing or invalid."),
+        (status = INTERNAL_SERVER_ERROR, description = "A database error occurred.", body = String),
+    ),
+    security(("session" = [])),
+)]
+pub async fn post(
+    State(repos): State<Repos>,
+    session: Option<Extension<SessionToken>>,
+) -> impl IntoResponse {
+    let session = match session {
+        Some(Extension(SessionToken(session))) => session,
+        None => return (StatusCode::NOT_FOUND).into_response(),
+    };
+
+    match repos.sessions.insert_or_update_session(&session).await {
+        Ok(_) => {}
+        Err(err) => return (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()).into_response(),
+    }
+
+    let Some(solution) = ANSWERS.choose(&mut rand::rng()) else {
+        return (StatusCode::INTERNAL_SERVER_ERROR, "no answers to draw from").into_response();
+    };
+
+    match repos
+        .practices
+        .create_practice(&generate_practice_id(), &session, solution)
+        .await
+    {
+        Ok(practice) => (
+            StatusCode::CREATED,
+            Json(PostResponse {
+                letters_count: practice.letters_count(),
+                remaining_tries: practice.remaining_tries(),
+                state: practice.game_state(),
+                id: practice.id,
+            }),
+        )
+            .into_response(),
+        Err(err) => (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()).into_response(),
+    }
+}
@@ -0,0 +1,62 @@
+//! Endpoint `/play/practice/{id}/submit`.
+
+use crate::{
+    WORDS,
+    database::repo::{Repos, SubmitError},
+    endpoint::play::submit::{PostPayload, PostResponse},
+    middleware::session::SessionToken,
+};
+
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use entity::PuzzleSolution;
+
+/// The client submits a word to solve a practice.
+#[utoipa::path(
+    post,
+    path = "/play/practice/{id}/submit",
+    operation_id = "submit_practice_answer",
+    tag = "play",
+    params(("id" = String, Path, description = "The opaque id of the practice.")),
+    request_body = PostPayload,
+    responses(
+        (status = ACCEPTED, description = "The answer has been submitted.", body = PostResponse),
+        (status = BAD_REQUEST, description = "The answer is invalid."),
+        (status = CONFLICT, description = "The practice is over and accepts no further answers.", body = PostResponse),
+        (status = NOT_FOUND, description = "The session token is missing or invalid, or the session has no such practice."),
+        (status = INTERNAL_SERVER_ERROR, description = "A database error occurred.", body = String),
+    ),
+    security(("session" = [])),
+)]
+pub async fn post(
+    State(repos): State<Repos>,
+    session: Option<Extension<SessionToken>>,
+    Path(id): Path<String>,
+    Json(payload): Json<PostPayload>,
+) -> impl IntoResponse {
+    let session = match session {
+        Some(Extension(SessionToken(session))) => session,
+        None => return (StatusCode::NOT_FOUND).into_response(),
+    };
+
+    let answer = match PuzzleSolution::try_from(&payload.answer[..]) {
+        Ok(answer) if WORDS.contains(&&answer.to_string()[..]) => answer,
+        _ => return (StatusCode::BAD_REQUEST).into_response(),
+    };
+
+    let Some(practice) = repos.practices.get_practice(&id, &session).await else {
+        return (StatusCode::NOT_FOUND).into_response();
+    };
+
+    match repos.practices.submit_to_practice(practice, &answer).await {
+        Ok(result) => (StatusCode::ACCEPTED, Json(PostResponse::from(result))).into_response(),
+        Err(SubmitError::GameOver(result)) => {
+            (StatusCode::CONFLICT, Json(PostResponse::from(result))).into_response()
+        }
+        Err(err) => (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()).into_response(),
+    }
+}
@@ -12,6 +12,25 @@ use axum_extra::extract::{
 };
 
 /// The client requests a session token.
+#[utoipa::path(
+    get,
+    path = "/play/session",
+    operation_id = "get_session",
+    tag = "play",
+    responses(
+        (
+            status = OK,
+            description = "The existing session token is valid and has been refreshed.",
+            headers(("set-cookie" = String, description = "The `session_token` cookie.")),
+        ),
+        (
+            status = CREATED,
+            description = "A new session token has been issued.",
+            headers(("set-cookie" = String, description = "The `session_token` cookie.")),
+        ),
+    ),
+    security((), ("session" = [])),
+)]
 pub async fn get(jar: CookieJar, session: Option<Extension<SessionToken>>) -> impl IntoResponse {
     fn setup_cookie(session: String) -> Cookie<'static> {
         let mut cookie = Cookie::new(cookies::SESSION_TOKEN, session);
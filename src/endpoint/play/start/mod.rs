@@ -1,42 +1,86 @@
 //! Endpoint `/play/start`.
 
 use crate::{
-    database::{
-        self,
-        tables::{
-            histories::{create_history, get_history},
-            puzzles::{get_puzzle, insert_solution},
-            sessions::insert_or_update_session,
-        },
-    },
+    database::repo::Repos,
+    difficulty,
+    endpoint::play::share,
+    env::TIME_ATTACK_LIMIT,
+    live::{LIVE, LiveEvent},
     middleware::session::SessionToken,
+    puzzle_number,
 };
 
-use axum::{Extension, Json, extract::Query, http::StatusCode, response::IntoResponse};
+use axum::{
+    Extension, Json,
+    extract::{Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use chrono::{TimeDelta, Utc};
 use entity::{
-    HISTORY_MAX_TRIES, PUZZLE_LETTERS_COUNT, PuzzleDate, PuzzleSolution, SubmitHistory, SubmitWord,
-    puzzles::Model as Puzzle,
+    GameState, HISTORY_MAX_TRIES, Hint, Hints, Keyboard, PUZZLE_LETTERS_COUNT, PuzzleSolution,
+    SubmitHistory, SubmitWord, Timing, puzzles::Model as Puzzle,
 };
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 /// The parameters for the get request.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, utoipa::IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct GetParams {
-    /// The date of the puzzle in `YYYY-MM-DD` format.
-    pub date: String,
+    /// The date of the puzzle in `YYYY-MM-DD` format, unless given by `number`.
+    pub date: Option<String>,
+    /// The sequential number of the puzzle, unless given by `date`.
+    pub number: Option<u64>,
+    /// The mode to play the puzzle in, which only applies when the puzzle is first started.
+    #[serde(default)]
+    pub mode: PlayMode,
+}
+
+/// The modes to play a puzzle in. Every game is timed on the server.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum PlayMode {
+    /// The puzzle can be finished at any pace.
+    #[default]
+    Classic,
+    /// The puzzle must be finished within [`TIME_ATTACK_LIMIT`], after which submissions count as
+    /// a loss.
+    TimeAttack,
 }
 
 /// The response for the get request.
-#[derive(Debug, Clone, Default, Serialize)]
+#[derive(Debug, Clone, Default, Serialize, ToSchema)]
+#[schema(as = StartResponse)]
 pub struct GetResponse {
     /// The number of letters in the word.
     pub letters_count: usize,
     /// The number of remaining tries.
     pub remaining_tries: usize,
-    /// Whether the puzzle has been completed.
+    /// Whether the puzzle has been solved.
     pub is_completed: bool,
+    /// Whether the game is over, either won or lost.
+    pub is_over: bool,
+    /// The game state.
+    pub state: GameState,
+    /// The solution, revealed once the game is lost.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub solution: Option<PuzzleSolution>,
     /// The history of submitted words.
     pub history: Vec<SubmitWord>,
+    /// The letter states known from the history.
+    pub keyboard: Keyboard,
+    /// The hints used.
+    pub hints: Vec<Hint>,
+    /// The server-side timing, which is missing for games started before it was tracked.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timing: Option<Timing>,
+    /// The text to share, once the game is over.
+    ///
+    /// See: [`share`]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub share: Option<String>,
 }
 
 /// The client requests to start a puzzle session.
@@ -44,7 +88,23 @@ pub struct GetResponse {
 /// # Panics
 ///
 /// Panics if cannot get a random word from [`random_word`].
+#[utoipa::path(
+    get,
+    path = "/play/start",
+    operation_id = "start_puzzle",
+    tag = "play",
+    params(GetParams),
+    responses(
+        (status = OK, description = "The puzzle has already been started.", body = GetResponse),
+        (status = CREATED, description = "The puzzle has been started.", body = GetResponse),
+        (status = BAD_REQUEST, description = "The date or the number is invalid.", body = String),
+        (status = NOT_FOUND, description = "The session token is missing or invalid."),
+        (status = INTERNAL_SERVER_ERROR, description = "A database error occurred.", body = String),
+    ),
+    security(("session" = [])),
+)]
 pub async fn get(
+    State(repos): State<Repos>,
     session: Option<Extension<SessionToken>>,
     Query(params): Query<GetParams>,
 ) -> impl IntoResponse {
@@ -53,34 +113,52 @@ pub async fn get(
         None => return (StatusCode::NOT_FOUND).into_response(),
     };
 
-    let db = database::acquire_or_response!();
-
-    let date = match PuzzleDate::try_from(&params.date[..]) {
+    let date = match puzzle_number::resolve(params.date.as_deref(), params.number) {
         Ok(date) => date,
         Err(err) => return (StatusCode::BAD_REQUEST, err.to_string()).into_response(),
     };
 
-    match insert_or_update_session(&db, &session).await {
+    match repos.sessions.insert_or_update_session(&session).await {
         Ok(_) => {}
         Err(err) => return (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()).into_response(),
     }
 
-    match get_history(&db, &date, &session).await {
-        Some(history) => (
-            StatusCode::OK,
-            Json(GetResponse {
-                letters_count: history.letters_count(),
-                remaining_tries: history.remaining_tries(),
-                is_completed: history.is_completed,
-                history: history
-                    .submit_history
-                    .map(SubmitHistory::into_vec)
-                    .unwrap_or_default(),
-            }),
-        )
-            .into_response(),
+    match repos.histories.get_history(&date, &session).await {
+        Some(history) => {
+            let state = history.game_state();
+            let share = share(
+                &date,
+                state,
+                &history.submit_history.clone().unwrap_or_default(),
+                &history.hints.clone().unwrap_or_default(),
+            );
+            (
+                StatusCode::OK,
+                Json(GetResponse {
+                    letters_count: history.letters_count(),
+                    remaining_tries: history.remaining_tries(),
+                    is_completed: state.is_won(),
+                    is_over: state.is_over(),
+                    state,
+                    solution: state.reveals_solution().then_some(history.solution),
+                    keyboard: history
+                        .submit_history
+                        .as_ref()
+                        .map(SubmitHistory::keyboard)
+                        .unwrap_or_default(),
+                    history: history
+                        .submit_history
+                        .map(SubmitHistory::into_vec)
+                        .unwrap_or_default(),
+                    hints: history.hints.map(Hints::into_vec).unwrap_or_default(),
+                    timing: history.timing,
+                    share,
+                }),
+            )
+                .into_response()
+        }
         None => {
-            let solution = match get_puzzle(&db, &date).await {
+            let solution = match repos.puzzles.get_puzzle(&date).await {
                 Some(Puzzle { solution, .. }) => solution,
                 None => {
                     let str = random_word::get_len(5, random_word::Lang::En).unwrap();
@@ -92,8 +170,11 @@ pub async fn get(
                         }
                     };
 
-                    match insert_solution(&db, &date, &solution).await {
-                        Ok(_) => solution,
+                    match repos.puzzles.insert_solution(&date, &solution, None).await {
+                        Ok(_) => {
+                            difficulty::spawn_rating(repos.clone(), date.clone(), solution.clone());
+                            solution
+                        }
                         Err(err) => {
                             return (StatusCode::INTERNAL_SERVER_ERROR, err.to_string())
                                 .into_response();
@@ -102,17 +183,33 @@ pub async fn get(
                 }
             };
 
-            match create_history(&db, &date, &session, &solution).await {
-                Ok(_) => (
-                    StatusCode::CREATED,
-                    Json(GetResponse {
-                        letters_count: PUZZLE_LETTERS_COUNT,
-                        remaining_tries: HISTORY_MAX_TRIES,
-                        is_completed: false,
-                        ..Default::default()
-                    }),
-                )
-                    .into_response(),
+            let time_limit = match params.mode {
+                PlayMode::Classic => None,
+                PlayMode::TimeAttack => TimeDelta::from_std(*TIME_ATTACK_LIMIT).ok(),
+            };
+            let timing = Timing::new(Utc::now(), time_limit);
+            let mut outbox = LIVE.outbox(&date).await;
+            match repos
+                .histories
+                .create_history(&date, &session, &solution, &timing)
+                .await
+            {
+                Ok(_) => {
+                    outbox.push(LiveEvent::Started);
+                    outbox.publish();
+                    (
+                        StatusCode::CREATED,
+                        Json(GetResponse {
+                            letters_count: PUZZLE_LETTERS_COUNT,
+                            remaining_tries: HISTORY_MAX_TRIES,
+                            is_completed: false,
+                            is_over: false,
+                            timing: Some(timing),
+                            ..Default::default()
+                        }),
+                    )
+                        .into_response()
+                }
                 Err(err) => (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()).into_response(),
             }
         }
@@ -2,43 +2,98 @@
 
 use crate::{
     WORDS,
-    database::{self, tables::histories::submit_to_history},
-    middleware::session::SessionToken,
+    database::repo::{Repos, SubmitError, SubmitResult},
+    endpoint::{play::share, puzzles::results::beaten_percent},
+    middleware::{address::ClientAddress, session::SessionToken},
+    puzzle_number,
 };
 
-use axum::{Extension, Json, extract::Query, http::StatusCode, response::IntoResponse};
-use entity::{PuzzleDate, PuzzleSolution, SubmitWord};
+use axum::{
+    Extension, Json,
+    extract::{Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use entity::{GameState, Hint, Keyboard, PuzzleDate, PuzzleSolution, SubmitWord, Timing};
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 /// The parameters for the post request.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, utoipa::IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct PostParams {
-    /// The date of the puzzle.
-    pub date: String,
+    /// The date of the puzzle in `YYYY-MM-DD` format, unless given by `number`.
+    pub date: Option<String>,
+    /// The sequential number of the puzzle, unless given by `date`.
+    pub number: Option<u64>,
 }
 
 /// The payload for the post request.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+#[schema(as = SubmitPayload)]
 pub struct PostPayload {
     /// The answer being submitted.
     pub answer: String,
 }
 
 /// The response for the post request.
-#[derive(Debug, Clone, Default, Serialize)]
+#[derive(Debug, Clone, Default, Serialize, ToSchema)]
+#[schema(as = SubmitResponse)]
 pub struct PostResponse {
     /// The number of letters in the word.
     pub letters_count: usize,
     /// The number of remaining tries.
     pub remaining_tries: usize,
-    /// Whether the puzzle has been completed.
+    /// Whether the puzzle has been solved.
     pub is_completed: bool,
+    /// Whether the game is over, either won or lost.
+    pub is_over: bool,
+    /// The game state.
+    pub state: GameState,
+    /// The solution, revealed once the game is lost.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub solution: Option<PuzzleSolution>,
     /// The history of submitted words.
     pub history: Vec<SubmitWord>,
+    /// The letter states known from the history.
+    pub keyboard: Keyboard,
+    /// The hints used.
+    pub hints: Vec<Hint>,
+    /// The server-side timing, which is missing for games that are not timed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timing: Option<Timing>,
+    /// The text to share, once the game is over.
+    ///
+    /// See: [`share`]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub share: Option<String>,
+    /// The percentage of other players beaten, once a daily puzzle is over.
+    ///
+    /// See: [`beaten_percent`]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub beaten_percent: Option<u32>,
 }
 
 /// The client submits a word to solve the puzzle.
+#[utoipa::path(
+    post,
+    path = "/play/submit",
+    operation_id = "submit_answer",
+    tag = "play",
+    params(PostParams),
+    request_body = PostPayload,
+    responses(
+        (status = ACCEPTED, description = "The answer has been submitted.", body = PostResponse),
+        (status = BAD_REQUEST, description = "The date or the answer is invalid."),
+        (status = CONFLICT, description = "The game is over and accepts no further answers, or the deadline of the time attack has been missed and the game is lost.", body = PostResponse),
+        (status = NOT_FOUND, description = "The session token is missing or invalid."),
+        (status = INTERNAL_SERVER_ERROR, description = "A database error occurred.", body = String),
+    ),
+    security(("session" = [])),
+)]
 pub async fn post(
+    ClientAddress(address): ClientAddress,
+    State(repos): State<Repos>,
     session: Option<Extension<SessionToken>>,
     Query(params): Query<PostParams>,
     Json(payload): Json<PostPayload>,
@@ -48,27 +103,67 @@ pub async fn post(
         None => return (StatusCode::NOT_FOUND).into_response(),
     };
 
-    let db = database::acquire_or_response!();
-
     let (date, answer) = match (
-        PuzzleDate::try_from(&params.date[..]),
+        puzzle_number::resolve(params.date.as_deref(), params.number),
         PuzzleSolution::try_from(&payload.answer[..]),
     ) {
         (Ok(date), Ok(answer)) if WORDS.contains(&&answer.to_string()[..]) => (date, answer),
         _ => return (StatusCode::BAD_REQUEST).into_response(),
     };
 
-    match submit_to_history(&db, &date, &session, &answer).await {
-        Ok(result) => (
-            StatusCode::ACCEPTED,
-            Json(PostResponse {
-                letters_count: result.submit_history.letters_count(),
-                remaining_tries: result.submit_history.remaining_tries(),
-                is_completed: result.is_completed,
-                history: result.submit_history.into_vec(),
-            }),
-        )
-            .into_response(),
+    match repos
+        .histories
+        .submit_to_history(&date, &session, &answer, &address)
+        .await
+    {
+        Ok(result) => {
+            let response = daily_response(&repos, &date, result).await;
+            (StatusCode::ACCEPTED, Json(response)).into_response()
+        }
+        Err(SubmitError::GameOver(result)) => {
+            let response = daily_response(&repos, &date, result).await;
+            (StatusCode::CONFLICT, Json(response)).into_response()
+        }
         Err(err) => (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()).into_response(),
     }
 }
+
+/// Creates the response of a daily puzzle, telling the text to share and how many players were
+/// beaten once it is over.
+async fn daily_response(repos: &Repos, date: &PuzzleDate, result: SubmitResult) -> PostResponse {
+    let share = share(date, result.state, &result.submit_history, &result.hints);
+    let mut response = PostResponse::from(result);
+    response.share = share;
+    if response.state.is_over() {
+        let counts = repos.results.get_result_counts(date).await;
+        response.beaten_percent = beaten_percent(&counts, response.state);
+    }
+    response
+}
+
+impl From<SubmitResult> for PostResponse {
+    fn from(
+        SubmitResult {
+            submit_history,
+            state,
+            solution,
+            hints,
+            timing,
+        }: SubmitResult,
+    ) -> Self {
+        Self {
+            letters_count: submit_history.letters_count(),
+            remaining_tries: submit_history.remaining_tries(),
+            is_completed: state.is_won(),
+            is_over: state.is_over(),
+            state,
+            solution: state.reveals_solution().then_some(solution),
+            keyboard: submit_history.keyboard(),
+            history: submit_history.into_vec(),
+            hints: hints.into_vec(),
+            timing,
+            share: None,
+            beaten_percent: None,
+        }
+    }
+}
@@ -0,0 +1,146 @@
+//! Endpoint `/play/versus/{id}`.
+//!
+//! Versus matches pit two players against each other on a single board: they alternate guesses on
+//! a shared history, and whoever tints the solution wins. Matches are kept apart from the daily
+//! puzzles.
+
+use crate::{
+    database::repo::Repos,
+    env::{VERSUS_LOBBY_TIMEOUT, VERSUS_TURN_TIMEOUT},
+    middleware::session::SessionToken,
+};
+
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use chrono::{DateTime, TimeDelta, Utc};
+use entity::{Keyboard, PuzzleSolution, SubmitWord, versus_matches::Model as VersusMatch};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+pub mod queue;
+pub mod submit;
+
+/// The status of a versus match, as seen by one of its players.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum VersusStatus {
+    /// The match is waiting in the lobby for an opponent.
+    Waiting,
+    /// The match has waited in the lobby for longer than [`VERSUS_LOBBY_TIMEOUT`] and is never
+    /// matched.
+    Expired,
+    /// The player may submit a word.
+    YourTurn,
+    /// The opponent may submit a word.
+    OpponentTurn,
+    /// The player has tinted the solution, or the opponent has timed out.
+    Won,
+    /// The opponent has tinted the solution, or the player has timed out.
+    Lost,
+    /// The shared history is full without the solution.
+    Drawn,
+}
+
+/// The response for a versus match.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[schema(as = VersusResponse)]
+pub struct VersusResponse {
+    /// The opaque id of the match.
+    pub id: String,
+    /// The number of letters in the word.
+    pub letters_count: usize,
+    /// The status of the match for the player.
+    pub status: VersusStatus,
+    /// The time the current turn times out, after which the idle player loses.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub turn_deadline: Option<DateTime<Utc>>,
+    /// The solution, revealed once the match is over.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub solution: Option<PuzzleSolution>,
+    /// The history of words submitted by both players, starting from the host.
+    pub history: Vec<SubmitWord>,
+    /// The letter states known from the history.
+    pub keyboard: Keyboard,
+}
+
+impl VersusResponse {
+    /// Creates a response from a match as seen by one of its players.
+    pub fn new(game: &VersusMatch, session: &str) -> Self {
+        let history = game.submit_history.clone().unwrap_or_default();
+        let lobby_timeout = TimeDelta::from_std(*VERSUS_LOBBY_TIMEOUT).unwrap_or(TimeDelta::MAX);
+        let turn_timeout = TimeDelta::from_std(*VERSUS_TURN_TIMEOUT).unwrap_or(TimeDelta::MAX);
+
+        let status = if game.is_over() {
+            match &game.winner {
+                Some(winner) if winner == session => VersusStatus::Won,
+                Some(_) => VersusStatus::Lost,
+                None => VersusStatus::Drawn,
+            }
+        } else {
+            match game.turn() {
+                Some(turn) if turn == session => VersusStatus::YourTurn,
+                Some(_) => VersusStatus::OpponentTurn,
+                None if Utc::now().naive_utc() - game.created_at >= lobby_timeout => {
+                    VersusStatus::Expired
+                }
+                None => VersusStatus::Waiting,
+            }
+        };
+
+        Self {
+            id: game.id.clone(),
+            letters_count: game.letters_count(),
+            status,
+            turn_deadline: game
+                .turn()
+                .and_then(|_| game.turn_started_at.checked_add_signed(turn_timeout))
+                .map(|deadline| deadline.and_utc()),
+            solution: game.is_over().then(|| game.solution.clone()),
+            keyboard: Keyboard::new(&history),
+            history: history.0,
+        }
+    }
+}
+
+/// The player gets a versus match, where an idle turn is forfeited first.
+#[utoipa::path(
+    get,
+    path = "/play/versus/{id}",
+    operation_id = "get_versus",
+    tag = "play",
+    params(("id" = String, Path, description = "The opaque id of the match.")),
+    responses(
+        (status = OK, description = "The match is played by the session.", body = VersusResponse),
+        (status = NOT_FOUND, description = "The session token is missing or invalid, or the session plays no such match."),
+        (status = INTERNAL_SERVER_ERROR, description = "A database error occurred.", body = String),
+    ),
+    security(("session" = [])),
+)]
+pub async fn get(
+    State(repos): State<Repos>,
+    session: Option<Extension<SessionToken>>,
+    Path(id): Path<String>,
+) -> impl IntoResponse {
+    let session = match session {
+        Some(Extension(SessionToken(session))) => session,
+        None => return (StatusCode::NOT_FOUND).into_response(),
+    };
+
+    let turn_timeout = TimeDelta::from_std(*VERSUS_TURN_TIMEOUT).unwrap_or(TimeDelta::MAX);
+    match repos
+        .versus
+        .settle_versus(&id, &session, turn_timeout)
+        .await
+    {
+        Ok(Some(game)) => {
+            (StatusCode::OK, Json(VersusResponse::new(&game, &session))).into_response()
+        }
+        Ok(None) => (StatusCode::NOT_FOUND).into_response(),
+        Err(err) => (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()).into_response(),
+    }
+}
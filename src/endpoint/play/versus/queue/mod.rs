@@ -0,0 +1,82 @@
+//! Endpoint `/play/versus/queue`.
+
+use crate::{
+    ANSWERS,
+    database::repo::Repos,
+    endpoint::play::{practice::generate_practice_id, versus::VersusResponse},
+    env::VERSUS_LOBBY_TIMEOUT,
+    middleware::session::SessionToken,
+};
+
+use axum::{Extension, Json, extract::State, http::StatusCode, response::IntoResponse};
+use chrono::{TimeDelta, Utc};
+use rand::seq::IndexedRandom as _;
+
+/// The player queues for a versus match.
+///
+/// The player resumes their own match still waiting in the lobby, or joins the oldest match
+/// waiting for an opponent, or else hosts a new match in the lobby. Matches waiting for longer
+/// than [`VERSUS_LOBBY_TIMEOUT`] are never matched. New matches draw their solution from
+/// [`ANSWERS`].
+#[utoipa::path(
+    post,
+    path = "/play/versus/queue",
+    operation_id = "queue_versus",
+    tag = "play",
+    responses(
+        (status = OK, description = "The player is waiting in the lobby, or has joined a match.", body = VersusResponse),
+        (status = CREATED, description = "The player hosts a new match waiting in the lobby.", body = VersusResponse),
+        (status = NOT_FOUND, description = "The session token is missing or invalid."),
+        (status = INTERNAL_SERVER_ERROR, description = "A database error occurred.", body = String),
+    ),
+    security(("session" = [])),
+)]
+pub async fn post(
+    State(repos): State<Repos>,
+    session: Option<Extension<SessionToken>>,
+) -> impl IntoResponse {
+    let session = match session {
+        Some(Extension(SessionToken(session))) => session,
+        None => return (StatusCode::NOT_FOUND).into_response(),
+    };
+
+    match repos.sessions.insert_or_update_session(&session).await {
+        Ok(_) => {}
+        Err(err) => return (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()).into_response(),
+    }
+
+    let lobby_timeout = TimeDelta::from_std(*VERSUS_LOBBY_TIMEOUT).unwrap_or(TimeDelta::MAX);
+    let since = Utc::now()
+        .naive_utc()
+        .checked_sub_signed(lobby_timeout)
+        .unwrap_or_default();
+
+    if let Some(game) = repos.versus.get_waiting_versus(&session, since).await {
+        return (StatusCode::OK, Json(VersusResponse::new(&game, &session))).into_response();
+    }
+
+    match repos.versus.join_waiting_versus(&session, since).await {
+        Ok(Some(game)) => {
+            return (StatusCode::OK, Json(VersusResponse::new(&game, &session))).into_response();
+        }
+        Ok(None) => {}
+        Err(err) => return (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()).into_response(),
+    }
+
+    let Some(solution) = ANSWERS.choose(&mut rand::rng()) else {
+        return (StatusCode::INTERNAL_SERVER_ERROR, "no answers to draw from").into_response();
+    };
+
+    match repos
+        .versus
+        .create_versus(&generate_practice_id(), &session, solution)
+        .await
+    {
+        Ok(game) => (
+            StatusCode::CREATED,
+            Json(VersusResponse::new(&game, &session)),
+        )
+            .into_response(),
+        Err(err) => (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()).into_response(),
+    }
+}
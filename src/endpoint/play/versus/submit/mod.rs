@@ -0,0 +1,76 @@
+//! Endpoint `/play/versus/{id}/submit`.
+
+use crate::{
+    WORDS,
+    database::repo::{Repos, VersusError},
+    endpoint::play::{submit::PostPayload, versus::VersusResponse},
+    env::VERSUS_TURN_TIMEOUT,
+    middleware::session::SessionToken,
+};
+
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use chrono::TimeDelta;
+use entity::PuzzleSolution;
+
+/// The player submits a word to the shared history of a versus match on their turn.
+#[utoipa::path(
+    post,
+    path = "/play/versus/{id}/submit",
+    operation_id = "submit_versus_answer",
+    tag = "play",
+    params(("id" = String, Path, description = "The opaque id of the match.")),
+    request_body = PostPayload,
+    responses(
+        (status = ACCEPTED, description = "The answer has been submitted.", body = VersusResponse),
+        (status = BAD_REQUEST, description = "The answer is invalid."),
+        (status = FORBIDDEN, description = "The match is waiting for an opponent, or it is the turn of the opponent.", body = String),
+        (status = CONFLICT, description = "The match is over, possibly because the turn has timed out.", body = VersusResponse),
+        (status = NOT_FOUND, description = "The session token is missing or invalid, or the session plays no such match."),
+        (status = INTERNAL_SERVER_ERROR, description = "A database error occurred.", body = String),
+    ),
+    security(("session" = [])),
+)]
+pub async fn post(
+    State(repos): State<Repos>,
+    session: Option<Extension<SessionToken>>,
+    Path(id): Path<String>,
+    Json(payload): Json<PostPayload>,
+) -> impl IntoResponse {
+    let session = match session {
+        Some(Extension(SessionToken(session))) => session,
+        None => return (StatusCode::NOT_FOUND).into_response(),
+    };
+
+    let answer = match PuzzleSolution::try_from(&payload.answer[..]) {
+        Ok(answer) if WORDS.contains(&&answer.to_string()[..]) => answer,
+        _ => return (StatusCode::BAD_REQUEST).into_response(),
+    };
+
+    let turn_timeout = TimeDelta::from_std(*VERSUS_TURN_TIMEOUT).unwrap_or(TimeDelta::MAX);
+    match repos
+        .versus
+        .submit_to_versus(&id, &session, &answer, turn_timeout)
+        .await
+    {
+        Ok(game) => (
+            StatusCode::ACCEPTED,
+            Json(VersusResponse::new(&game, &session)),
+        )
+            .into_response(),
+        Err(VersusError::GameOver(game)) => (
+            StatusCode::CONFLICT,
+            Json(VersusResponse::new(&game, &session)),
+        )
+            .into_response(),
+        Err(err @ (VersusError::Waiting | VersusError::NotYourTurn)) => {
+            (StatusCode::FORBIDDEN, err.to_string()).into_response()
+        }
+        Err(VersusError::NotFound) => (StatusCode::NOT_FOUND).into_response(),
+        Err(err) => (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()).into_response(),
+    }
+}
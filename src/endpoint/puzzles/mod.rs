@@ -0,0 +1,3 @@
+//! Endpoint `/puzzles`.
+
+pub mod results;
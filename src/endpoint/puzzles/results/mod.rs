@@ -0,0 +1,182 @@
+//! Endpoint `/puzzles/{date}/results`.
+
+use crate::{
+    database::repo::Repos, endpoint::play::has_ended, middleware::session::SessionToken,
+    puzzle_number,
+};
+
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use chrono::Utc;
+use entity::{
+    GameState, HISTORY_MAX_TRIES, PuzzleDate, PuzzleSolution, puzzles::Model as Puzzle,
+    result_counts::Model as ResultCount,
+};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// The number of most common first guesses returned.
+const FIRST_GUESSES_LIMIT: usize = 10;
+
+/// A first guess and the number of games starting with it.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct FirstGuess {
+    /// The guessed word.
+    pub word: String,
+    /// The number of games starting with the word.
+    pub count: usize,
+}
+
+/// The response for the get request.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[schema(as = ResultsResponse)]
+pub struct GetResponse {
+    /// The date of the puzzle.
+    pub date: PuzzleDate,
+    /// The sequential number of the puzzle, if numbered.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub number: Option<u64>,
+    /// The number of finished games.
+    pub played: usize,
+    /// The number of games won.
+    pub won: usize,
+    /// The number of games lost.
+    pub lost: usize,
+    /// The ratio of games lost to games finished, between 0 and 1.
+    pub failure_rate: f64,
+    /// The average number of guesses of the games won.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub average_guesses: Option<f64>,
+    /// The number of games won by guess count, starting from a single guess.
+    pub distribution: Vec<usize>,
+    /// The most common first guesses, most common first.
+    pub first_guesses: Vec<FirstGuess>,
+    /// The solution, revealed once the day is over in every timezone.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub solution: Option<PuzzleSolution>,
+    /// The percentage of other players the session did better than, once it has finished.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub beaten_percent: Option<u32>,
+}
+
+impl GetResponse {
+    /// Aggregates the counts of the finished games of a date.
+    pub fn new(date: PuzzleDate, counts: &[ResultCount]) -> Self {
+        let mut distribution = vec![0; HISTORY_MAX_TRIES];
+        let (mut won, mut lost, mut guesses) = (0, 0, 0);
+        for count in counts {
+            let games = usize::try_from(count.count).unwrap_or_default();
+            let tries = usize::try_from(count.tries).unwrap_or_default();
+            if count.is_won {
+                won += games;
+                guesses += games * tries;
+                if let Some(slot) = tries.checked_sub(1).and_then(|i| distribution.get_mut(i)) {
+                    *slot += games;
+                }
+            } else {
+                lost += games;
+            }
+        }
+
+        let played = won + lost;
+        Self {
+            number: puzzle_number::number_of(&date),
+            date,
+            played,
+            won,
+            lost,
+            failure_rate: if played == 0 {
+                0.0
+            } else {
+                lost as f64 / played as f64
+            },
+            average_guesses: (won > 0).then(|| guesses as f64 / won as f64),
+            distribution,
+            first_guesses: Vec::new(),
+            solution: None,
+            beaten_percent: None,
+        }
+    }
+}
+
+/// Returns the percentage of the other finished games a finished game did better than.
+///
+/// A lost game beats nobody, while a won game beats every lost game and every game won in more
+/// guesses. The percentage is missing if the game is not over or nobody else has finished.
+pub fn beaten_percent(counts: &[ResultCount], state: GameState) -> Option<u32> {
+    let played: i64 = counts.iter().map(|count| count.count).sum();
+    let others = played - 1;
+    if others <= 0 || !state.is_over() {
+        return None;
+    }
+
+    let beaten: i64 = match state {
+        GameState::Won { tries } => counts
+            .iter()
+            .filter(|count| {
+                !count.is_won || usize::try_from(count.tries).is_ok_and(|other| other > tries)
+            })
+            .map(|count| count.count)
+            .sum(),
+        _ => 0,
+    };
+    u32::try_from(beaten * 100 / others).ok()
+}
+
+/// The client gets the aggregate results of a puzzle.
+///
+/// The results are materialised as games finish. The solution is only revealed once the day is
+/// over in every timezone, and a session which has finished the puzzle is told how many players it beat.
+#[utoipa::path(
+    get,
+    path = "/puzzles/{date}/results",
+    operation_id = "get_puzzle_results",
+    tag = "puzzles",
+    params(("date" = String, Path, description = "The date of the puzzle in `YYYY-MM-DD` format, or its sequential number.")),
+    responses(
+        (status = OK, description = "The aggregate results of the puzzle.", body = GetResponse),
+        (status = BAD_REQUEST, description = "The date or the number is invalid.", body = String),
+        (status = NOT_FOUND, description = "The puzzle does not exist."),
+    ),
+    security((), ("session" = [])),
+)]
+pub async fn get(
+    State(repos): State<Repos>,
+    session: Option<Extension<SessionToken>>,
+    Path(date): Path<String>,
+) -> impl IntoResponse {
+    let date = match puzzle_number::parse(&date) {
+        Ok(date) => date,
+        Err(err) => return (StatusCode::BAD_REQUEST, err.to_string()).into_response(),
+    };
+
+    let Some(Puzzle { solution, .. }) = repos.puzzles.get_puzzle(&date).await else {
+        return (StatusCode::NOT_FOUND).into_response();
+    };
+
+    let counts = repos.results.get_result_counts(&date).await;
+    let mut response = GetResponse::new(date.clone(), &counts);
+    response.first_guesses = repos
+        .results
+        .get_first_guess_counts(&date, FIRST_GUESSES_LIMIT)
+        .await
+        .into_iter()
+        .map(|count| FirstGuess {
+            word: count.word,
+            count: usize::try_from(count.count).unwrap_or_default(),
+        })
+        .collect();
+    response.solution = has_ended(&date, Utc::now()).then_some(solution);
+
+    if let Some(Extension(SessionToken(session))) = session
+        && let Some(history) = repos.histories.get_history(&date, &session).await
+    {
+        response.beaten_percent = beaten_percent(&counts, history.game_state());
+    }
+
+    (StatusCode::OK, Json(response)).into_response()
+}
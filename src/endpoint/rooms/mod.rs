@@ -0,0 +1,72 @@
+//! Endpoint `/rooms`.
+//!
+//! Race rooms let friends solve the same word at once, pushing their progress over a WebSocket.
+//!
+//! See: [`ROOMS`]
+
+use crate::{ANSWERS, WORDS, middleware::session::SessionToken, rooms::ROOMS};
+
+use axum::{Extension, Json, http::StatusCode, response::IntoResponse};
+use entity::PuzzleSolution;
+use rand::seq::IndexedRandom as _;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+pub mod ws;
+
+/// The payload for the post request.
+#[derive(Debug, Clone, Default, Deserialize, ToSchema)]
+#[schema(as = RoomPayload)]
+pub struct PostPayload {
+    /// The solution to race on, which is drawn from the answers if not specified.
+    pub solution: Option<String>,
+}
+
+/// The response for the post request.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[schema(as = RoomResponse)]
+pub struct PostResponse {
+    /// The code to join the room with.
+    pub code: String,
+}
+
+/// The host creates a race room, on a solution drawn from [`ANSWERS`] unless specified.
+#[utoipa::path(
+    post,
+    path = "/rooms",
+    operation_id = "create_room",
+    tag = "rooms",
+    request_body = PostPayload,
+    responses(
+        (status = CREATED, description = "The room has been created.", body = PostResponse),
+        (status = BAD_REQUEST, description = "The solution is invalid."),
+        (status = NOT_FOUND, description = "The session token is missing or invalid."),
+        (status = INTERNAL_SERVER_ERROR, description = "There are no answers to draw from.", body = String),
+    ),
+    security(("session" = [])),
+)]
+pub async fn post(
+    session: Option<Extension<SessionToken>>,
+    Json(payload): Json<PostPayload>,
+) -> impl IntoResponse {
+    if session.is_none() {
+        return (StatusCode::NOT_FOUND).into_response();
+    }
+
+    let solution = match payload.solution {
+        Some(solution) => match PuzzleSolution::try_from(&solution[..]) {
+            Ok(solution) if WORDS.contains(&&solution.to_string()[..]) => solution,
+            _ => return (StatusCode::BAD_REQUEST).into_response(),
+        },
+        None => match ANSWERS.choose(&mut rand::rng()) {
+            Some(solution) => solution.clone(),
+            None => {
+                return (StatusCode::INTERNAL_SERVER_ERROR, "no answers to draw from")
+                    .into_response();
+            }
+        },
+    };
+
+    let code = ROOMS.create(solution);
+    (StatusCode::CREATED, Json(PostResponse { code })).into_response()
+}
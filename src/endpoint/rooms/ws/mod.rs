@@ -0,0 +1,167 @@
+//! Endpoint `/rooms/{code}/ws`.
+
+use crate::{
+    WORDS,
+    middleware::session::SessionToken,
+    rooms::{ROOMS, RoomError, RoomEvent},
+};
+
+use axum::{
+    Extension,
+    extract::{
+        Path, Query,
+        ws::{Message, WebSocket, WebSocketUpgrade},
+    },
+    http::StatusCode,
+    response::IntoResponse,
+};
+use entity::{GameState, PuzzleSolution, SubmitWord};
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast::{Receiver, error::RecvError};
+
+/// The parameters for the get request.
+#[derive(Debug, Clone, Deserialize, utoipa::IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct GetParams {
+    /// The display name of the player.
+    pub name: String,
+}
+
+/// The messages sent by a player.
+#[derive(Debug, Clone, Deserialize)]
+struct ClientMessage {
+    answer: String,
+}
+
+/// The messages sent to a single player, besides the [`RoomEvent`]s of the room.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum PlayerMessage {
+    /// The player has joined the room.
+    Welcome { player: String },
+    /// The word submitted by the player, with its letters.
+    Submitted { word: SubmitWord, state: GameState },
+    /// The message of the player has been rejected.
+    Rejected { reason: String },
+}
+
+/// The player joins a race room over a WebSocket.
+///
+/// Each text message of the player holds an answer such as `{"answer": "crane"}`. The player is
+/// told the tinted word privately, while every player is pushed the [`RoomEvent`]s of the room,
+/// whose tints carry no letters. Leaving the socket leaves the room.
+#[utoipa::path(
+    get,
+    path = "/rooms/{code}/ws",
+    operation_id = "join_room",
+    tag = "rooms",
+    params(("code" = String, Path, description = "The room code."), GetParams),
+    responses(
+        (status = SWITCHING_PROTOCOLS, description = "The WebSocket has been opened."),
+        (status = NOT_FOUND, description = "The session token is missing or invalid, or the room does not exist."),
+    ),
+    security(("session" = [])),
+)]
+pub async fn get(
+    session: Option<Extension<SessionToken>>,
+    Path(code): Path<String>,
+    Query(params): Query<GetParams>,
+    upgrade: WebSocketUpgrade,
+) -> impl IntoResponse {
+    let session = match session {
+        Some(Extension(SessionToken(session))) => session,
+        None => return (StatusCode::NOT_FOUND).into_response(),
+    };
+
+    if !ROOMS.contains(&code) {
+        return (StatusCode::NOT_FOUND).into_response();
+    }
+
+    // joins only once upgraded, so that a failed upgrade leaves no player behind
+    upgrade.on_upgrade(move |mut socket| async move {
+        let Some((player, events)) = ROOMS.join(&code, &session, &params.name) else {
+            let closed = PlayerMessage::Rejected {
+                reason: "the room does not exist".to_owned(),
+            };
+            send(&mut socket, &closed).await.ok();
+            socket.send(Message::Close(None)).await.ok();
+            return;
+        };
+
+        race(socket, &code, &session, player, events).await;
+        ROOMS.leave(&code, &session);
+    })
+}
+
+async fn race(
+    mut socket: WebSocket,
+    code: &str,
+    session: &str,
+    player: String,
+    mut events: Receiver<RoomEvent>,
+) {
+    if send(&mut socket, &PlayerMessage::Welcome { player })
+        .await
+        .is_err()
+    {
+        return;
+    }
+
+    loop {
+        tokio::select! {
+            message = socket.recv() => {
+                let text = match message {
+                    Some(Ok(Message::Text(text))) => text,
+                    Some(Ok(Message::Close(_)) | Err(_)) | None => return,
+                    Some(Ok(_)) => continue,
+                };
+                let reply = submit(code, session, &text);
+                if send(&mut socket, &reply).await.is_err() {
+                    return;
+                }
+            }
+            event = events.recv() => {
+                match event {
+                    Ok(RoomEvent::Closed) | Err(RecvError::Closed) => {
+                        send(&mut socket, &RoomEvent::Closed).await.ok();
+                        socket.send(Message::Close(None)).await.ok();
+                        return;
+                    }
+                    Ok(event) => {
+                        if send(&mut socket, &event).await.is_err() {
+                            return;
+                        }
+                    }
+                    Err(RecvError::Lagged(missed)) => {
+                        tracing::warn!("a player of room {code} missed {missed} events!");
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn submit(code: &str, session: &str, text: &str) -> PlayerMessage {
+    let rejected = |reason: &str| PlayerMessage::Rejected {
+        reason: reason.to_owned(),
+    };
+
+    let Ok(ClientMessage { answer }) = serde_json::from_str(text) else {
+        return rejected("the message is invalid");
+    };
+    let answer = match PuzzleSolution::try_from(&answer[..]) {
+        Ok(answer) if WORDS.contains(&&answer.to_string()[..]) => answer,
+        _ => return rejected("the answer is invalid"),
+    };
+
+    match ROOMS.submit(code, session, &answer) {
+        Ok((word, state)) => PlayerMessage::Submitted { word, state },
+        Err(RoomError::GameOver(state)) => rejected(&format!("the game is over: {state}")),
+        Err(err) => rejected(&err.to_string()),
+    }
+}
+
+async fn send<T: Serialize + Sync>(socket: &mut WebSocket, message: &T) -> Result<(), axum::Error> {
+    let text = serde_json::to_string(message).unwrap_or_default();
+    socket.send(Message::Text(text.into())).await
+}
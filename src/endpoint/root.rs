@@ -1,31 +1,50 @@
 //! Endpoint root.
 
-use axum::{Json, extract::Query, http::StatusCode, response::IntoResponse};
+use axum::{
+    Json,
+    extract::{Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
 use chrono::Datelike as _;
-use entity::puzzles::Model as Puzzle;
 use entity::{PuzzleDate, PuzzleSolution, puzzles::ResultPuzzle};
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
-use crate::database::{
-    self,
-    tables::puzzles::{get_puzzle, get_puzzles, insert_solution},
-};
+use crate::{database::repo::Repos, difficulty, env::PUZZLE_EPOCH, puzzle_number};
 
 /// The parameters for the get request.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, utoipa::IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct GetParams {
     /// The date of the puzzle to get.
     pub date: Option<String>,
+    /// The sequential number of the puzzle to get, instead of its date.
+    pub number: Option<u64>,
     /// Whether to generate a new puzzle if missing.
     pub generate_if_missing: Option<bool>,
 }
 
+/// The response for the get request.
+#[non_exhaustive]
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(untagged)]
+#[schema(as = PuzzlesGetResponse)]
+pub enum GetResponse {
+    /// A single puzzle, when a date is specified.
+    Puzzle(GetResponsePuzzle),
+    /// All puzzles, when no date is specified.
+    Puzzles(GetResponsePuzzles),
+}
+
 /// The response for a single puzzle get request.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[schema(as = PuzzleResponse)]
 pub struct GetResponsePuzzle(ResultPuzzle);
 
 /// The response for multiple puzzles get request.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[schema(as = PuzzlesResponse)]
 pub struct GetResponsePuzzles {
     /// The number of available puzzles.
     pub count: usize,
@@ -38,19 +57,33 @@ pub struct GetResponsePuzzles {
 /// # Panics
 ///
 /// Panics if cannot get a random word from [`random_word`].
-pub async fn get(Query(params): Query<GetParams>) -> impl IntoResponse {
-    let db = database::acquire_or_response!();
-
-    if let Some(date) = params.date {
-        let date = match PuzzleDate::try_from(&date[..]) {
+#[utoipa::path(
+    get,
+    path = "",
+    operation_id = "get_puzzles",
+    tag = "puzzles",
+    params(GetParams),
+    responses(
+        (status = OK, description = "The puzzle for the date, or all puzzles if no date is specified.", body = GetResponse),
+        (status = CREATED, description = "The puzzle was missing and has been generated.", body = GetResponsePuzzle),
+        (status = BAD_REQUEST, description = "The date or the number is invalid.", body = String),
+        (status = NOT_FOUND, description = "No puzzle exists for the date."),
+        (status = INTERNAL_SERVER_ERROR, description = "A database error occurred.", body = String),
+    ),
+)]
+pub async fn get(State(repos): State<Repos>, Query(params): Query<GetParams>) -> impl IntoResponse {
+    if params.date.is_some() || params.number.is_some() {
+        let date = match puzzle_number::resolve(params.date.as_deref(), params.number) {
             Ok(date) => date,
             Err(err) => return (StatusCode::BAD_REQUEST, err.to_string()).into_response(),
         };
 
-        if let Some(puzzle) = get_puzzle(&db, &date).await {
+        if let Some(puzzle) = repos.puzzles.get_puzzle(&date).await {
             (
                 StatusCode::OK,
-                Json(GetResponsePuzzle(puzzle.to_result_puzzle())),
+                Json(GetResponse::Puzzle(GetResponsePuzzle(
+                    puzzle.to_result_puzzle().with_number_from(&PUZZLE_EPOCH),
+                ))),
             )
                 .into_response()
         } else if params.generate_if_missing.unwrap_or(false) {
@@ -62,19 +95,36 @@ pub async fn get(Query(params): Query<GetParams>) -> impl IntoResponse {
                 }
             };
 
-            match insert_solution(&db, &date, &solution).await {
+            match repos.puzzles.insert_solution(&date, &solution, None).await {
                 Ok(_) => {
+                    difficulty::spawn_rating(repos.clone(), date.clone(), solution.clone());
                     if date.inner().year() == 2077 {
                         (
                             StatusCode::CREATED,
                             [("x-greeting", "Good morning, Night City!")],
-                            Json(GetResponsePuzzle(ResultPuzzle { date, solution })),
+                            Json(GetResponsePuzzle(
+                                ResultPuzzle {
+                                    date,
+                                    number: None,
+                                    solution,
+                                    difficulty: None,
+                                }
+                                .with_number_from(&PUZZLE_EPOCH),
+                            )),
                         )
                             .into_response()
                     } else {
                         (
                             StatusCode::CREATED,
-                            Json(GetResponsePuzzle(ResultPuzzle { date, solution })),
+                            Json(GetResponsePuzzle(
+                                ResultPuzzle {
+                                    date,
+                                    number: None,
+                                    solution,
+                                    difficulty: None,
+                                }
+                                .with_number_from(&PUZZLE_EPOCH),
+                            )),
                         )
                             .into_response()
                     }
@@ -85,31 +135,35 @@ pub async fn get(Query(params): Query<GetParams>) -> impl IntoResponse {
             (StatusCode::NOT_FOUND).into_response()
         }
     } else {
-        let puzzles: Vec<ResultPuzzle> = get_puzzles(&db)
+        let puzzles: Vec<ResultPuzzle> = repos
+            .puzzles
+            .get_puzzles()
             .await
             .into_iter()
-            .map(Puzzle::to_result_puzzle)
+            .map(|puzzle| puzzle.to_result_puzzle().with_number_from(&PUZZLE_EPOCH))
             .collect();
         (
             StatusCode::OK,
-            Json(GetResponsePuzzles {
+            Json(GetResponse::Puzzles(GetResponsePuzzles {
                 count: puzzles.len(),
                 puzzles,
-            }),
+            })),
         )
             .into_response()
     }
 }
 
 /// The parameters for the post request.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, utoipa::IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct PostParams {
     /// Whether to ignore conflict if the puzzle already exists.
     pub ignores_conflict: Option<bool>,
 }
 
 /// The payload for the post request.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+#[schema(as = PuzzlePayload)]
 pub struct PostPayload {
     /// The date of the puzzle.
     pub date: String,
@@ -118,12 +172,27 @@ pub struct PostPayload {
 }
 
 /// The client posted a puzzle.
+#[utoipa::path(
+    post,
+    path = "",
+    operation_id = "insert_puzzle",
+    tag = "puzzles",
+    params(PostParams),
+    request_body = PostPayload,
+    responses(
+        (status = CREATED, description = "The puzzle has been inserted."),
+        (status = BAD_REQUEST, description = "The date or the solution is invalid."),
+        (status = UNAUTHORIZED, description = "The PASETO token is missing or invalid."),
+        (status = CONFLICT, description = "A puzzle already exists for the date."),
+        (status = INTERNAL_SERVER_ERROR, description = "A database error occurred.", body = String),
+    ),
+    security(("paseto" = [])),
+)]
 pub async fn post(
+    State(repos): State<Repos>,
     Query(params): Query<PostParams>,
     Json(payload): Json<PostPayload>,
 ) -> impl IntoResponse {
-    let db = database::acquire_or_response!();
-
     let (date, solution) = match (
         PuzzleDate::try_from(&payload.date[..]),
         PuzzleSolution::try_from(&payload.solution[..]),
@@ -132,13 +201,17 @@ pub async fn post(
         _ => return (StatusCode::BAD_REQUEST).into_response(),
     };
 
-    if !params.ignores_conflict.unwrap_or(false) && get_puzzle(&db, &date).await.is_some() {
+    if !params.ignores_conflict.unwrap_or(false) && repos.puzzles.get_puzzle(&date).await.is_some()
+    {
         // there is an existing puzzle and we shouldn't proceed
         (StatusCode::CONFLICT).into_response()
     } else {
         // there isn't any existing puzzles
-        match insert_solution(&db, &date, &solution).await {
-            Ok(_) => (StatusCode::CREATED).into_response(),
+        match repos.puzzles.insert_solution(&date, &solution, None).await {
+            Ok(_) => {
+                difficulty::spawn_rating(repos, date, solution);
+                (StatusCode::CREATED).into_response()
+            }
             Err(err) => (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()).into_response(),
         }
     }
@@ -0,0 +1,75 @@
+use super::{TestApp, get, json, post_json, solution, with_session};
+
+use crate::middleware::{address::ClientAddress, auth::generate_paseto_token};
+
+use std::net::IpAddr;
+
+use axum::http::{StatusCode, header};
+use entity::{PuzzleDate, PuzzleSolution};
+use serde_json::json;
+
+#[tokio::test]
+async fn flags_lucky_first_guesses() {
+    let app = TestApp::new();
+    let session = app.session().await;
+    let dates = ["2025-01-01", "2025-01-02", "2025-01-03"];
+
+    for date in dates {
+        app.repos
+            .puzzles
+            .insert_solution(
+                &PuzzleDate::try_from(date).unwrap(),
+                &PuzzleSolution::try_from(solution()).unwrap(),
+                None,
+            )
+            .await
+            .unwrap();
+        let response = app
+            .send(with_session(
+                get(&format!("/v1/play/start?date={date}")),
+                &session,
+            ))
+            .await;
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let mut request = post_json(
+            &format!("/v1/play/submit?date={date}"),
+            &json!({ "answer": solution() }),
+        );
+        request.headers_mut().insert(
+            "x-forwarded-for",
+            "198.51.100.1, 203.0.113.7".parse().unwrap(),
+        );
+        let response = app.send(with_session(request, &session)).await;
+        assert_eq!(response.status(), StatusCode::ACCEPTED);
+    }
+
+    // the third first-guess win is flagged and left out of the results
+    let played = |date: &str| {
+        let request = with_session(get(&format!("/v1/puzzles/{date}/results")), &session);
+        async { json(app.send(request).await).await["played"].clone() }
+    };
+    assert_eq!(played(dates[1]).await, json!(1));
+    assert_eq!(played(dates[2]).await, json!(0));
+
+    let response = app.send(get("/v1/admin/flags")).await;
+    assert!(response.status().is_client_error());
+
+    let token = generate_paseto_token().await;
+    let mut request = get(&format!("/v1/admin/flags?date={}", dates[2]));
+    request.headers_mut().insert(
+        header::AUTHORIZATION,
+        format!("Bearer {token}").parse().unwrap(),
+    );
+    let response = app.send(request).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = json(response).await;
+    assert_eq!(body["count"], 1);
+    assert_eq!(body["histories"][0]["session"], session);
+    assert_eq!(
+        body["histories"][0]["flags"],
+        json!(["lucky_first_guesses"])
+    );
+    // the address seen by the proxy is stored hashed
+    let address = ClientAddress::hash(IpAddr::from([203, 0, 113, 7]));
+    assert_eq!(body["histories"][0]["address"], address.0);
+}
@@ -0,0 +1,91 @@
+use super::{DATE, TestApp, get, json, misses, post_json, solution, with_session};
+
+use axum::http::{StatusCode, header};
+use serde_json::json;
+
+#[tokio::test]
+async fn plays_custom_puzzle() {
+    let app = TestApp::new();
+    let creator = app.session().await;
+    let create = |payload| app.send(with_session(post_json("/v1/custom", &payload), &creator));
+
+    let response = create(json!({ "word": "zzzzz" })).await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let response = create(json!({ "word": solution(), "max_tries": 13 })).await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let response = create(json!({ "word": "zzzzz", "allow_any_word": true })).await;
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let response = create(json!({ "word": solution(), "max_tries": 2 })).await;
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let code = json(response).await["code"].as_str().unwrap().to_owned();
+
+    // the code is never accepted as a token to schedule puzzles
+    let mut request = post_json("/v1", &json!({ "date": DATE, "solution": solution() }));
+    request.headers_mut().insert(
+        header::AUTHORIZATION,
+        format!("Bearer {code}").parse().unwrap(),
+    );
+    assert_eq!(app.send(request).await.status(), StatusCode::UNAUTHORIZED);
+
+    let mut tampered = code.clone();
+    tampered.pop();
+    tampered.push(if code.ends_with('0') { '1' } else { '0' });
+    for code in [&tampered, &"v4.local.anything".to_owned()] {
+        let response = app
+            .send(with_session(
+                get(&format!("/v1/custom/{code}/start")),
+                &creator,
+            ))
+            .await;
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    let friend = app.session().await;
+    let submit = |answer: &str| {
+        app.send(with_session(
+            post_json(
+                &format!("/v1/custom/{code}/submit"),
+                &json!({ "answer": answer }),
+            ),
+            &friend,
+        ))
+    };
+    assert_eq!(submit(solution()).await.status(), StatusCode::NOT_FOUND);
+
+    let response = app
+        .send(with_session(
+            get(&format!("/v1/custom/{code}/start")),
+            &friend,
+        ))
+        .await;
+    assert_eq!(response.status(), StatusCode::CREATED);
+    assert_eq!(json(response).await["remaining_tries"], 2);
+
+    let mut misses = misses(solution());
+    assert_eq!(submit("zzzzz").await.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(
+        submit(misses.next().unwrap()).await.status(),
+        StatusCode::ACCEPTED
+    );
+    let response = submit(misses.next().unwrap()).await;
+    assert_eq!(response.status(), StatusCode::ACCEPTED);
+    let body = json(response).await;
+    assert_eq!(body["state"], json!({ "status": "lost" }));
+    assert_eq!(body["solution"], solution());
+    assert_eq!(submit(solution()).await.status(), StatusCode::CONFLICT);
+
+    let results = |session: &str| {
+        app.send(with_session(
+            get(&format!("/v1/custom/{code}/results")),
+            session,
+        ))
+    };
+    assert_eq!(results(&friend).await.status(), StatusCode::FORBIDDEN);
+    let response = results(&creator).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = json(response).await;
+    assert_eq!(body["players"], 1);
+    assert_eq!(body["lost"], 1);
+    assert_eq!(body["distribution"], json!([0, 0]));
+}
@@ -0,0 +1,49 @@
+use super::{DATE, LINK_TEMPLATE, TestApp, get, solution};
+
+use crate::puzzle_number;
+
+use axum::{
+    body::{Body, to_bytes},
+    http::{Response, StatusCode, header},
+};
+use entity::PuzzleDate;
+
+async fn text(response: Response<Body>) -> String {
+    let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    String::from_utf8(bytes.to_vec()).unwrap()
+}
+
+#[tokio::test]
+async fn exports_schedule_without_solutions() {
+    let app = TestApp::new();
+    app.schedule(solution()).await;
+
+    let date = PuzzleDate::try_from(DATE).unwrap();
+    let number = puzzle_number::number_of(&date).unwrap();
+    let link = LINK_TEMPLATE
+        .replace("{number}", &number.to_string())
+        .replace("{date}", DATE);
+
+    for (uri, content_type) in [
+        ("/feed.rss", "application/rss+xml"),
+        ("/feed.atom", "application/atom+xml"),
+        ("/calendar.ics", "text/calendar"),
+    ] {
+        let response = app.send(get(uri)).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        let header = response.headers()[header::CONTENT_TYPE].to_str().unwrap();
+        assert!(header.starts_with(content_type), "{uri}: {header}");
+
+        let body = text(response).await;
+        assert!(body.contains(&format!("#{number}")), "{uri}: {body}");
+        assert!(!body.to_lowercase().contains(solution()), "{uri}: {body}");
+        if uri == "/calendar.ics" {
+            assert!(body.contains("DTSTART;VALUE=DATE:20250101\r\n"));
+            assert!(body.contains(&format!("URL:{link}")));
+            assert!(body.lines().all(|line| line.trim_end().len() <= 75));
+        } else {
+            assert!(body.contains("Wordle &amp; Friends"));
+            assert!(body.contains(&link.replace('&', "&amp;")));
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use super::{TestApp, get, json, post_json, with_session};
+
+use crate::endpoint::groups::leaderboard::score;
+
+use axum::http::StatusCode;
+use entity::GameState;
+use serde_json::json;
+
+#[tokio::test]
+async fn joins_group_and_gets_leaderboard() {
+    let app = TestApp::new();
+    let owner = app.session().await;
+    let friend = app.session().await;
+    let outsider = app.session().await;
+
+    let payload = json!({ "name": "Friends", "nickname": " " });
+    let response = app
+        .send(with_session(post_json("/v1/groups", &payload), &owner))
+        .await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let payload = json!({ "name": "Friends", "nickname": "Owner" });
+    let response = app
+        .send(with_session(post_json("/v1/groups", &payload), &owner))
+        .await;
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let group = json(response).await;
+    let id = group["id"].as_str().unwrap();
+    let invite_code = group["invite_code"].as_str().unwrap();
+
+    let payload = json!({ "invite_code": "nowhere", "nickname": "Friend" });
+    let response = app
+        .send(with_session(
+            post_json("/v1/groups/join", &payload),
+            &friend,
+        ))
+        .await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    let payload = json!({ "invite_code": invite_code.to_lowercase(), "nickname": "Friend" });
+    let response = app
+        .send(with_session(
+            post_json("/v1/groups/join", &payload),
+            &friend,
+        ))
+        .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(json(response).await["id"], id);
+
+    let uri = format!("/v1/groups/{id}/leaderboard");
+    let response = app.send(with_session(get(&uri), &outsider)).await;
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    let response = app
+        .send(with_session(get("/v1/groups/nowhere/leaderboard"), &friend))
+        .await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    let response = app.send(with_session(get(&uri), &friend)).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let leaderboard = json(response).await;
+    assert_eq!(leaderboard["name"], "Friends");
+    let entries = leaderboard["entries"].as_array().unwrap();
+    let you: Vec<_> = entries
+        .iter()
+        .map(|entry| (entry["nickname"].clone(), entry["is_you"].clone()))
+        .collect();
+    assert_eq!(
+        you,
+        [
+            (json!("Friend"), json!(true)),
+            (json!("Owner"), json!(false))
+        ]
+    );
+
+    assert_eq!(score(GameState::Won { tries: 1 }), Some(6));
+    assert_eq!(score(GameState::Lost), Some(0));
+    assert_eq!(score(GameState::InProgress), None);
+}
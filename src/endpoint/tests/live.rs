@@ -0,0 +1,146 @@
+use super::{TestApp, get, post_json, solution, with_session};
+
+use crate::live::{Live, LiveCounts, LiveEvent};
+
+use std::time::Duration;
+
+use axum::{body::Body, http::StatusCode};
+use entity::{PuzzleDate, PuzzleSolution};
+use serde_json::json;
+use tokio_stream::StreamExt as _;
+
+/// A date no other test plays, as the live feed is shared by the whole process.
+const LIVE_DATE: &str = "2025-03-03";
+
+/// Reads the next event of a feed as its name and data.
+async fn next_event(
+    body: &mut (impl tokio_stream::Stream<Item = Result<axum::body::Bytes, axum::Error>> + Unpin),
+) -> (String, serde_json::Value) {
+    let bytes = body.next().await.unwrap().unwrap();
+    let text = String::from_utf8(bytes.to_vec()).unwrap();
+    let field = |name: &str| {
+        text.lines()
+            .find_map(|line| line.strip_prefix(name))
+            .unwrap()
+            .to_owned()
+    };
+    (
+        field("event: "),
+        serde_json::from_str(&field("data: ")).unwrap(),
+    )
+}
+
+#[tokio::test]
+async fn streams_live_activity() {
+    let app = TestApp::new();
+    app.repos
+        .puzzles
+        .insert_solution(
+            &PuzzleDate::try_from(LIVE_DATE).unwrap(),
+            &PuzzleSolution::try_from(solution()).unwrap(),
+            None,
+        )
+        .await
+        .unwrap();
+    let early = app.session().await;
+    let late = app.session().await;
+    let start = |session| {
+        app.send(with_session(
+            get(&format!("/v1/play/start?date={LIVE_DATE}")),
+            session,
+        ))
+    };
+
+    assert_eq!(start(&early).await.status(), StatusCode::CREATED);
+
+    let response = app.send(get(&format!("/v1/live/{LIVE_DATE}"))).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let mut body = Body::into_data_stream(response.into_body());
+    let (name, data) = next_event(&mut body).await;
+    assert_eq!(name, "counts");
+    assert_eq!(
+        data,
+        json!({ "counts": { "started": 1, "won": 0, "lost": 0 } })
+    );
+
+    assert_eq!(start(&late).await.status(), StatusCode::CREATED);
+    let (name, data) = next_event(&mut body).await;
+    assert_eq!(name, "started");
+    assert_eq!(data["counts"]["started"], 2);
+
+    let request = post_json(
+        &format!("/v1/play/submit?date={LIVE_DATE}"),
+        &json!({ "answer": solution() }),
+    );
+    assert_eq!(
+        app.send(with_session(request, &late)).await.status(),
+        StatusCode::ACCEPTED
+    );
+    let (name, data) = next_event(&mut body).await;
+    assert_eq!(name, "won");
+    // the events never tell who played
+    assert_eq!(
+        data,
+        json!({
+            "event": { "type": "won", "tries": 1 },
+            "counts": { "started": 2, "won": 1, "lost": 0 },
+        })
+    );
+
+    let response = app.send(get("/v1/live/not-a-date")).await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    // only the scheduled dates that may be played now can be followed
+    let response = app.send(get("/v1/live/2025-03-04")).await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    let response = app.send(get("/v1/live/2999-01-01")).await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn seeds_each_game_once() {
+    let live = Live::new();
+    let date = PuzzleDate::try_from(LIVE_DATE).unwrap();
+    let seeded = || async {
+        LiveCounts {
+            started: 1,
+            ..Default::default()
+        }
+    };
+
+    // a cancelled subscription leaves the date unseeded
+    let cancelled = live.subscribe(&date, async || std::future::pending::<LiveCounts>().await);
+    assert!(
+        tokio::time::timeout(Duration::from_millis(10), cancelled)
+            .await
+            .is_err()
+    );
+    assert!(!live.is_seeded(&date));
+
+    // the date is not seeded while a game is being stored, which the seeding then counts
+    let mut outbox = live.outbox(&date).await;
+    assert!(
+        tokio::time::timeout(Duration::from_millis(10), live.subscribe(&date, seeded))
+            .await
+            .is_err()
+    );
+    outbox.push(LiveEvent::Started);
+    outbox.publish();
+    let mut subscription = live.subscribe(&date, seeded).await;
+    assert_eq!(subscription.counts().started, 1);
+
+    let mut outbox = live.outbox(&date).await;
+    outbox.push(LiveEvent::Won { tries: 3 });
+    outbox.publish();
+    assert_eq!(
+        subscription.next().await.unwrap().counts,
+        LiveCounts {
+            started: 1,
+            won: 1,
+            lost: 0
+        }
+    );
+
+    // the counts are evicted with the last subscriber
+    drop(subscription);
+    assert!(!live.is_seeded(&date));
+}
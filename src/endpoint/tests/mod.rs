@@ -0,0 +1,165 @@
+//! Router-level tests for the endpoints, served from a [`MemoryStore`](crate::database::memory::MemoryStore).
+
+use crate::{WORDS, cookies, database::repo::Repos, endpoint};
+
+use std::{net::SocketAddr, sync::Once};
+
+use axum::{
+    Router,
+    body::{Body, to_bytes},
+    extract::connect_info::MockConnectInfo,
+    http::{Request, Response, header},
+};
+use entity::{PuzzleDate, PuzzleSolution};
+use serde_json::Value;
+use tower::ServiceExt as _;
+
+mod admin;
+mod cors;
+mod custom;
+mod feeds;
+mod groups;
+mod live;
+mod play;
+mod puzzles;
+mod rooms;
+mod session;
+mod today;
+mod versus;
+
+/// The origin allowed by the CORS config of the tests.
+const ALLOWED_ORIGIN: &str = "https://wordle.example";
+
+/// The link template of the feeds config of the tests.
+const LINK_TEMPLATE: &str = "https://wordle.example/play/{number}?date={date}";
+
+/// The date every test plays on.
+const DATE: &str = "2025-01-01";
+
+/// Sets up the environment shared by all tests.
+fn setup() {
+    static SETUP: Once = Once::new();
+
+    SETUP.call_once(|| {
+        let config_dir =
+            std::env::temp_dir().join(format!("api-wordle-tests-{}", std::process::id()));
+        std::fs::create_dir_all(&config_dir).unwrap();
+        std::fs::write(
+            config_dir.join("cors.toml"),
+            format!("origins = [\"{ALLOWED_ORIGIN}\"]\n"),
+        )
+        .unwrap();
+        std::fs::write(
+            config_dir.join("feeds.toml"),
+            format!("title = \"Wordle & Friends\"\nlink_template = \"{LINK_TEMPLATE}\"\n"),
+        )
+        .unwrap();
+
+        // SAFETY: this runs exactly once, before any test reads the environment.
+        unsafe {
+            std::env::set_var("CONFIG_DIR", config_dir);
+            std::env::set_var("PASETO_SYMMETRIC_KEY", "a".repeat(64));
+            std::env::set_var("SESSION_SYMMETRIC_KEY", "b".repeat(64));
+            std::env::set_var("CUSTOM_SYMMETRIC_KEY", "c".repeat(64));
+            std::env::set_var("ADDRESS_SYMMETRIC_KEY", "d".repeat(64));
+            std::env::set_var("TRUSTED_FORWARDED_HEADER", "X-Forwarded-For");
+            // a session is flagged from its third first-guess win in a row
+            std::env::set_var("CHEAT_FIRST_GUESS_SAMPLE", "3");
+            // the games of the tests finish instantly, so only time them when testing flags
+            std::env::set_var("CHEAT_FAST_SOLVE", "0");
+            std::env::set_var("CHEAT_QUICK_SOLVE", "0");
+        }
+    });
+}
+
+/// The app under test together with its store.
+struct TestApp {
+    repos: Repos,
+    router: Router,
+}
+
+impl TestApp {
+    fn new() -> Self {
+        setup();
+        let repos = Repos::memory();
+        let router = endpoint::route_from(Router::new())
+            .with_state(repos.clone())
+            .layer(MockConnectInfo(SocketAddr::from(([127, 0, 0, 1], 0))));
+        Self { repos, router }
+    }
+
+    async fn send(&self, request: Request<Body>) -> Response<Body> {
+        self.router.clone().oneshot(request).await.unwrap()
+    }
+
+    /// Requests a new session and returns its token.
+    async fn session(&self) -> String {
+        let response = self.send(get("/v1/play/session")).await;
+        session_cookie(&response).unwrap()
+    }
+
+    /// Schedules `solution` for [`DATE`].
+    async fn schedule(&self, solution: &str) {
+        self.repos
+            .puzzles
+            .insert_solution(
+                &PuzzleDate::try_from(DATE).unwrap(),
+                &PuzzleSolution::try_from(solution).unwrap(),
+                None,
+            )
+            .await
+            .unwrap();
+    }
+}
+
+fn get(uri: &str) -> Request<Body> {
+    Request::get(uri).body(Body::empty()).unwrap()
+}
+
+fn post_json(uri: &str, json: &Value) -> Request<Body> {
+    Request::post(uri)
+        .header(header::CONTENT_TYPE, "application/json")
+        .body(Body::from(json.to_string()))
+        .unwrap()
+}
+
+fn with_session(mut request: Request<Body>, session: &str) -> Request<Body> {
+    request.headers_mut().insert(
+        header::COOKIE,
+        format!("{}={session}", cookies::SESSION_TOKEN)
+            .parse()
+            .unwrap(),
+    );
+    request
+}
+
+/// Returns the value of the session cookie set by a response.
+fn session_cookie(response: &Response<Body>) -> Option<String> {
+    response
+        .headers()
+        .get_all(header::SET_COOKIE)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .find_map(|value| {
+            value
+                .split(';')
+                .next()?
+                .strip_prefix(&format!("{}=", cookies::SESSION_TOKEN))
+                .map(str::to_owned)
+        })
+}
+
+async fn json(response: Response<Body>) -> Value {
+    let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    serde_json::from_slice(&bytes).unwrap()
+}
+
+/// Returns the dictionary word the play tests solve for.
+fn solution() -> &'static str {
+    WORDS[0]
+}
+
+/// Returns dictionary words other than `solution`.
+fn misses(solution: &str) -> impl Iterator<Item = &'static str> {
+    WORDS.iter().copied().filter(move |word| *word != solution)
+}
@@ -0,0 +1,620 @@
+use super::{DATE, TestApp, get, json, misses, post_json, solution, with_session};
+
+use crate::ANSWERS;
+
+use std::sync::Arc;
+
+use axum::http::StatusCode;
+use entity::{
+    Absurdle, BoardSolutions, BoardVariant, GameState, HINT_COST, HISTORY_MAX_HINTS,
+    HISTORY_MAX_TRIES, PuzzleDate, PuzzleSolution,
+};
+use serde_json::{Value, json};
+use tokio::task::JoinSet;
+
+async fn start(app: &TestApp, session: &str, date: &str) -> (StatusCode, Value) {
+    let response = app
+        .send(with_session(
+            get(&format!("/v1/play/start?date={date}")),
+            session,
+        ))
+        .await;
+    let status = response.status();
+    let body = if status.is_success() || status == StatusCode::CONFLICT {
+        json(response).await
+    } else {
+        Value::Null
+    };
+    (status, body)
+}
+
+async fn submit(app: &TestApp, session: &str, date: &str, answer: &str) -> (StatusCode, Value) {
+    let response = app
+        .send(with_session(
+            post_json(
+                &format!("/v1/play/submit?date={date}"),
+                &json!({ "answer": answer }),
+            ),
+            session,
+        ))
+        .await;
+    let status = response.status();
+    let body = if status.is_success() || status == StatusCode::CONFLICT {
+        json(response).await
+    } else {
+        Value::Null
+    };
+    (status, body)
+}
+
+#[tokio::test]
+async fn requires_session() {
+    let app = TestApp::new();
+    app.schedule(solution()).await;
+
+    let response = app.send(get(&format!("/v1/play/start?date={DATE}"))).await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn start_then_win() {
+    let app = TestApp::new();
+    app.schedule(solution()).await;
+    let session = app.session().await;
+
+    let (status, body) = start(&app, &session, DATE).await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(body["remaining_tries"], HISTORY_MAX_TRIES);
+    assert_eq!(body["is_completed"], false);
+    assert_eq!(body["state"], json!({ "status": "in_progress" }));
+
+    let miss = misses(solution()).next().unwrap();
+    let (status, body) = submit(&app, &session, DATE, miss).await;
+    assert_eq!(status, StatusCode::ACCEPTED);
+    assert_eq!(body["is_completed"], false);
+
+    let (status, body) = submit(&app, &session, DATE, solution()).await;
+    assert_eq!(status, StatusCode::ACCEPTED);
+    assert_eq!(body["is_completed"], true);
+    assert_eq!(body["is_over"], true);
+    assert_eq!(body["state"], json!({ "status": "won", "tries": 2 }));
+    assert_eq!(body.get("solution"), None);
+    assert_eq!(
+        body["keyboard"]["fixed"],
+        json!(solution().to_ascii_uppercase().chars().collect::<Vec<_>>())
+    );
+    assert_eq!(body["remaining_tries"], HISTORY_MAX_TRIES - 2);
+    assert!(
+        body["history"][1]
+            .as_array()
+            .unwrap()
+            .iter()
+            .all(|letter| letter["matches"] == "+")
+    );
+
+    let (status, body) = start(&app, &session, DATE).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["is_completed"], true);
+    assert_eq!(body["state"], json!({ "status": "won", "tries": 2 }));
+    assert_eq!(body["history"].as_array().unwrap().len(), 2);
+
+    let (status, _) = submit(&app, &session, DATE, solution()).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn start_then_lose() {
+    let app = TestApp::new();
+    app.schedule(solution()).await;
+    let session = app.session().await;
+    start(&app, &session, DATE).await;
+
+    for (tries, miss) in misses(solution()).take(HISTORY_MAX_TRIES - 1).enumerate() {
+        let (status, body) = submit(&app, &session, DATE, miss).await;
+        assert_eq!(status, StatusCode::ACCEPTED);
+        assert_eq!(body["remaining_tries"], HISTORY_MAX_TRIES - tries - 1);
+        assert_eq!(body["is_completed"], false);
+        assert_eq!(body["is_over"], false);
+        assert_eq!(body.get("solution"), None);
+    }
+
+    let last = misses(solution()).nth(HISTORY_MAX_TRIES - 1).unwrap();
+    let (status, body) = submit(&app, &session, DATE, last).await;
+    assert_eq!(status, StatusCode::ACCEPTED);
+    assert_eq!(body["is_completed"], false);
+    assert_eq!(body["is_over"], true);
+    assert_eq!(body["state"], json!({ "status": "lost" }));
+    assert_eq!(body["solution"], solution());
+
+    let (status, body) = start(&app, &session, DATE).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["remaining_tries"], 0);
+    assert_eq!(body["is_completed"], false);
+    assert_eq!(body["is_over"], true);
+    assert_eq!(body["state"], json!({ "status": "lost" }));
+    assert_eq!(body["solution"], solution());
+    assert_eq!(body["history"].as_array().unwrap().len(), HISTORY_MAX_TRIES);
+}
+
+#[tokio::test]
+async fn rejects_submit_after_max_tries() {
+    let app = TestApp::new();
+    app.schedule(solution()).await;
+    let session = app.session().await;
+    start(&app, &session, DATE).await;
+
+    for miss in misses(solution()).take(HISTORY_MAX_TRIES) {
+        submit(&app, &session, DATE, miss).await;
+    }
+
+    let (status, body) = submit(&app, &session, DATE, solution()).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    assert_eq!(body["state"], json!({ "status": "lost" }));
+    assert_eq!(body["solution"], solution());
+
+    let (_, body) = start(&app, &session, DATE).await;
+    assert_eq!(body["history"].as_array().unwrap().len(), HISTORY_MAX_TRIES);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn accepts_one_of_concurrent_last_submits() {
+    let app = Arc::new(TestApp::new());
+    app.schedule(solution()).await;
+    let session = app.session().await;
+    start(&app, &session, DATE).await;
+
+    for miss in misses(solution()).take(HISTORY_MAX_TRIES - 1) {
+        submit(&app, &session, DATE, miss).await;
+    }
+
+    let last = misses(solution()).nth(HISTORY_MAX_TRIES - 1).unwrap();
+    let mut submits = JoinSet::new();
+    for _ in 0..8 {
+        let (app, session) = (app.clone(), session.clone());
+        submits.spawn(async move { submit(&app, &session, DATE, last).await.0 });
+    }
+    let statuses = submits.join_all().await;
+    assert_eq!(
+        statuses
+            .iter()
+            .filter(|status| **status == StatusCode::ACCEPTED)
+            .count(),
+        1
+    );
+    assert!(
+        statuses
+            .iter()
+            .all(|status| [StatusCode::ACCEPTED, StatusCode::CONFLICT].contains(status))
+    );
+
+    let (_, body) = start(&app, &session, DATE).await;
+    assert_eq!(body["history"].as_array().unwrap().len(), HISTORY_MAX_TRIES);
+}
+
+#[tokio::test]
+async fn rejects_invalid_words() {
+    let app = TestApp::new();
+    app.schedule(solution()).await;
+    let session = app.session().await;
+    start(&app, &session, DATE).await;
+
+    for answer in ["zzzzz", "cran", "cranes", "cr4ne", ""] {
+        let (status, _) = submit(&app, &session, DATE, answer).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST, "{answer:?}");
+    }
+
+    let (_, body) = start(&app, &session, DATE).await;
+    assert_eq!(body["remaining_tries"], HISTORY_MAX_TRIES);
+}
+
+#[tokio::test]
+async fn rejects_bad_dates() {
+    let app = TestApp::new();
+    let session = app.session().await;
+
+    for date in ["2025-13-01", "2025-02-30", "yesterday", "1969-12-31"] {
+        let (status, _) = start(&app, &session, date).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST, "{date}");
+
+        let (status, _) = submit(&app, &session, date, solution()).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST, "{date}");
+    }
+}
+
+#[tokio::test]
+async fn rejects_submit_before_start() {
+    let app = TestApp::new();
+    app.schedule(solution()).await;
+    let session = app.session().await;
+
+    let (status, _) = submit(&app, &session, DATE, solution()).await;
+    assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+}
+
+#[tokio::test]
+async fn uses_hints() {
+    let app = TestApp::new();
+    app.schedule(solution()).await;
+    let session = app.session().await;
+    start(&app, &session, DATE).await;
+
+    let hint = |session: String| {
+        let request = post_json(&format!("/v1/play/hint?date={DATE}"), &Value::Null);
+        app.send(with_session(request, &session))
+    };
+
+    for used in 1..=HISTORY_MAX_HINTS {
+        let response = hint(session.clone()).await;
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let body = json(response).await;
+        let index = body["hint"]["index"].as_u64().unwrap() as usize;
+        let letter = solution().as_bytes()[index].to_ascii_uppercase() as char;
+        assert_eq!(body["hint"]["letter"], letter.to_string());
+        assert_eq!(body["remaining_hints"], HISTORY_MAX_HINTS - used);
+    }
+
+    let response = hint(session.clone()).await;
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+
+    let (_, body) = start(&app, &session, DATE).await;
+    assert_eq!(body["hints"].as_array().unwrap().len(), HISTORY_MAX_HINTS);
+
+    let (_, body) = submit(&app, &session, DATE, solution()).await;
+    assert_eq!(body["hints"].as_array().unwrap().len(), HISTORY_MAX_HINTS);
+    let tries = 1 + HISTORY_MAX_HINTS * HINT_COST;
+    assert_eq!(body["state"]["tries"], tries);
+
+    let bulbs = "💡".repeat(HISTORY_MAX_HINTS);
+    let share = format!("Wordle #1292 {tries}/{HISTORY_MAX_TRIES} {bulbs}\n\n🟩🟩🟩🟩🟩");
+    assert_eq!(body["share"], share);
+}
+
+#[tokio::test]
+async fn analyzes_finished_game() {
+    let app = TestApp::new();
+    app.schedule(solution()).await;
+    let session = app.session().await;
+    start(&app, &session, DATE).await;
+
+    let analysis = || {
+        app.send(with_session(
+            get(&format!("/v1/play/analysis?date={DATE}")),
+            &session,
+        ))
+    };
+    assert_eq!(analysis().await.status(), StatusCode::CONFLICT);
+
+    let miss = misses(solution()).next().unwrap();
+    submit(&app, &session, DATE, miss).await;
+    submit(&app, &session, DATE, solution()).await;
+
+    let response = analysis().await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = json(response).await;
+    assert_eq!(body["solution"], solution());
+    let guesses = body["guesses"].as_array().unwrap();
+    assert_eq!(guesses.len(), 2);
+    assert_eq!(guesses[0]["guess"], miss.to_ascii_uppercase());
+    assert_eq!(guesses[1]["candidates_after"], 1);
+}
+
+#[tokio::test]
+async fn plays_practice_apart_from_daily() {
+    let app = TestApp::new();
+    let session = app.session().await;
+
+    let practice = || {
+        app.send(with_session(
+            post_json("/v1/play/practice/start", &Value::Null),
+            &session,
+        ))
+    };
+    let response = practice().await;
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let id = json(response).await["id"].as_str().unwrap().to_owned();
+    let other = json(practice().await).await["id"]
+        .as_str()
+        .unwrap()
+        .to_owned();
+    assert_ne!(id, other);
+
+    let submit = |session: String, id: String, answer: String| {
+        app.send(with_session(
+            post_json(
+                &format!("/v1/play/practice/{id}/submit"),
+                &json!({ "answer": answer }),
+            ),
+            &session,
+        ))
+    };
+    let solution = app
+        .repos
+        .practices
+        .get_practice(&id, &session)
+        .await
+        .unwrap()
+        .solution;
+    assert!(ANSWERS.contains(&solution));
+    let solution = solution.to_string();
+    let miss = misses(&solution).next().unwrap().to_owned();
+
+    let response = submit(session.clone(), "missing".to_owned(), miss.clone()).await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    let stranger = app.session().await;
+    let response = submit(stranger, id.clone(), miss.clone()).await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    let response = submit(session.clone(), id.clone(), miss).await;
+    assert_eq!(response.status(), StatusCode::ACCEPTED);
+    let response = submit(session.clone(), id.clone(), solution.clone()).await;
+    assert_eq!(response.status(), StatusCode::ACCEPTED);
+    assert_eq!(
+        json(response).await["state"],
+        json!({ "status": "won", "tries": 2 })
+    );
+
+    let response = submit(session.clone(), id, solution).await;
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+    assert!(
+        app.repos
+            .histories
+            .get_history(&DATE.try_into().unwrap(), &session)
+            .await
+            .is_none()
+    );
+}
+
+#[tokio::test]
+async fn plays_boards() {
+    let app = TestApp::new();
+    let session = app.session().await;
+    let words: Vec<&str> = misses(solution()).take(2).collect();
+    app.repos
+        .boards
+        .insert_board_solutions(
+            &DATE.try_into().unwrap(),
+            BoardVariant::Dordle,
+            &BoardSolutions(words.iter().map(|w| (*w).try_into().unwrap()).collect()),
+        )
+        .await
+        .unwrap();
+
+    let response = app
+        .send(with_session(
+            get(&format!(
+                "/v1/play/boards/start?date={DATE}&variant=quordle"
+            )),
+            &session,
+        ))
+        .await;
+    assert_eq!(response.status(), StatusCode::CREATED);
+    assert_eq!(json(response).await["boards"].as_array().unwrap().len(), 4);
+
+    let response = app
+        .send(with_session(
+            get(&format!("/v1/play/boards/start?date={DATE}&variant=dordle")),
+            &session,
+        ))
+        .await;
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let submit = |answer: &str| {
+        app.send(with_session(
+            post_json(
+                &format!("/v1/play/boards/submit?date={DATE}&variant=dordle"),
+                &json!({ "answer": answer }),
+            ),
+            &session,
+        ))
+    };
+    let response = submit(words[1]).await;
+    assert_eq!(response.status(), StatusCode::ACCEPTED);
+    let body = json(response).await;
+    assert_eq!(
+        body["remaining_tries"],
+        BoardVariant::Dordle.max_tries() - 1
+    );
+    assert_eq!(body["boards"][1]["solved_in"], 1);
+    assert!(body.get("share").is_none());
+
+    let response = submit(words[0]).await;
+    assert_eq!(response.status(), StatusCode::ACCEPTED);
+    let body = json(response).await;
+    assert_eq!(body["state"], json!({ "status": "won", "tries": 2 }));
+    assert_eq!(body["boards"][1]["history"].as_array().unwrap().len(), 1);
+    assert!(
+        body["share"]
+            .as_str()
+            .unwrap()
+            .starts_with("Dordle #1292 2/7\n\n")
+    );
+
+    assert_eq!(submit(words[0]).await.status(), StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn plays_absurdle() {
+    let app = TestApp::new();
+    let session = app.session().await;
+
+    let response = app
+        .send(with_session(
+            post_json("/v1/play/absurdle/start", &Value::Null),
+            &session,
+        ))
+        .await;
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let body = json(response).await;
+    assert_eq!(body["remaining_candidates"], ANSWERS.len());
+    let id = body["id"].as_str().unwrap().to_owned();
+
+    let submit = |answer: String| {
+        app.send(with_session(
+            post_json(
+                &format!("/v1/play/absurdle/{id}/submit"),
+                &json!({ "answer": answer }),
+            ),
+            &session,
+        ))
+    };
+
+    let mut remaining = ANSWERS.len();
+    for tries in 1..=ANSWERS.len() {
+        let game = app
+            .repos
+            .absurdles
+            .get_absurdle(&id, &session)
+            .await
+            .unwrap();
+        let candidates = Absurdle::candidates(&ANSWERS, &game.submit_history.unwrap_or_default());
+        assert_eq!(candidates.len(), remaining);
+
+        let response = submit(candidates[0].to_string()).await;
+        assert_eq!(response.status(), StatusCode::ACCEPTED);
+        let body = json(response).await;
+        remaining = body["remaining_candidates"].as_u64().unwrap() as usize;
+        assert!(remaining >= 1);
+        assert_eq!(body["history"].as_array().unwrap().len(), tries);
+        if body["state"]["status"] == "won" {
+            assert_eq!(remaining, 1);
+            break;
+        }
+    }
+
+    let response = submit(ANSWERS[0].to_string()).await;
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn times_games_on_the_server() {
+    let app = TestApp::new();
+    app.schedule(solution()).await;
+    let session = app.session().await;
+
+    let (status, body) = start(&app, &session, DATE).await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert!(body["timing"]["started_at"].is_string());
+    assert!(body["timing"].get("deadline").is_none());
+
+    let miss = misses(solution()).next().unwrap();
+    let (_, body) = submit(&app, &session, DATE, miss).await;
+    assert_eq!(body["timing"]["submitted_at"].as_array().unwrap().len(), 1);
+    assert!(body["timing"].get("completed_in_millis").is_none());
+    let (_, body) = submit(&app, &session, DATE, solution()).await;
+    assert_eq!(body["timing"]["submitted_at"].as_array().unwrap().len(), 2);
+    assert!(body["timing"]["completed_in_millis"].as_i64().unwrap() >= 0);
+
+    let racer = app.session().await;
+    let response = app
+        .send(with_session(
+            get(&format!("/v1/play/start?date={DATE}&mode=time_attack")),
+            &racer,
+        ))
+        .await;
+    assert_eq!(response.status(), StatusCode::CREATED);
+    assert!(json(response).await["timing"]["deadline"].is_string());
+
+    let date = DATE.try_into().unwrap();
+    let mut history = app
+        .repos
+        .histories
+        .get_history(&date, &racer)
+        .await
+        .unwrap();
+    let timing = history.timing.as_mut().unwrap();
+    timing.deadline = Some(timing.started_at);
+    app.repos.histories.update_history(&history).await.unwrap();
+
+    // the missed deadline is lost wherever the game is read
+    let (_, body) = start(&app, &racer, DATE).await;
+    assert_eq!(body["state"], json!({ "status": "lost" }));
+    assert_eq!(body["solution"], solution());
+
+    let request = post_json(&format!("/v1/play/hint?date={DATE}"), &Value::Null);
+    let response = app.send(with_session(request, &racer)).await;
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+    let history = app
+        .repos
+        .histories
+        .get_history(&date, &racer)
+        .await
+        .unwrap();
+    assert_eq!(history.game_state, Some(GameState::Lost));
+    assert!(history.hints.is_none());
+    assert_eq!(app.repos.results.get_result_counts(&date).await.len(), 2);
+
+    let (status, body) = submit(&app, &racer, DATE, solution()).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    assert_eq!(body["state"], json!({ "status": "lost" }));
+    assert_eq!(body["history"], json!([]));
+    assert!(body["timing"]["completed_in_millis"].is_i64());
+}
+
+#[tokio::test]
+async fn browses_archive() {
+    let app = TestApp::new();
+    let session = app.session().await;
+    for date in ["2025-01-30", "2025-01-31", "2025-02-01", "2999-01-01"] {
+        app.repos
+            .puzzles
+            .insert_solution(
+                &PuzzleDate::try_from(date).unwrap(),
+                &PuzzleSolution::try_from(solution()).unwrap(),
+                None,
+            )
+            .await
+            .unwrap();
+    }
+    start(&app, &session, "2025-01-31").await;
+    submit(&app, &session, "2025-01-31", solution()).await;
+    start(&app, &session, "2025-02-01").await;
+
+    let archive = |query: &str| {
+        let request = with_session(get(&format!("/v1/play/archive?{query}")), &session);
+        app.send(request)
+    };
+    assert_eq!(
+        archive("per_page=0").await.status(),
+        StatusCode::BAD_REQUEST
+    );
+    let body = json(archive("per_page=3").await).await;
+    assert_eq!(body["total"], 4);
+    assert_eq!(body["pages"], 2);
+    assert_eq!(
+        body["months"],
+        json!([
+            {
+                "month": "2999-01",
+                "dates": [{
+                    "date": "2999-01-01",
+                    "number": 357038,
+                    "state": { "status": "not_started" },
+                    "playable": false,
+                }],
+            },
+            {
+                "month": "2025-02",
+                "dates": [{
+                    "date": "2025-02-01",
+                    "number": 1323,
+                    "state": { "status": "in_progress" },
+                    "playable": true,
+                }],
+            },
+            {
+                "month": "2025-01",
+                "dates": [{
+                    "date": "2025-01-31",
+                    "number": 1322,
+                    "state": { "status": "won", "tries": 1 },
+                    "playable": true,
+                }],
+            },
+        ])
+    );
+    let body = json(archive("page=2&per_page=3").await).await;
+    assert_eq!(body["months"][0]["dates"][0]["date"], "2025-01-30");
+    assert_eq!(body["months"][0]["dates"][0]["number"], 1321);
+}
@@ -0,0 +1,257 @@
+use super::{DATE, TestApp, get, json, misses, post_json, solution, with_session};
+
+use crate::{difficulty, middleware::auth::generate_paseto_token};
+
+use std::time::Duration;
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode, header},
+};
+use chrono::{FixedOffset, Utc};
+use entity::{PuzzleDate, PuzzleSolution};
+use serde_json::{Value, json};
+
+fn post_puzzle(token: Option<&str>, solution: &str) -> Request<Body> {
+    let mut request = post_json("/v1", &json!({ "date": DATE, "solution": solution }));
+    if let Some(token) = token {
+        request.headers_mut().insert(
+            header::AUTHORIZATION,
+            format!("Bearer {token}").parse().unwrap(),
+        );
+    }
+    request
+}
+
+#[tokio::test]
+async fn rejects_post_without_token() {
+    let app = TestApp::new();
+
+    let response = app.send(post_puzzle(None, "crane")).await;
+    assert!(response.status().is_client_error());
+    assert_eq!(app.repos.puzzles.get_dates().await, Vec::new());
+}
+
+#[tokio::test]
+async fn rejects_post_with_invalid_token() {
+    let app = TestApp::new();
+
+    let response = app
+        .send(post_puzzle(Some("v4.local.forged"), "crane"))
+        .await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    assert_eq!(app.repos.puzzles.get_dates().await, Vec::new());
+}
+
+#[tokio::test]
+async fn posts_with_token() {
+    let app = TestApp::new();
+    let token = generate_paseto_token().await;
+
+    let response = app.send(post_puzzle(Some(&token), "crane")).await;
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let response = app.send(post_puzzle(Some(&token), "slate")).await;
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+
+    let response = app.send(get(&format!("/v1?date={DATE}"))).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let mut body = json(response).await;
+    assert_eq!(body["date"], DATE);
+    assert_eq!(body["solution"], "crane");
+
+    // the difficulty is rated in the background
+    for _ in 0..100 {
+        if body["difficulty"].is_f64() {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        body = json(app.send(get(&format!("/v1?date={DATE}"))).await).await;
+    }
+    assert!(body["difficulty"].is_f64());
+
+    // puzzles are numbered in days from the default epoch
+    assert_eq!(body["number"], 1292);
+    let response = app.send(get("/v1?number=1292")).await;
+    assert_eq!(json(response).await["date"], DATE);
+    let response = app.send(get(&format!("/v1?date={DATE}&number=1292"))).await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let response = app.send(get("/v1/puzzles/1292/results")).await;
+    assert_eq!(json(response).await["date"], DATE);
+}
+
+#[tokio::test]
+async fn rejects_bad_dates() {
+    let app = TestApp::new();
+
+    let response = app.send(get("/v1?date=2025-00-01")).await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let response = app.send(get(&format!("/v1?date={DATE}"))).await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn lists_dates() {
+    let app = TestApp::new();
+    app.schedule("crane").await;
+
+    let response = app.send(get("/v1/dates")).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        json(response).await,
+        json!({
+            "count": 1,
+            "dates": [DATE],
+            "items": [{ "date": DATE, "number": 1292 }],
+        })
+    );
+}
+
+#[tokio::test]
+async fn blends_difficulty_after_the_day() {
+    let app = TestApp::new();
+    app.schedule(solution()).await;
+    assert_eq!(difficulty::blend_ended(&app.repos).await, 0);
+
+    let session = app.session().await;
+    app.send(with_session(
+        get(&format!("/v1/play/start?date={DATE}")),
+        &session,
+    ))
+    .await;
+    app.send(with_session(
+        post_json(
+            &format!("/v1/play/submit?date={DATE}"),
+            &json!({ "answer": solution() }),
+        ),
+        &session,
+    ))
+    .await;
+
+    assert_eq!(difficulty::blend_ended(&app.repos).await, 1);
+    assert_eq!(difficulty::blend_ended(&app.repos).await, 0);
+
+    let response = app.send(get("/v1")).await;
+    let body = json(response).await;
+    let blended = body["puzzles"][0]["difficulty"].as_f64().unwrap();
+    let predicted = difficulty::rate(&solution().try_into().unwrap())
+        .await
+        .unwrap();
+    assert!(1.0 < blended && blended < predicted);
+}
+
+#[tokio::test]
+async fn aggregates_results() {
+    let app = TestApp::new();
+    app.schedule(solution()).await;
+    let miss = misses(solution()).next().unwrap();
+    let play = async |answers: &[&str]| {
+        let session = app.session().await;
+        app.send(with_session(
+            get(&format!("/v1/play/start?date={DATE}")),
+            &session,
+        ))
+        .await;
+        let mut last = Value::Null;
+        for answer in answers {
+            let request = post_json(
+                &format!("/v1/play/submit?date={DATE}"),
+                &json!({ "answer": answer }),
+            );
+            last = json(app.send(with_session(request, &session)).await).await;
+        }
+        (session, last)
+    };
+
+    let (first, last) = play(&[solution()]).await;
+    assert!(last.get("beaten_percent").is_none());
+    let (second, last) = play(&[miss, solution()]).await;
+    assert_eq!(last["beaten_percent"], 0);
+    let (_, last) = play(&[miss; 6]).await;
+    assert_eq!(last["state"], json!({ "status": "lost" }));
+    assert_eq!(last["beaten_percent"], 0);
+
+    let results = |session: &str| {
+        app.send(with_session(
+            get(&format!("/v1/puzzles/{DATE}/results")),
+            session,
+        ))
+    };
+    let body = json(results(&first).await).await;
+    assert_eq!(body["played"], 3);
+    assert_eq!(body["won"], 2);
+    assert_eq!(body["lost"], 1);
+    assert_eq!(body["distribution"], json!([1, 1, 0, 0, 0, 0]));
+    assert_eq!(body["average_guesses"], 1.5);
+    assert_eq!(body["first_guesses"][0]["word"], miss.to_uppercase());
+    assert_eq!(body["first_guesses"][0]["count"], 2);
+    assert_eq!(body["solution"], solution());
+    assert_eq!(body["beaten_percent"], 100);
+    assert_eq!(json(results(&second).await).await["beaten_percent"], 50);
+
+    // the solution is hidden until the day is over
+    app.repos
+        .puzzles
+        .insert_solution(
+            &PuzzleDate::try_from("2999-01-01").unwrap(),
+            &PuzzleSolution::try_from(solution()).unwrap(),
+            None,
+        )
+        .await
+        .unwrap();
+    let body = json(app.send(get("/v1/puzzles/2999-01-01/results")).await).await;
+    assert_eq!(body["played"], 0);
+    assert!(body.get("solution").is_none());
+
+    // ... in every timezone
+    let latest = PuzzleDate::at(&Utc::now(), &FixedOffset::west_opt(12 * 3600).unwrap());
+    let previous = PuzzleDate::new(latest.0.pred_opt().unwrap());
+    for date in [&latest, &previous] {
+        app.repos
+            .puzzles
+            .insert_solution(date, &PuzzleSolution::try_from(solution()).unwrap(), None)
+            .await
+            .unwrap();
+    }
+    let body = json(
+        app.send(get(&format!("/v1/puzzles/{latest}/results")))
+            .await,
+    )
+    .await;
+    assert!(body.get("solution").is_none());
+    let body = json(
+        app.send(get(&format!("/v1/puzzles/{previous}/results")))
+            .await,
+    )
+    .await;
+    assert_eq!(body["solution"], solution());
+    let response = app.send(get("/v1/puzzles/2000-01-01/results")).await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn charges_hints_in_results() {
+    let app = TestApp::new();
+    app.schedule(solution()).await;
+    let session = app.session().await;
+    app.send(with_session(
+        get(&format!("/v1/play/start?date={DATE}")),
+        &session,
+    ))
+    .await;
+    let request = post_json(&format!("/v1/play/hint?date={DATE}"), &Value::Null);
+    app.send(with_session(request, &session)).await;
+    let request = post_json(
+        &format!("/v1/play/submit?date={DATE}"),
+        &json!({ "answer": solution() }),
+    );
+    let body = json(app.send(with_session(request, &session)).await).await;
+    assert_eq!(body["state"]["tries"], 2);
+
+    // the hinted win lands in the bucket of its charged tries
+    let request = with_session(get(&format!("/v1/puzzles/{DATE}/results")), &session);
+    let body = json(app.send(request).await).await;
+    assert_eq!(body["distribution"], json!([0, 1, 0, 0, 0, 0]));
+    assert_eq!(body["average_guesses"], 2.0);
+}
@@ -0,0 +1,113 @@
+use super::{TestApp, json, misses, post_json, solution, with_session};
+
+use crate::rooms::{ROOMS, RoomError, RoomEvent};
+
+use axum::http::StatusCode;
+use entity::{GameState, PuzzleSolution};
+use serde_json::json;
+
+#[tokio::test]
+async fn races_in_room() {
+    let app = TestApp::new();
+    let host = app.session().await;
+    let guest = app.session().await;
+    let create = |payload| app.send(with_session(post_json("/v1/rooms", &payload), &host));
+
+    let response = create(json!({ "solution": "zzzzz" })).await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let response = create(json!({})).await;
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let response = create(json!({ "solution": solution() })).await;
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let code = json(response).await["code"].as_str().unwrap().to_owned();
+
+    let (host_id, mut events) = ROOMS.join(&code, &host, "host").unwrap();
+    let (guest_id, _) = ROOMS.join(&code, &guest, "guest").unwrap();
+    assert!(ROOMS.join("nowhere", &guest, "guest").is_none());
+
+    let miss = PuzzleSolution::try_from(misses(solution()).next().unwrap()).unwrap();
+    let answer = PuzzleSolution::try_from(solution()).unwrap();
+    let (_, state) = ROOMS.submit(&code, &guest, &miss).unwrap();
+    assert_eq!(state, GameState::InProgress);
+    let (_, state) = ROOMS.submit(&code, &guest, &answer).unwrap();
+    assert_eq!(state, GameState::Won { tries: 2 });
+    ROOMS.submit(&code, &host, &answer).unwrap();
+    assert!(matches!(
+        ROOMS.submit(&code, &guest, &answer),
+        Err(RoomError::GameOver(GameState::Won { .. }))
+    ));
+
+    let mut received = Vec::new();
+    while let Ok(event) = events.try_recv() {
+        received.push(serde_json::to_value(event).unwrap());
+    }
+    let places: Vec<_> = received
+        .iter()
+        .filter(|event| event["type"] == "finished")
+        .map(|event| (event["player"].clone(), event["place"].clone()))
+        .collect();
+    assert_eq!(
+        places,
+        [(json!(guest_id), json!(1)), (json!(host_id), json!(2))]
+    );
+    // the tints never spoil the letters
+    for event in received.iter().filter(|event| event["type"] == "tinted") {
+        assert!(event["pattern"].is_array());
+        assert!(!event.to_string().contains(&solution().to_uppercase()));
+    }
+
+    ROOMS.leave(&code, &guest);
+    assert_eq!(
+        events.try_recv().unwrap(),
+        RoomEvent::Left { player: guest_id }
+    );
+    ROOMS.leave(&code, &host);
+    assert!(!ROOMS.contains(&code));
+}
+
+#[tokio::test]
+async fn places_winners_and_keeps_players_in_room() {
+    let code = ROOMS.create(PuzzleSolution::try_from(solution()).unwrap());
+    let (loser_id, mut events) = ROOMS.join(&code, "loser", "loser").unwrap();
+    let (winner_id, _) = ROOMS.join(&code, "winner", "winner").unwrap();
+    ROOMS.join(&code, "watcher", "watcher").unwrap();
+
+    let miss = PuzzleSolution::try_from(misses(solution()).next().unwrap()).unwrap();
+    let answer = PuzzleSolution::try_from(solution()).unwrap();
+    ROOMS.submit(&code, "loser", &miss).unwrap();
+
+    // a player who has started resumes their history on reconnecting
+    ROOMS.leave(&code, "loser");
+    assert_eq!(ROOMS.join(&code, "loser", "loser").unwrap().0, loser_id);
+    let mut state = GameState::InProgress;
+    while !state.is_over() {
+        (_, state) = ROOMS.submit(&code, "loser", &miss).unwrap();
+    }
+    assert_eq!(state, GameState::Lost);
+    ROOMS.submit(&code, "winner", &answer).unwrap();
+
+    let mut places = Vec::new();
+    while let Ok(event) = events.try_recv() {
+        if let RoomEvent::Finished { player, place, .. } = event {
+            places.push((player, place));
+        }
+    }
+    assert_eq!(places, [(loser_id, None), (winner_id, Some(1))]);
+
+    // a player who has not started is forgotten on leaving
+    ROOMS.leave(&code, "watcher");
+    assert!(matches!(
+        ROOMS.submit(&code, "watcher", &answer),
+        Err(RoomError::NotFound)
+    ));
+
+    // the room stays open while a player is connected, even after finishing
+    ROOMS.leave(&code, "loser");
+    assert!(ROOMS.contains(&code));
+    assert!(matches!(
+        ROOMS.submit(&code, "loser", &answer),
+        Err(RoomError::GameOver(GameState::Lost))
+    ));
+    ROOMS.leave(&code, "winner");
+    assert!(!ROOMS.contains(&code));
+}
@@ -0,0 +1,44 @@
+use super::{TestApp, get, session_cookie, with_session};
+
+use axum::http::{StatusCode, header};
+
+#[tokio::test]
+async fn issues_session() {
+    let app = TestApp::new();
+    let response = app.send(get("/v1/play/session")).await;
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let cookie = response
+        .headers()
+        .get(header::SET_COOKIE)
+        .unwrap()
+        .to_str()
+        .unwrap();
+    assert!(cookie.contains("HttpOnly"), "{cookie}");
+    assert!(cookie.contains("SameSite=None"), "{cookie}");
+    assert!(cookie.contains("Secure"), "{cookie}");
+    assert!(session_cookie(&response).is_some_and(|token| !token.is_empty()));
+}
+
+#[tokio::test]
+async fn refreshes_session() {
+    let app = TestApp::new();
+    let session = app.session().await;
+
+    let response = app
+        .send(with_session(get("/v1/play/session"), &session))
+        .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(session_cookie(&response), Some(session));
+}
+
+#[tokio::test]
+async fn replaces_invalid_session() {
+    let app = TestApp::new();
+
+    let response = app
+        .send(with_session(get("/v1/play/session"), "forged"))
+        .await;
+    assert_eq!(response.status(), StatusCode::CREATED);
+    assert_ne!(session_cookie(&response).as_deref(), Some("forged"));
+}
@@ -0,0 +1,71 @@
+use super::{TestApp, get, json, solution, with_session};
+
+use axum::http::StatusCode;
+use chrono::Utc;
+use chrono_tz::Tz;
+use entity::{PuzzleDate, PuzzleSolution};
+
+#[tokio::test]
+async fn resolves_today_in_timezone() {
+    let app = TestApp::new();
+
+    let response = app.send(get("/v1/today")).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = json(response).await;
+    assert_eq!(body["timezone"], "UTC");
+    assert_eq!(body["utc_offset"], 0);
+
+    for tz in [Tz::Pacific__Kiritimati, Tz::Pacific__Pago_Pago] {
+        let expected = PuzzleDate::at(&Utc::now(), &tz).to_string();
+        let response = app.send(get(&format!("/v1/today?tz={}", tz.name()))).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = json(response).await;
+        assert_eq!(body["timezone"], tz.name());
+        // the date may have rolled over between the request and the expectation
+        if body["date"] == expected {
+            let seconds = body["seconds_until_rollover"].as_i64().unwrap();
+            assert!((0..=24 * 60 * 60).contains(&seconds));
+        }
+    }
+
+    let mut request = get("/v1/today");
+    request
+        .headers_mut()
+        .insert("x-timezone", "Asia/Tokyo".parse().unwrap());
+    let body = json(app.send(request).await).await;
+    assert_eq!(body["timezone"], "Asia/Tokyo");
+    assert_eq!(body["utc_offset"], 9 * 60 * 60);
+
+    let response = app.send(get("/v1/today?tz=Mars/Olympus_Mons")).await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn plays_today_in_kiritimati() {
+    let app = TestApp::new();
+    let session = app.session().await;
+    // the date of the earliest timezone, which is a day ahead of UTC from 10:00 UTC
+    let date = PuzzleDate::at(&Utc::now(), &Tz::Pacific__Kiritimati);
+    app.repos
+        .puzzles
+        .insert_solution(&date, &PuzzleSolution::try_from(solution()).unwrap(), None)
+        .await
+        .unwrap();
+
+    let response = app
+        .send(with_session(
+            get(&format!("/v1/play/start?date={date}")),
+            &session,
+        ))
+        .await;
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let request = with_session(get("/v1/play/archive"), &session);
+    let body = json(app.send(request).await).await;
+    let dates = body["months"][0]["dates"].as_array().unwrap();
+    let archived = dates
+        .iter()
+        .find(|archived| archived["date"] == date.to_string())
+        .unwrap();
+    assert_eq!(archived["playable"], true);
+}
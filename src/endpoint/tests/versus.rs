@@ -0,0 +1,113 @@
+use super::{TestApp, get, json, misses, post_json, solution, with_session};
+
+use axum::http::StatusCode;
+use chrono::TimeDelta;
+use serde_json::{Value, json};
+
+/// Queues a session for a versus match and returns the status code and the match.
+async fn queue(app: &TestApp, session: &str) -> (StatusCode, Value) {
+    let request = with_session(post_json("/v1/play/versus/queue", &json!({})), session);
+    let response = app.send(request).await;
+    (response.status(), json(response).await)
+}
+
+async fn submit(app: &TestApp, session: &str, id: &str, answer: &str) -> (StatusCode, Value) {
+    let uri = format!("/v1/play/versus/{id}/submit");
+    let request = with_session(post_json(&uri, &json!({ "answer": answer })), session);
+    let response = app.send(request).await;
+    let status = response.status();
+    match status {
+        StatusCode::ACCEPTED | StatusCode::CONFLICT => (status, json(response).await),
+        _ => (status, Value::Null),
+    }
+}
+
+#[tokio::test]
+async fn plays_versus_on_alternating_turns() {
+    let app = TestApp::new();
+    let host = app.session().await;
+    let guest = app.session().await;
+
+    let (status, waiting) = queue(&app, &host).await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(waiting["status"], "waiting");
+    let id = waiting["id"].as_str().unwrap().to_owned();
+    assert_eq!(queue(&app, &host).await.1["id"], id);
+    assert_eq!(
+        submit(&app, &host, &id, solution()).await.0,
+        StatusCode::FORBIDDEN
+    );
+
+    let (status, joined) = queue(&app, &guest).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(joined["id"], id);
+    assert_eq!(joined["status"], "opponent_turn");
+    assert!(joined["turn_deadline"].is_string());
+
+    let game = app.repos.versus.get_versus(&id, &host).await.unwrap();
+    let solution = game.solution.to_string();
+    let miss = misses(&solution).next().unwrap();
+
+    assert_eq!(
+        submit(&app, &guest, &id, miss).await.0,
+        StatusCode::FORBIDDEN
+    );
+    let (status, game) = submit(&app, &host, &id, miss).await;
+    assert_eq!(status, StatusCode::ACCEPTED);
+    assert_eq!(game["status"], "opponent_turn");
+    assert!(game.get("solution").is_none());
+    assert_eq!(
+        submit(&app, &host, &id, miss).await.0,
+        StatusCode::FORBIDDEN
+    );
+
+    let (status, game) = submit(&app, &guest, &id, &solution).await;
+    assert_eq!(status, StatusCode::ACCEPTED);
+    assert_eq!(game["status"], "won");
+    assert_eq!(game["history"].as_array().unwrap().len(), 2);
+
+    let response = app
+        .send(with_session(get(&format!("/v1/play/versus/{id}")), &host))
+        .await;
+    let game = json(response).await;
+    assert_eq!(game["status"], "lost");
+    assert_eq!(game["solution"], solution);
+    let (status, game) = submit(&app, &host, &id, miss).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    assert_eq!(game["status"], "lost");
+
+    // only the players see the match
+    let outsider = app.session().await;
+    let response = app
+        .send(with_session(
+            get(&format!("/v1/play/versus/{id}")),
+            &outsider,
+        ))
+        .await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn forfeits_idle_versus_turns() {
+    let app = TestApp::new();
+    let host = app.session().await;
+    let guest = app.session().await;
+
+    let id = queue(&app, &host).await.1["id"]
+        .as_str()
+        .unwrap()
+        .to_owned();
+    queue(&app, &guest).await;
+
+    let mut game = app.repos.versus.get_versus(&id, &host).await.unwrap();
+    game.turn_started_at -= TimeDelta::hours(1);
+    app.repos.versus.update_versus(&game).await.unwrap();
+
+    let (status, game) = submit(&app, &host, &id, solution()).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    assert_eq!(game["status"], "lost");
+    let response = app
+        .send(with_session(get(&format!("/v1/play/versus/{id}")), &guest))
+        .await;
+    assert_eq!(json(response).await["status"], "won");
+}
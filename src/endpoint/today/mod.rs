@@ -0,0 +1,111 @@
+//! Endpoint `/today`.
+
+use crate::{
+    env::{TIMEZONE_MAX_OFFSET, TIMEZONE_MIN_OFFSET},
+    puzzle_number,
+};
+
+use axum::{
+    Json,
+    extract::Query,
+    http::{HeaderMap, StatusCode},
+    response::IntoResponse,
+};
+use chrono::{DateTime, Offset as _, Utc};
+use chrono_tz::Tz;
+use entity::PuzzleDate;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// The header carrying the IANA timezone of the client.
+pub const TIMEZONE_HEADER: &str = "x-timezone";
+
+/// The parameters for the get request.
+#[derive(Debug, Clone, Deserialize, utoipa::IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct GetParams {
+    /// The IANA timezone of the client, such as `Asia/Tokyo`, which takes precedence over the
+    /// `X-Timezone` header. Defaults to UTC if neither is given.
+    pub tz: Option<String>,
+}
+
+/// The response for the get request.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[schema(as = TodayResponse)]
+pub struct GetResponse {
+    /// The date of today's puzzle in the timezone.
+    pub date: PuzzleDate,
+    /// The sequential number of today's puzzle, if numbered.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub number: Option<u64>,
+    /// The IANA timezone the date is resolved in.
+    pub timezone: String,
+    /// The current offset of the timezone from UTC in seconds.
+    pub utc_offset: i32,
+    /// The time the puzzle of the next date starts in the timezone.
+    pub next_rollover_at: DateTime<Utc>,
+    /// The number of seconds until the next rollover.
+    pub seconds_until_rollover: i64,
+}
+
+/// The client resolves today's puzzle in its timezone.
+///
+/// Timezones whose current offset from UTC falls outside [`TIMEZONE_MIN_OFFSET`] and
+/// [`TIMEZONE_MAX_OFFSET`] are rejected.
+#[utoipa::path(
+    get,
+    path = "/today",
+    operation_id = "get_today",
+    tag = "puzzles",
+    params(
+        GetParams,
+        ("X-Timezone" = Option<String>, Header, description = "The IANA timezone of the client, unless given by `tz`."),
+    ),
+    responses(
+        (status = OK, description = "Today's puzzle in the timezone.", body = GetResponse),
+        (status = BAD_REQUEST, description = "The timezone is unknown or its offset is outside the server policy.", body = String),
+    ),
+)]
+pub async fn get(headers: HeaderMap, Query(params): Query<GetParams>) -> impl IntoResponse {
+    let name = params
+        .tz
+        .or_else(|| {
+            headers
+                .get(TIMEZONE_HEADER)
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_owned)
+        })
+        .unwrap_or_else(|| Tz::UTC.name().to_owned());
+    let Ok(tz) = name.parse::<Tz>() else {
+        return (StatusCode::BAD_REQUEST, format!("unknown timezone {name}")).into_response();
+    };
+
+    let now = Utc::now();
+    let utc_offset = now.with_timezone(&tz).offset().fix().local_minus_utc();
+    if !(*TIMEZONE_MIN_OFFSET * 3600..=*TIMEZONE_MAX_OFFSET * 3600).contains(&utc_offset) {
+        return (
+            StatusCode::BAD_REQUEST,
+            format!("the offset of timezone {name} is outside the allowed range"),
+        )
+            .into_response();
+    }
+
+    let date = PuzzleDate::at(&now, &tz);
+    let Some(next_rollover_at) = date.next_rollover(&tz) else {
+        return (
+            StatusCode::BAD_REQUEST,
+            format!("no rollover after {date} in timezone {name}"),
+        )
+            .into_response();
+    };
+
+    let response = GetResponse {
+        number: puzzle_number::number_of(&date),
+        date,
+        timezone: tz.name().to_owned(),
+        utc_offset,
+        next_rollover_at,
+        seconds_until_rollover: (next_rollover_at - now).num_seconds(),
+    };
+    (StatusCode::OK, Json(response)).into_response()
+}
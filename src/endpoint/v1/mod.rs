@@ -0,0 +1,181 @@
+//! The endpoints of API version 1, mounted under `/v1`.
+//!
+//! See: [`ApiVersion::V1`](crate::middleware::version::ApiVersion::V1)
+
+use crate::{
+    database::repo::Repos,
+    endpoint::{admin, custom, dates, groups, live, play, puzzles, rooms, root, today, validate},
+    middleware::{auth::authorize_paseto_token, session::validate_session_token},
+};
+
+use axum::{
+    Router,
+    middleware::from_fn,
+    routing::{get, post},
+};
+use utoipa::OpenApi;
+
+/// The OpenAPI document of API version 1.
+#[derive(Debug, OpenApi)]
+#[openapi(paths(
+    root::get,
+    root::post,
+    admin::flags::get,
+    custom::post,
+    custom::results::get,
+    custom::start::get,
+    custom::submit::post,
+    dates::get,
+    groups::post,
+    groups::join::post,
+    groups::leaderboard::get,
+    live::get,
+    puzzles::results::get,
+    validate::get,
+    play::absurdle::start::post,
+    play::archive::get,
+    play::absurdle::submit::post,
+    play::analysis::get,
+    play::boards::start::get,
+    play::boards::submit::post,
+    play::hint::post,
+    play::practice::start::post,
+    play::practice::submit::post,
+    play::session::get,
+    play::start::get,
+    play::submit::post,
+    play::versus::get,
+    play::versus::queue::post,
+    play::versus::submit::post,
+    rooms::post,
+    rooms::ws::get,
+    today::get,
+))]
+pub struct ApiDoc;
+
+/// Creates a [`Router`] with the endpoints of API version 1, relative to its prefix.
+pub fn router() -> Router<Repos> {
+    let mut app = Router::new();
+    app = route_gets(app);
+    app = route_posts(app);
+    app
+}
+
+fn route_gets(app: Router<Repos>) -> Router<Repos> {
+    app.route("/", get(root::get))
+        .route("/dates", get(dates::get))
+        .route(
+            "/admin/flags",
+            get(admin::flags::get).route_layer(from_fn(authorize_paseto_token)),
+        )
+        .route("/live/{date}", get(live::get))
+        .route(
+            "/puzzles/{date}/results",
+            get(puzzles::results::get).route_layer(from_fn(validate_session_token)),
+        )
+        .route("/today", get(today::get))
+        .route("/validate", get(validate::get))
+        .route(
+            "/groups/{id}/leaderboard",
+            get(groups::leaderboard::get).route_layer(from_fn(validate_session_token)),
+        )
+        .route(
+            "/play/archive",
+            get(play::archive::get).route_layer(from_fn(validate_session_token)),
+        )
+        .route(
+            "/play/session",
+            get(play::session::get).route_layer(from_fn(validate_session_token)),
+        )
+        .route(
+            "/play/start",
+            get(play::start::get).route_layer(from_fn(validate_session_token)),
+        )
+        .route(
+            "/play/analysis",
+            get(play::analysis::get).route_layer(from_fn(validate_session_token)),
+        )
+        .route(
+            "/play/boards/start",
+            get(play::boards::start::get).route_layer(from_fn(validate_session_token)),
+        )
+        .route(
+            "/play/versus/{id}",
+            get(play::versus::get).route_layer(from_fn(validate_session_token)),
+        )
+        .route(
+            "/custom/{code}/start",
+            get(custom::start::get).route_layer(from_fn(validate_session_token)),
+        )
+        .route(
+            "/custom/{code}/results",
+            get(custom::results::get).route_layer(from_fn(validate_session_token)),
+        )
+        .route(
+            "/rooms/{code}/ws",
+            get(rooms::ws::get).route_layer(from_fn(validate_session_token)),
+        )
+}
+
+fn route_posts(app: Router<Repos>) -> Router<Repos> {
+    app.route(
+        "/",
+        post(root::post).route_layer(from_fn(authorize_paseto_token)),
+    )
+    .route(
+        "/play/submit",
+        post(play::submit::post).route_layer(from_fn(validate_session_token)),
+    )
+    .route(
+        "/play/hint",
+        post(play::hint::post).route_layer(from_fn(validate_session_token)),
+    )
+    .route(
+        "/play/boards/submit",
+        post(play::boards::submit::post).route_layer(from_fn(validate_session_token)),
+    )
+    .route(
+        "/play/practice/start",
+        post(play::practice::start::post).route_layer(from_fn(validate_session_token)),
+    )
+    .route(
+        "/play/practice/{id}/submit",
+        post(play::practice::submit::post).route_layer(from_fn(validate_session_token)),
+    )
+    .route(
+        "/play/absurdle/start",
+        post(play::absurdle::start::post).route_layer(from_fn(validate_session_token)),
+    )
+    .route(
+        "/play/absurdle/{id}/submit",
+        post(play::absurdle::submit::post).route_layer(from_fn(validate_session_token)),
+    )
+    .route(
+        "/play/versus/queue",
+        post(play::versus::queue::post).route_layer(from_fn(validate_session_token)),
+    )
+    .route(
+        "/play/versus/{id}/submit",
+        post(play::versus::submit::post).route_layer(from_fn(validate_session_token)),
+    )
+    .route(
+        "/custom",
+        post(custom::post).route_layer(from_fn(validate_session_token)),
+    )
+    .route(
+        "/custom/{code}/submit",
+        post(custom::submit::post).route_layer(from_fn(validate_session_token)),
+    )
+    .route(
+        "/groups",
+        post(groups::post).route_layer(from_fn(validate_session_token)),
+    )
+    .route(
+        "/groups/join",
+        post(groups::join::post).route_layer(from_fn(validate_session_token)),
+    )
+    .route(
+        "/rooms",
+        post(rooms::post).route_layer(from_fn(validate_session_token)),
+    )
+}
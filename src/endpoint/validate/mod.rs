@@ -6,13 +6,25 @@ use axum::{extract::Query, http::StatusCode, response::IntoResponse};
 use serde::Deserialize;
 
 /// The parameters for the get request.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, utoipa::IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct GetParams {
     /// The word to validate.
     pub word: String,
 }
 
 /// The client validates a word.
+#[utoipa::path(
+    get,
+    path = "/validate",
+    operation_id = "validate_word",
+    tag = "words",
+    params(GetParams),
+    responses(
+        (status = OK, description = "The word is in the dictionary."),
+        (status = NOT_FOUND, description = "The word is not in the dictionary."),
+    ),
+)]
 pub async fn get(Query(params): Query<GetParams>) -> impl IntoResponse {
     tracing::info!("validating word {}…", params.word);
 
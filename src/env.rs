@@ -1,8 +1,11 @@
 //! Defines environment variables.
 
-use std::{env, path::PathBuf};
+use std::{env, path::PathBuf, time::Duration};
 
 use api_framework::{parse_env, static_lazy_lock};
+use axum::http::HeaderName;
+use chrono::NaiveDate;
+use entity::PuzzleDate;
 use tracing::level_filters::LevelFilter;
 
 use crate::sha256::sha256_hex_to_bytes;
@@ -57,7 +60,9 @@ static_lazy_lock! {
 }
 
 static_lazy_lock! {
-    /// The database connection URL.
+    /// The database connection URL. Its scheme selects the backend: `postgres://` for PostgreSQL, or
+    /// `sqlite://` for SQLite, which requires the `sqlite` feature. Use `sqlite://{path}?mode=rwc` to
+    /// create the SQLite database file if missing.
     pub DATABASE_URL: String = env::var("DATABASE_URL").expect("DATABASE_URL not set in environment");
 }
 
@@ -70,3 +75,112 @@ static_lazy_lock! {
     /// The session symmetric key hashed using SHA256.
     pub SESSION_SYMMETRIC_KEY: [u8; 32] = parse_env!("SESSION_SYMMETRIC_KEY" => |k| Ok(sha256_hex_to_bytes(&k).expect("SESSION_SYMMETRIC_KEY must be a valid 32-byte long SHA256 token"))).expect("SESSION_SYMMETRIC_KEY not set in environment");
 }
+
+static_lazy_lock! {
+    /// The symmetric key encrypting the codes of custom puzzles hashed using SHA256, which must
+    /// differ from [`PASETO_SYMMETRIC_KEY`] so that the codes cannot be forged into admin tokens.
+    pub CUSTOM_SYMMETRIC_KEY: [u8; 32] = parse_env!("CUSTOM_SYMMETRIC_KEY" => |k| Ok(sha256_hex_to_bytes(&k).expect("CUSTOM_SYMMETRIC_KEY must be a valid 32-byte long SHA256 token"))).expect("CUSTOM_SYMMETRIC_KEY not set in environment");
+}
+
+static_lazy_lock! {
+    /// The symmetric key hashing the IP addresses of the clients hashed using SHA256, so that only
+    /// keyed hashes of the addresses are stored.
+    pub ADDRESS_SYMMETRIC_KEY: [u8; 32] = parse_env!("ADDRESS_SYMMETRIC_KEY" => |k| Ok(sha256_hex_to_bytes(&k).expect("ADDRESS_SYMMETRIC_KEY must be a valid 32-byte long SHA256 token"))).expect("ADDRESS_SYMMETRIC_KEY not set in environment");
+}
+
+static_lazy_lock! {
+    /// The header a trusted reverse proxy sets to the IP address of the client, such as
+    /// `X-Forwarded-For`, whose last address is used. The address of the peer is used if not
+    /// specified, so it must be set behind a reverse proxy.
+    pub TRUSTED_FORWARDED_HEADER: Option<HeaderName> = parse_env!("TRUSTED_FORWARDED_HEADER" => |s| s.parse::<HeaderName>(); anyhow).ok();
+}
+
+static_lazy_lock! {
+    /// The date when the unversioned routes were deprecated. Defaults to `2026-10-18` if not specified.
+    pub LEGACY_ROUTES_DEPRECATED_AT: NaiveDate = parse_env!("LEGACY_ROUTES_DEPRECATED_AT" => |s| NaiveDate::parse_from_str(&s, "%Y-%m-%d"); anyhow).unwrap_or(NaiveDate::from_ymd_opt(2026, 10, 18).unwrap());
+}
+
+static_lazy_lock! {
+    /// The date when the unversioned routes will be removed. Defaults to `2027-04-18` if not specified.
+    pub LEGACY_ROUTES_SUNSET_AT: NaiveDate = parse_env!("LEGACY_ROUTES_SUNSET_AT" => |s| NaiveDate::parse_from_str(&s, "%Y-%m-%d"); anyhow).unwrap_or(NaiveDate::from_ymd_opt(2027, 4, 18).unwrap());
+}
+
+static_lazy_lock! {
+    /// The interval between blending the difficulties of ended puzzles with their play data, in
+    /// seconds. Defaults to an hour if not specified.
+    pub DIFFICULTY_BLEND_INTERVAL: Duration = parse_env!("DIFFICULTY_BLEND_INTERVAL" => |s| s.parse::<u64>().map(Duration::from_secs); anyhow).unwrap_or(Duration::from_secs(60 * 60));
+}
+
+static_lazy_lock! {
+    /// The time allowed to finish a time attack from its start, in seconds. Defaults to three
+    /// minutes if not specified.
+    pub TIME_ATTACK_LIMIT: Duration = parse_env!("TIME_ATTACK_LIMIT" => |s| s.parse::<u64>().map(Duration::from_secs); anyhow).unwrap_or(Duration::from_secs(3 * 60));
+}
+
+static_lazy_lock! {
+    /// The time a player may take on a versus turn, in seconds, after which the opponent wins.
+    /// Defaults to one minute if not specified.
+    pub VERSUS_TURN_TIMEOUT: Duration = parse_env!("VERSUS_TURN_TIMEOUT" => |s| s.parse::<u64>().map(Duration::from_secs); anyhow).unwrap_or(Duration::from_secs(60));
+}
+
+static_lazy_lock! {
+    /// The time a versus match may wait in the lobby for a guest, in seconds, after which it is
+    /// never matched. Defaults to five minutes if not specified.
+    pub VERSUS_LOBBY_TIMEOUT: Duration = parse_env!("VERSUS_LOBBY_TIMEOUT" => |s| s.parse::<u64>().map(Duration::from_secs); anyhow).unwrap_or(Duration::from_secs(5 * 60));
+}
+
+static_lazy_lock! {
+    /// The time from the start of a game under which a win is flagged as a fast solve, in
+    /// milliseconds. Defaults to two seconds if not specified.
+    pub CHEAT_FAST_SOLVE: Duration = parse_env!("CHEAT_FAST_SOLVE" => |s| s.parse::<u64>().map(Duration::from_millis); anyhow).unwrap_or(Duration::from_secs(2));
+}
+
+static_lazy_lock! {
+    /// The share of the recent finished games of a session won on the first guess from which it is
+    /// flagged as guessing luckily, as each is a one-in-thousands chance. Defaults to 0.2 if not
+    /// specified.
+    pub CHEAT_FIRST_GUESS_RATE: f64 = parse_env!("CHEAT_FIRST_GUESS_RATE" => |s| s.parse::<f64>(); anyhow).unwrap_or(0.2);
+}
+
+static_lazy_lock! {
+    /// The number of recent finished games a session needs before its first-guess wins are
+    /// flagged. Defaults to 10 if not specified.
+    pub CHEAT_FIRST_GUESS_SAMPLE: usize = parse_env!("CHEAT_FIRST_GUESS_SAMPLE" => |s| s.parse::<usize>(); anyhow).unwrap_or(10);
+}
+
+static_lazy_lock! {
+    /// The time from the start of a game under which a win counts towards the shared address
+    /// flag, in seconds. Defaults to thirty seconds if not specified.
+    pub CHEAT_QUICK_SOLVE: Duration = parse_env!("CHEAT_QUICK_SOLVE" => |s| s.parse::<u64>().map(Duration::from_secs); anyhow).unwrap_or(Duration::from_secs(30));
+}
+
+static_lazy_lock! {
+    /// The number of sessions from the same address quickly winning a puzzle from which they are
+    /// flagged. Defaults to 3 if not specified.
+    pub CHEAT_SHARED_ADDRESS_SESSIONS: usize = parse_env!("CHEAT_SHARED_ADDRESS_SESSIONS" => |s| s.parse::<usize>(); anyhow).unwrap_or(3);
+}
+
+static_lazy_lock! {
+    /// The number of past days whose daily puzzles may still be started, today excluded. Every
+    /// past day is playable if not specified, while future days are not until they have started at
+    /// [`TIMEZONE_MAX_OFFSET`].
+    pub PLAY_WINDOW_DAYS: Option<u64> = parse_env!("PLAY_WINDOW_DAYS" => |s| s.parse::<u64>(); anyhow).ok();
+}
+
+static_lazy_lock! {
+    /// The date of puzzle number 0, from which the puzzles are numbered sequentially. Defaults to
+    /// `2021-06-19` if not specified.
+    pub PUZZLE_EPOCH: PuzzleDate = parse_env!("PUZZLE_EPOCH" => |s| PuzzleDate::try_from(&s[..]); anyhow).unwrap_or(PuzzleDate::new(NaiveDate::from_ymd_opt(2021, 6, 19).unwrap()));
+}
+
+static_lazy_lock! {
+    /// The lowest UTC offset of a client timezone whose date is accepted as today, in hours.
+    /// Defaults to -12 if not specified.
+    pub TIMEZONE_MIN_OFFSET: i32 = parse_env!("TIMEZONE_MIN_OFFSET" => |s| s.parse::<i32>(); anyhow).unwrap_or(-12);
+}
+
+static_lazy_lock! {
+    /// The highest UTC offset of a client timezone whose date is accepted as today, in hours.
+    /// Defaults to 14 if not specified.
+    pub TIMEZONE_MAX_OFFSET: i32 = parse_env!("TIMEZONE_MAX_OFFSET" => |s| s.parse::<i32>(); anyhow).unwrap_or(14);
+}
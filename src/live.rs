@@ -0,0 +1,318 @@
+//! The in-process feed of live daily puzzle activity.
+//!
+//! Events are anonymised and carry the running counts of the day, so a subscriber lagging behind
+//! catches up on the next event. The counts of a date are seeded from the histories when it is
+//! first subscribed to, and are kept up to date by the events from then on until its last
+//! subscriber leaves. The events of a game are only published once the game is stored, and the
+//! games of a date are not stored while its counts are seeded, so every game is counted once.
+
+use std::{
+    collections::BTreeMap,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll, ready},
+};
+
+use api_framework::static_lazy_lock;
+use entity::{GameState, PuzzleDate, histories::Model as History};
+use parking_lot::Mutex;
+use serde::Serialize;
+use tokio::sync::{OwnedRwLockReadGuard, RwLock, broadcast};
+use tokio_stream::{Stream, wrappers::BroadcastStream};
+use utoipa::ToSchema;
+
+static_lazy_lock! {
+    /// The feed of live daily puzzle activity.
+    pub LIVE: Live = Live::new();
+}
+
+/// The number of updates a slow subscriber may lag behind before missing some.
+const UPDATES_CAPACITY: usize = 256;
+
+/// An anonymised event of a daily puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum LiveEvent {
+    /// Someone has started the puzzle.
+    Started,
+    /// Someone has solved the puzzle.
+    Won {
+        /// The number of tries taken to solve the puzzle.
+        tries: usize,
+    },
+    /// Someone has run out of tries or time.
+    Lost,
+}
+
+impl LiveEvent {
+    /// Returns the name of the event in the feed.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Started => "started",
+            Self::Won { .. } => "won",
+            Self::Lost => "lost",
+        }
+    }
+}
+
+/// The running counts of games of a daily puzzle.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, ToSchema)]
+pub struct LiveCounts {
+    /// The number of games started.
+    pub started: usize,
+    /// The number of games won.
+    pub won: usize,
+    /// The number of games lost.
+    pub lost: usize,
+}
+
+impl LiveCounts {
+    /// Counts the games of the histories of a date, where flagged games only count as started.
+    pub fn from_histories(histories: &[History]) -> Self {
+        histories
+            .iter()
+            .fold(Self::default(), |mut counts, history| {
+                counts.started += 1;
+                if history.is_flagged() {
+                    return counts;
+                }
+                match history.game_state() {
+                    GameState::Won { .. } => counts.won += 1,
+                    GameState::Lost => counts.lost += 1,
+                    _ => {}
+                }
+                counts
+            })
+    }
+
+    fn apply(&mut self, event: LiveEvent) {
+        match event {
+            LiveEvent::Started => self.started += 1,
+            LiveEvent::Won { .. } => self.won += 1,
+            LiveEvent::Lost => self.lost += 1,
+        }
+    }
+}
+
+/// An update of the feed of a daily puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+pub struct LiveUpdate {
+    /// The event, which is missing for the counts sent on subscription.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event: Option<LiveEvent>,
+    /// The running counts after the event.
+    pub counts: LiveCounts,
+}
+
+/// The feed of a date.
+#[derive(Debug, Default)]
+struct DateFeed {
+    /// The running counts, once seeded.
+    counts: Option<LiveCounts>,
+    /// The number of subscribers following the date.
+    subscribers: usize,
+    /// Held for reading while games of the date are stored until their events are published, and
+    /// for writing while the counts are seeded.
+    lock: Arc<RwLock<()>>,
+}
+
+/// A message of the broadcast channel.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum LiveMessage {
+    /// An update of the feed of a date.
+    Update(PuzzleDate, LiveUpdate),
+    /// The server is shutting down and every feed ends.
+    Closed,
+}
+
+/// The feed of live daily puzzle activity, broadcast to every subscriber.
+#[derive(Debug)]
+pub struct Live {
+    messages: broadcast::Sender<LiveMessage>,
+    dates: Mutex<BTreeMap<PuzzleDate, DateFeed>>,
+}
+
+impl Live {
+    /// Creates a feed without subscribers.
+    pub fn new() -> Self {
+        Self {
+            messages: broadcast::channel(UPDATES_CAPACITY).0,
+            dates: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    /// Returns whether the counts of a date have been seeded.
+    pub fn is_seeded(&self, date: &PuzzleDate) -> bool {
+        self.dates
+            .lock()
+            .get(date)
+            .is_some_and(|feed| feed.counts.is_some())
+    }
+
+    /// Subscribes to the feed of a date, seeding its counts with the counts loaded from its
+    /// histories unless already seeded. No game of the date is stored while loading.
+    pub async fn subscribe<F, Fut>(&self, date: &PuzzleDate, load: F) -> LiveSubscription<'_>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = LiveCounts>,
+    {
+        let lock = {
+            let mut dates = self.dates.lock();
+            let feed = dates.entry(date.clone()).or_default();
+            feed.subscribers += 1;
+            Arc::clone(&feed.lock)
+        };
+        // leaves the date again if the subscription is cancelled while seeding
+        let mut subscription = LiveSubscription {
+            live: self,
+            date: date.clone(),
+            counts: LiveCounts::default(),
+            updates: BroadcastStream::new(self.messages.subscribe()),
+        };
+
+        if !self.is_seeded(date) {
+            let _seeding = lock.write().await;
+            if !self.is_seeded(date) {
+                tracing::info!("seeding live counts for {date}…");
+                let counts = load().await;
+                if let Some(feed) = self.dates.lock().get_mut(date) {
+                    feed.counts = Some(counts);
+                }
+            }
+        }
+
+        let dates = self.dates.lock();
+        // the updates are received from the counts on
+        subscription.updates = BroadcastStream::new(self.messages.subscribe());
+        subscription.counts = dates
+            .get(date)
+            .and_then(|feed| feed.counts)
+            .unwrap_or_default();
+        subscription
+    }
+
+    /// Holds off the seeding of a date while a game of the date is stored, returning an outbox for
+    /// the events of the game to publish once it is.
+    pub async fn outbox(&self, date: &PuzzleDate) -> LiveOutbox<'_> {
+        let lock = Arc::clone(&self.dates.lock().entry(date.clone()).or_default().lock);
+        LiveOutbox {
+            live: self,
+            date: date.clone(),
+            events: Vec::new(),
+            guard: Some(lock.read_owned().await),
+        }
+    }
+
+    /// Publishes an event of a date. Dates nobody follows are counted from the histories on their
+    /// next subscription instead.
+    fn publish(&self, date: &PuzzleDate, event: LiveEvent) {
+        let mut dates = self.dates.lock();
+        let Some(counts) = dates.get_mut(date).and_then(|feed| feed.counts.as_mut()) else {
+            return;
+        };
+
+        counts.apply(event);
+        let update = LiveUpdate {
+            event: Some(event),
+            counts: *counts,
+        };
+        self.messages
+            .send(LiveMessage::Update(date.clone(), update))
+            .ok();
+    }
+
+    /// Evicts the dates nobody follows or stores games of, along with their counts.
+    fn prune(&self) {
+        self.dates
+            .lock()
+            .retain(|_, feed| feed.subscribers > 0 || Arc::strong_count(&feed.lock) > 1);
+    }
+
+    /// Ends the feed of every subscriber.
+    pub fn close(&self) {
+        tracing::info!("closing {} live feeds…", self.messages.receiver_count());
+        self.messages.send(LiveMessage::Closed).ok();
+    }
+}
+
+impl Default for Live {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A subscription to the feed of a date, streaming its updates until the feed ends.
+#[derive(Debug)]
+pub struct LiveSubscription<'a> {
+    live: &'a Live,
+    date: PuzzleDate,
+    counts: LiveCounts,
+    updates: BroadcastStream<LiveMessage>,
+}
+
+impl LiveSubscription<'_> {
+    /// Returns the counts of the date on subscription.
+    pub const fn counts(&self) -> LiveCounts {
+        self.counts
+    }
+}
+
+impl Stream for LiveSubscription<'_> {
+    type Item = LiveUpdate;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            match ready!(Pin::new(&mut self.updates).poll_next(cx)) {
+                Some(Ok(LiveMessage::Update(date, update))) if date == self.date => {
+                    return Poll::Ready(Some(update));
+                }
+                Some(Ok(LiveMessage::Closed)) | None => return Poll::Ready(None),
+                // the updates of other dates, or missed by lagging behind
+                Some(_) => {}
+            }
+        }
+    }
+}
+
+impl Drop for LiveSubscription<'_> {
+    fn drop(&mut self) {
+        if let Some(feed) = self.live.dates.lock().get_mut(&self.date) {
+            feed.subscribers = feed.subscribers.saturating_sub(1);
+        }
+        self.live.prune();
+    }
+}
+
+/// The events of a game of a date, held back until the game is stored. The counts of the date are
+/// not seeded until the outbox is published or dropped.
+#[derive(Debug)]
+pub struct LiveOutbox<'a> {
+    live: &'a Live,
+    date: PuzzleDate,
+    events: Vec<LiveEvent>,
+    guard: Option<OwnedRwLockReadGuard<()>>,
+}
+
+impl LiveOutbox<'_> {
+    /// Holds back an event until the outbox is published.
+    pub fn push(&mut self, event: LiveEvent) {
+        self.events.push(event);
+    }
+
+    /// Publishes the events once the game is stored. The events are discarded if the outbox is
+    /// dropped instead.
+    pub fn publish(mut self) {
+        for event in std::mem::take(&mut self.events) {
+            self.live.publish(&self.date, event);
+        }
+    }
+}
+
+impl Drop for LiveOutbox<'_> {
+    fn drop(&mut self) {
+        self.guard.take();
+        self.live.prune();
+    }
+}
@@ -1,39 +1,84 @@
 //! KessokuTeaTime API backend for the wordle game.
 
-use crate::env::{
-    DATABASE_URL, PORT, TRACING_STDERR_LEVEL,
-    info::{BUILD_TIMESTAMP, GIT_HASH},
+use crate::{
+    database::repo::Repos,
+    env::{
+        DATABASE_URL, PORT, TRACING_STDERR_LEVEL,
+        info::{BUILD_TIMESTAMP, GIT_HASH},
+    },
 };
 
-use std::net::SocketAddr;
+use std::{net::SocketAddr, sync::LazyLock};
 
 use anyhow::{Error, anyhow};
 use api_framework::{shutdown, static_lazy_lock};
 use axum::Router;
+use clap::{Arg, ArgAction, ArgMatches, Command};
+use entity::{PuzzleSolution, Solver};
 use tokio::net::TcpListener;
+use utoipa::OpenApi as _;
 
 pub mod config;
 pub mod env;
 pub mod sha256;
 pub mod trace;
 
+pub mod anticheat;
 pub mod database;
+pub mod difficulty;
 pub mod endpoint;
+pub mod live;
 pub mod middleware;
+pub mod puzzle_number;
+pub mod rooms;
 
 static_lazy_lock! {
     WORDS: &[&str] = random_word::all_len(5, random_word::Lang::En).unwrap();
 }
 
+static_lazy_lock! {
+    /// The words that can be drawn as solutions, which Absurdle games narrow down.
+    ///
+    /// See: [`WORDS`]
+    pub ANSWERS: Vec<PuzzleSolution> = WORDS
+        .iter()
+        .filter_map(|word| PuzzleSolution::try_from(*word).ok())
+        .collect();
+}
+
+static_lazy_lock! {
+    /// The solver over the allowed guesses.
+    ///
+    /// See: [`WORDS`]
+    SOLVER: Solver = Solver::new(WORDS.iter().copied());
+}
+
 #[tokio::main]
 async fn main() {
+    let matches = cli();
+    if let Some(("openapi", _)) = matches.subcommand() {
+        println!(
+            "{}",
+            endpoint::openapi::ApiDoc::openapi()
+                .to_pretty_json()
+                .unwrap()
+        );
+        return;
+    }
+
     env::setup();
     trace::setup().unwrap();
     tracing::info!("stderr is tracing on level {:?}", *TRACING_STDERR_LEVEL);
     tracing::trace!("loaded environment: {:#?}", std::env::vars());
 
-    database::setup().await.unwrap();
-    tracing::trace!("set up database at {}", *DATABASE_URL);
+    let repos = if matches.get_flag("ephemeral") {
+        tracing::warn!("running in ephemeral mode, all data will be lost on exit!");
+        Repos::memory()
+    } else {
+        let db = database::setup().await.unwrap();
+        tracing::trace!("set up database at {}", *DATABASE_URL);
+        Repos::sea_orm(db)
+    };
 
     tracing::info!(
         "binary {} version {}",
@@ -43,14 +88,32 @@ async fn main() {
     tracing::info!("compiled from commit {GIT_HASH} at {BUILD_TIMESTAMP}");
     tracing::info!("starting server on port {}…", *PORT);
 
-    serve().await.unwrap();
+    tracing::info!("precomputing the solver tables…");
+    tokio::task::spawn_blocking(|| LazyLock::force(&SOLVER))
+        .await
+        .unwrap();
+    tracing::info!("precomputed the solver tables over {} words", SOLVER.len());
+
+    difficulty::spawn_blending(repos.clone());
+    serve(repos).await.unwrap();
 
     tracing::info!("stopping…");
 }
 
-async fn serve() -> Result<(), Error> {
-    let mut app = Router::new();
-    app = endpoint::route_from(app);
+fn cli() -> ArgMatches {
+    clap::command!()
+        .arg(
+            Arg::new("ephemeral")
+                .long("ephemeral")
+                .action(ArgAction::SetTrue)
+                .help("Serves from an in-memory store instead of the database"),
+        )
+        .subcommand(Command::new("openapi").about("Prints the OpenAPI document and exits"))
+        .get_matches()
+}
+
+async fn serve(repos: Repos) -> Result<(), Error> {
+    let app = endpoint::route_from(Router::new()).with_state(repos);
 
     let listener = TcpListener::bind(format!("0.0.0.0:{}", *PORT)).await?;
 
@@ -58,7 +121,11 @@ async fn serve() -> Result<(), Error> {
         listener,
         app.into_make_service_with_connect_info::<SocketAddr>(),
     )
-    .with_graceful_shutdown(shutdown::signal())
+    .with_graceful_shutdown(async {
+        shutdown::signal().await;
+        rooms::ROOMS.close_all();
+        live::LIVE.close();
+    })
     .await
     .map_err(|e| anyhow!(e))
 }
@@ -0,0 +1,60 @@
+//! Extractor for the address of the client.
+
+use crate::{
+    env::{ADDRESS_SYMMETRIC_KEY, TRUSTED_FORWARDED_HEADER},
+    sha256::hmac_sha256,
+};
+
+use std::net::{IpAddr, SocketAddr};
+
+use axum::{
+    extract::{ConnectInfo, FromRequestParts, rejection::ExtensionRejection},
+    http::{HeaderMap, request::Parts},
+};
+
+/// The keyed hash of the IP address of the client, which is stored instead of the address.
+///
+/// The address is read from the [`TRUSTED_FORWARDED_HEADER`] if configured and present, or else
+/// from the peer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClientAddress(pub String);
+
+impl ClientAddress {
+    /// Hashes an IP address with the [`ADDRESS_SYMMETRIC_KEY`].
+    pub fn hash(ip: IpAddr) -> Self {
+        Self(hex::encode(hmac_sha256(
+            &ADDRESS_SYMMETRIC_KEY,
+            ip.to_string().as_bytes(),
+        )))
+    }
+}
+
+/// Returns the last address of the [`TRUSTED_FORWARDED_HEADER`], which is the one the trusted
+/// reverse proxy has seen, as the addresses before it are set by the client.
+fn forwarded_ip(headers: &HeaderMap) -> Option<IpAddr> {
+    let header = TRUSTED_FORWARDED_HEADER.as_ref()?;
+    headers
+        .get_all(header)
+        .iter()
+        .next_back()?
+        .to_str()
+        .ok()?
+        .rsplit(',')
+        .next()?
+        .trim()
+        .parse()
+        .ok()
+}
+
+impl<S> FromRequestParts<S> for ClientAddress
+where
+    S: Send + Sync,
+{
+    type Rejection = ExtensionRejection;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let ConnectInfo(addr) = ConnectInfo::<SocketAddr>::from_request_parts(parts, state).await?;
+        let ip = forwarded_ip(&parts.headers).unwrap_or(addr.ip());
+        Ok(Self::hash(ip))
+    }
+}
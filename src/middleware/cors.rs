@@ -5,10 +5,13 @@
 /// Router layers for Cross-Origin Resource Sharing (CORS).
 pub mod layers {
     use api_framework::static_lazy_lock;
-    use axum::http::{HeaderValue, header, method::Method, request};
+    use axum::http::{HeaderName, HeaderValue, header, method::Method, request};
     use tower_http::cors::{AllowOrigin, CorsLayer};
 
-    use crate::config::{Config as _, services::CorsConfig};
+    use crate::{
+        config::{Config as _, services::CorsConfig},
+        endpoint::today::TIMEZONE_HEADER,
+    };
 
     static_lazy_lock! {
         /// The layer to handle Cross-Origin Resource Sharing (CORS).
@@ -22,6 +25,11 @@ pub mod layers {
         }))
         .allow_credentials(true)
         .allow_methods([Method::GET, Method::POST, Method::PUT, Method::DELETE, Method::OPTIONS])
-        .allow_headers([header::AUTHORIZATION, header::CONTENT_TYPE, header::COOKIE]);
+        .allow_headers([
+            header::AUTHORIZATION,
+            header::CONTENT_TYPE,
+            header::COOKIE,
+            HeaderName::from_static(TIMEZONE_HEADER),
+        ]);
     }
 }
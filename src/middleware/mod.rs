@@ -1,5 +1,7 @@
 //! The API middleware.
 
+pub mod address;
 pub mod auth;
 pub mod cors;
 pub mod session;
+pub mod version;
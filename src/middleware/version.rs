@@ -0,0 +1,140 @@
+//! Middleware for API versioning.
+
+use crate::env::{LEGACY_ROUTES_DEPRECATED_AT, LEGACY_ROUTES_SUNSET_AT};
+
+use std::{
+    fmt::Display,
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+use axum::{
+    extract::{Request, State},
+    http::{HeaderName, HeaderValue, header},
+    middleware::Next,
+    response::Response,
+};
+use chrono::NaiveDate;
+use tracing::Instrument as _;
+
+/// The `Deprecation` header as defined in RFC 9745.
+pub static DEPRECATION: HeaderName = HeaderName::from_static("deprecation");
+
+/// The `Sunset` header as defined in RFC 8594.
+pub static SUNSET: HeaderName = HeaderName::from_static("sunset");
+
+/// The available API versions.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ApiVersion {
+    /// The first version, mounted under `/v1`.
+    V1,
+}
+
+impl ApiVersion {
+    /// The latest API version, which the unversioned routes alias to.
+    pub const LATEST: Self = Self::V1;
+
+    /// The name of the API version.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::V1 => "v1",
+        }
+    }
+
+    /// The path prefix the API version is mounted under.
+    pub fn prefix(&self) -> &'static str {
+        match self {
+            Self::V1 => "/v1",
+        }
+    }
+
+    /// The number of requests this API version has handled since startup.
+    pub fn requests(&self) -> &'static AtomicU64 {
+        static V1: AtomicU64 = AtomicU64::new(0);
+
+        match self {
+            Self::V1 => &V1,
+        }
+    }
+}
+
+impl Display for ApiVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Counts the requests of an API version and traces the request under the version's span.
+///
+/// See: [`ApiVersion::requests`]
+pub async fn count_requests(
+    State(version): State<ApiVersion>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let count = version.requests().fetch_add(1, Ordering::Relaxed) + 1;
+    tracing::info!(api_version = %version, requests = count, "handling request #{count} for API {version}…");
+
+    next.run(request)
+        .instrument(tracing::info_span!("api", version = %version))
+        .await
+}
+
+/// The number of requests the deprecated unversioned routes have handled since startup. These are
+/// counted apart from [`ApiVersion::LATEST`] so that the remaining legacy traffic can be measured.
+pub fn unversioned_requests() -> &'static AtomicU64 {
+    static UNVERSIONED: AtomicU64 = AtomicU64::new(0);
+
+    &UNVERSIONED
+}
+
+/// Counts the requests of an unversioned route under the `unversioned` label and marks its response
+/// as deprecated, pointing to its successor under [`ApiVersion::LATEST`].
+///
+/// See: [`unversioned_requests`], [`LEGACY_ROUTES_DEPRECATED_AT`], [`LEGACY_ROUTES_SUNSET_AT`]
+pub async fn deprecate_unversioned(request: Request, next: Next) -> Response {
+    let path = request.uri().path().to_owned();
+    let count = unversioned_requests().fetch_add(1, Ordering::Relaxed) + 1;
+    tracing::warn!(
+        api_version = "unversioned",
+        requests = count,
+        "handling request #{count} for deprecated route {path}…"
+    );
+
+    let successor = match &path[..] {
+        "/" => ApiVersion::LATEST.prefix().to_owned(),
+        path => format!("{}{path}", ApiVersion::LATEST.prefix()),
+    };
+
+    let mut response = next
+        .run(request)
+        .instrument(tracing::info_span!("api", version = "unversioned"))
+        .await;
+    for (name, value) in [
+        (
+            DEPRECATION.clone(),
+            format!("@{}", timestamp(&LEGACY_ROUTES_DEPRECATED_AT)),
+        ),
+        (SUNSET.clone(), http_date(&LEGACY_ROUTES_SUNSET_AT)),
+        (
+            header::LINK,
+            format!("<{successor}>; rel=\"successor-version\""),
+        ),
+    ] {
+        if let Ok(value) = HeaderValue::from_str(&value) {
+            response.headers_mut().insert(name, value);
+        }
+    }
+    response
+}
+
+fn timestamp(date: &NaiveDate) -> i64 {
+    date.and_time(chrono::NaiveTime::MIN).and_utc().timestamp()
+}
+
+fn http_date(date: &NaiveDate) -> String {
+    date.and_time(chrono::NaiveTime::MIN)
+        .and_utc()
+        .format("%a, %d %b %Y %H:%M:%S GMT")
+        .to_string()
+}
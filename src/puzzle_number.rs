@@ -0,0 +1,83 @@
+//! Sequential puzzle numbers, counted in days from [`PUZZLE_EPOCH`].
+//!
+//! Players refer to puzzles by number, so wherever a date is accepted, a number may be given
+//! instead.
+
+use crate::env::PUZZLE_EPOCH;
+
+use std::fmt::Display;
+
+use entity::{PuzzleDate, PuzzleDateError};
+
+/// Returns the number of the puzzle of a date, or [`None`] if earlier than [`PUZZLE_EPOCH`].
+pub fn number_of(date: &PuzzleDate) -> Option<u64> {
+    date.number_from(&PUZZLE_EPOCH)
+}
+
+/// Returns how a puzzle of a date is referred to when shared, as `#` followed by its number, or as
+/// its date if earlier than [`PUZZLE_EPOCH`].
+pub fn label_of(date: &PuzzleDate) -> String {
+    number_of(date).map_or_else(|| date.to_string(), |number| format!("#{number}"))
+}
+
+/// Returns the date of the puzzle with a number.
+///
+/// # Errors
+///
+/// Returns [`PuzzleNumberError::OutOfRange`] if the number is too large for a date.
+pub fn date_of(number: u64) -> Result<PuzzleDate, PuzzleNumberError> {
+    PuzzleDate::from_number(&PUZZLE_EPOCH, number).ok_or(PuzzleNumberError::OutOfRange)
+}
+
+/// Resolves the date of a puzzle given either by date or by number.
+///
+/// # Errors
+///
+/// Returns [`PuzzleNumberError`] unless exactly one of a valid date or number is given.
+pub fn resolve(date: Option<&str>, number: Option<u64>) -> Result<PuzzleDate, PuzzleNumberError> {
+    match (date, number) {
+        (Some(date), None) => PuzzleDate::try_from(date).map_err(PuzzleNumberError::Date),
+        (None, Some(number)) => date_of(number),
+        (None, None) => Err(PuzzleNumberError::Missing),
+        (Some(_), Some(_)) => Err(PuzzleNumberError::Ambiguous),
+    }
+}
+
+/// Parses a path segment holding either a date in `YYYY-MM-DD` format or a puzzle number.
+///
+/// # Errors
+///
+/// Returns [`PuzzleNumberError`] if the segment is neither a valid date nor a valid number.
+pub fn parse(segment: &str) -> Result<PuzzleDate, PuzzleNumberError> {
+    match segment.parse::<u64>() {
+        Ok(number) => date_of(number),
+        Err(_) => PuzzleDate::try_from(segment).map_err(PuzzleNumberError::Date),
+    }
+}
+
+/// The errors that can occur when resolving a puzzle by date or by number.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum PuzzleNumberError {
+    /// Neither a date nor a number is given.
+    Missing,
+    /// Both a date and a number are given.
+    Ambiguous,
+    /// The date is invalid.
+    Date(PuzzleDateError),
+    /// The number is too large for a date.
+    OutOfRange,
+}
+
+impl Display for PuzzleNumberError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Missing => write!(f, "either a date or a number must be given"),
+            Self::Ambiguous => write!(f, "a date and a number cannot both be given"),
+            Self::Date(err) => write!(f, "{err}"),
+            Self::OutOfRange => write!(f, "the puzzle number is out of range"),
+        }
+    }
+}
+
+impl std::error::Error for PuzzleNumberError {}
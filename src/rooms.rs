@@ -0,0 +1,279 @@
+//! The in-process registry of race rooms.
+//!
+//! A room lives only as long as players are connected to it, and every room is closed on server
+//! shutdown. Players who have started keep their progress until the room is closed, so they may
+//! reconnect. Nothing is persisted.
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, TimeDelta, Utc};
+use entity::{GameState, Matches, PuzzleSolution, SubmitHistory, SubmitHistoryError, SubmitWord};
+use parking_lot::Mutex;
+use serde::Serialize;
+use tokio::sync::broadcast;
+
+/// The registry of every race room.
+pub static ROOMS: Rooms = Rooms::new();
+
+/// The number of events a slow connection may lag behind before missing some.
+const EVENTS_CAPACITY: usize = 64;
+
+/// The time a room may wait for its first player before being pruned.
+const IDLE_ROOM_TIMEOUT: TimeDelta = TimeDelta::minutes(10);
+
+/// The events pushed to every player of a room. Tints are sent without their letters, so nothing
+/// is spoiled.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum RoomEvent {
+    /// A player has joined the room.
+    Joined {
+        /// The public id of the player.
+        player: String,
+        /// The display name of the player.
+        name: String,
+    },
+    /// A player has left the room.
+    Left {
+        /// The public id of the player.
+        player: String,
+    },
+    /// A player has submitted a word.
+    Tinted {
+        /// The public id of the player.
+        player: String,
+        /// The number of words submitted by the player.
+        tries: usize,
+        /// The match statuses of the word.
+        pattern: Vec<Matches>,
+    },
+    /// A player has finished the race.
+    Finished {
+        /// The public id of the player.
+        player: String,
+        /// The game state reached by the player.
+        state: GameState,
+        /// The place of the player among the winners, starting from 1, if the player has won.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        place: Option<usize>,
+    },
+    /// The room has been closed by the server.
+    Closed,
+}
+
+/// A player of a room.
+#[derive(Debug)]
+struct Player {
+    id: String,
+    connections: usize,
+    history: SubmitHistory,
+}
+
+/// A race room where every player solves the same word.
+#[derive(Debug)]
+struct Room {
+    solution: PuzzleSolution,
+    players: BTreeMap<String, Player>,
+    winners: Vec<String>,
+    events: broadcast::Sender<RoomEvent>,
+    created_at: DateTime<Utc>,
+}
+
+/// The errors that can occur when submitting a word to a room.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum RoomError {
+    /// The room does not exist, or the session has not joined it.
+    NotFound,
+    /// The game of the player is over and accepts no further words.
+    GameOver(GameState),
+    /// The submission fails.
+    Submit(SubmitHistoryError),
+}
+
+impl std::fmt::Display for RoomError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotFound => write!(f, "no such room or player"),
+            Self::GameOver(state) => write!(f, "the game is over: {state}"),
+            Self::Submit(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for RoomError {}
+
+/// The registry of race rooms, keyed by their codes.
+#[derive(Debug)]
+pub struct Rooms {
+    rooms: Mutex<BTreeMap<String, Room>>,
+}
+
+impl Rooms {
+    /// Creates an empty registry.
+    pub const fn new() -> Self {
+        Self {
+            rooms: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    /// Creates a room on a solution and returns its code. Rooms nobody has joined in time are
+    /// pruned meanwhile.
+    pub fn create(&self, solution: PuzzleSolution) -> String {
+        let now = Utc::now();
+        let mut rooms = self.rooms.lock();
+        rooms.retain(|_, room| {
+            !room.players.is_empty() || now - room.created_at < IDLE_ROOM_TIMEOUT
+        });
+
+        let code = loop {
+            let code = hex::encode_upper(rand::random::<[u8; 3]>());
+            if !rooms.contains_key(&code) {
+                break code;
+            }
+        };
+        rooms.insert(
+            code.clone(),
+            Room {
+                solution,
+                players: BTreeMap::new(),
+                winners: Vec::new(),
+                events: broadcast::channel(EVENTS_CAPACITY).0,
+                created_at: now,
+            },
+        );
+
+        tracing::info!("created room {code}");
+        code
+    }
+
+    /// Joins a room with a session, returning the public id of the player and a receiver of the
+    /// room events. Joining again with the same session resumes the same player.
+    pub fn join(
+        &self,
+        code: &str,
+        session: &str,
+        name: &str,
+    ) -> Option<(String, broadcast::Receiver<RoomEvent>)> {
+        let mut rooms = self.rooms.lock();
+        let room = rooms.get_mut(code)?;
+        let receiver = room.events.subscribe();
+
+        let player = room
+            .players
+            .entry(session.to_owned())
+            .or_insert_with(|| Player {
+                id: hex::encode(rand::random::<[u8; 8]>()),
+                connections: 0,
+                history: SubmitHistory::new(),
+            });
+        player.connections += 1;
+        let id = player.id.clone();
+
+        tracing::info!("player {id} joined room {code}");
+        room.events
+            .send(RoomEvent::Joined {
+                player: id.clone(),
+                name: name.to_owned(),
+            })
+            .ok();
+        Some((id, receiver))
+    }
+
+    /// Submits a word for the player of a session, pushing its tint to the room and returning the
+    /// tinted word with its letters.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RoomError::NotFound`] if the room or the player is missing,
+    /// [`RoomError::GameOver`] if the game of the player is over, or [`RoomError::Submit`] if the
+    /// submission fails.
+    pub fn submit(
+        &self,
+        code: &str,
+        session: &str,
+        answer: &PuzzleSolution,
+    ) -> Result<(SubmitWord, GameState), RoomError> {
+        let mut rooms = self.rooms.lock();
+        let room = rooms.get_mut(code).ok_or(RoomError::NotFound)?;
+        let player = room.players.get_mut(session).ok_or(RoomError::NotFound)?;
+
+        let state = player.history.game_state();
+        if state.is_over() {
+            return Err(RoomError::GameOver(state));
+        }
+
+        let word = SubmitWord::tint(answer, &room.solution);
+        player.history.submit(word).map_err(RoomError::Submit)?;
+        let state = player.history.game_state();
+
+        room.events
+            .send(RoomEvent::Tinted {
+                player: player.id.clone(),
+                tries: player.history.len(),
+                pattern: word.matches().to_vec(),
+            })
+            .ok();
+        if state.is_over() {
+            let place = matches!(state, GameState::Won { .. }).then(|| {
+                room.winners.push(player.id.clone());
+                room.winners.len()
+            });
+            room.events
+                .send(RoomEvent::Finished {
+                    player: player.id.clone(),
+                    state,
+                    place,
+                })
+                .ok();
+        }
+        Ok((word, state))
+    }
+
+    /// Leaves a room with a session once its last connection closes. The player is kept with their
+    /// history if they have started, and the room is removed once no player is connected.
+    pub fn leave(&self, code: &str, session: &str) {
+        let mut rooms = self.rooms.lock();
+        let Some(room) = rooms.get_mut(code) else {
+            return;
+        };
+        let Some(player) = room.players.get_mut(session) else {
+            return;
+        };
+
+        player.connections = player.connections.saturating_sub(1);
+        if player.connections == 0 {
+            let id = player.id.clone();
+            if player.history.is_empty() {
+                room.players.remove(session);
+            }
+            tracing::info!("player {id} left room {code}");
+            room.events.send(RoomEvent::Left { player: id }).ok();
+        }
+        if room.players.values().all(|player| player.connections == 0) {
+            rooms.remove(code);
+            tracing::info!("removed room {code}");
+        }
+    }
+
+    /// Returns whether a room exists.
+    pub fn contains(&self, code: &str) -> bool {
+        self.rooms.lock().contains_key(code)
+    }
+
+    /// Closes every room, notifying their players.
+    pub fn close_all(&self) {
+        let rooms = std::mem::take(&mut *self.rooms.lock());
+        tracing::info!("closing {} rooms…", rooms.len());
+        for room in rooms.values() {
+            room.events.send(RoomEvent::Closed).ok();
+        }
+    }
+}
+
+impl Default for Rooms {
+    fn default() -> Self {
+        Self::new()
+    }
+}
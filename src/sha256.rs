@@ -1,5 +1,7 @@
 //! Utilities for handling SHA-256 hashes.
 
+use sha2::{Digest as _, Sha256};
+
 /// Converts a SHA-256 hash in hexadecimal string format to a byte array.
 #[derive(Debug)]
 #[non_exhaustive]
@@ -41,3 +43,19 @@ fn from_hex(b: u8) -> Result<u8, HexDecodeError> {
         _ => Err(HexDecodeError::InvalidHexCharacter),
     }
 }
+
+/// Computes the HMAC-SHA256 of a message with a 32-byte key.
+pub fn hmac_sha256(key: &[u8; 32], message: &[u8]) -> [u8; 32] {
+    let mut block = [0u8; 64];
+    block[..32].copy_from_slice(key);
+
+    let inner = Sha256::new()
+        .chain_update(block.map(|b| b ^ 0x36))
+        .chain_update(message)
+        .finalize();
+    Sha256::new()
+        .chain_update(block.map(|b| b ^ 0x5c))
+        .chain_update(inner)
+        .finalize()
+        .into()
+}
@@ -0,0 +1,67 @@
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+use entity::{PuzzleDate, PuzzleSolution, prelude::*, puzzles, sessions};
+use sea_orm::{
+    ActiveModelTrait as _, ActiveValue, ColumnTrait as _, EntityTrait as _, QueryFilter as _,
+    QueryOrder as _,
+};
+
+#[tokio::test]
+async fn test() {
+    let db = crate::setup().await;
+
+    for date in ["2026-10-17", "2025-12-31", "2026-01-02", "2026-10-18"] {
+        puzzles::ActiveModel {
+            date: ActiveValue::Set(PuzzleDate::try_from(date).unwrap()),
+            solution: ActiveValue::Set(PuzzleSolution::try_from("rusty").unwrap()),
+            ..Default::default()
+        }
+        .insert(&db)
+        .await
+        .unwrap();
+    }
+
+    let dates = Puzzles::find()
+        .filter(puzzles::Column::Date.between(
+            PuzzleDate::try_from("2026-01-01").unwrap(),
+            PuzzleDate::try_from("2026-10-17").unwrap(),
+        ))
+        .order_by_asc(puzzles::Column::Date)
+        .all(&db)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|puzzle| puzzle.date.to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(dates, ["2026-01-02", "2026-10-17"]);
+
+    let date_time = |hour, min| {
+        NaiveDateTime::new(
+            NaiveDate::from_ymd_opt(2026, 10, 18).unwrap(),
+            NaiveTime::from_hms_milli_opt(hour, min, 0, 250).unwrap(),
+        )
+    };
+    for (session, created_at) in [("late", date_time(21, 5)), ("early", date_time(9, 30))] {
+        sessions::ActiveModel {
+            session: ActiveValue::Set(session.to_owned()),
+            created_at: ActiveValue::Set(created_at),
+            updated_at: ActiveValue::Set(created_at),
+        }
+        .insert(&db)
+        .await
+        .unwrap();
+    }
+
+    let sessions = Sessions::find()
+        .filter(sessions::Column::CreatedAt.gt(date_time(9, 0)))
+        .order_by_asc(sessions::Column::CreatedAt)
+        .all(&db)
+        .await
+        .unwrap();
+    assert_eq!(
+        sessions
+            .iter()
+            .map(|session| (&session.session[..], session.created_at))
+            .collect::<Vec<_>>(),
+        [("early", date_time(9, 30)), ("late", date_time(21, 5))]
+    );
+}
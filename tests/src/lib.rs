@@ -7,12 +7,21 @@ use std::env;
 use migration::{Migrator, MigratorTrait as _};
 use sea_orm::{ConnectOptions, Database, DatabaseConnection};
 
+mod dates;
+mod results;
 mod submit_history;
 
+/// The database to test against if `DATABASE_URL` is not set, which is an in-memory SQLite
+/// database shared among the connections of a pool.
+const DEFAULT_DATABASE_URL: &str = "sqlite::memory:";
+
 async fn setup() -> DatabaseConnection {
     dotenvy::dotenv().ok();
-    let db_url = env::var("DATABASE_URL").expect("DATABASE_URL not set in environment");
-    let db = Database::connect(ConnectOptions::new(&db_url))
+    let db_url = env::var("DATABASE_URL").unwrap_or_else(|_| DEFAULT_DATABASE_URL.to_owned());
+    let mut options = ConnectOptions::new(&db_url);
+    options.max_connections(5);
+
+    let db = Database::connect(options)
         .await
         .unwrap_or_else(|_| panic!("cannot connect to {db_url}"));
 
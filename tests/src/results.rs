@@ -0,0 +1,130 @@
+use chrono::NaiveDateTime;
+use entity::{
+    CheatFlag, CheatFlags, GameState, Hint, Hints, PuzzleDate, PuzzleSolution, SubmitHistory,
+    SubmitWord, first_guess_counts, histories, prelude::*, puzzles, result_counts, sessions,
+};
+use migration::{Migrator, MigratorTrait as _};
+use sea_orm::{ActiveModelTrait as _, ActiveValue, EntityTrait as _, QueryOrder as _};
+
+#[tokio::test]
+async fn backfills_results() {
+    let db = crate::setup().await;
+    let date = PuzzleDate::try_from("2026-10-18").unwrap();
+    let solution = PuzzleSolution::try_from("RUSTY").unwrap();
+
+    puzzles::ActiveModel {
+        date: ActiveValue::Set(date.clone()),
+        solution: ActiveValue::Set(solution.clone()),
+        ..Default::default()
+    }
+    .insert(&db)
+    .await
+    .unwrap();
+
+    let played = |guesses: &[&str]| {
+        let mut submit_history = SubmitHistory::new();
+        for guess in guesses {
+            let guess = PuzzleSolution::try_from(*guess).unwrap();
+            submit_history
+                .submit(SubmitWord::tint(&guess, &solution))
+                .unwrap();
+        }
+        submit_history
+    };
+    let lost = ["CRANE"; 6];
+    let games = [
+        (
+            "won",
+            played(&["CRANE", "RUSTY"]),
+            Some(GameState::Won { tries: 2 }),
+            true,
+            false,
+        ),
+        ("lost", played(&lost), Some(GameState::Lost), false, false),
+        ("legacy", played(&["RUSTY"]), None, true, false),
+        ("legacy-hinted", played(&["RUSTY"]), None, true, false),
+        (
+            "hinted",
+            played(&["CRANE", "RUSTY"]),
+            Some(GameState::Won { tries: 3 }),
+            true,
+            false,
+        ),
+        (
+            "playing",
+            played(&["CRANE"]),
+            Some(GameState::InProgress),
+            false,
+            false,
+        ),
+        (
+            "flagged",
+            played(&["RUSTY"]),
+            Some(GameState::Won { tries: 1 }),
+            true,
+            true,
+        ),
+    ];
+    for (session, submit_history, game_state, is_completed, is_flagged) in games {
+        sessions::ActiveModel {
+            session: ActiveValue::Set(session.to_owned()),
+            created_at: ActiveValue::Set(NaiveDateTime::default()),
+            updated_at: ActiveValue::Set(NaiveDateTime::default()),
+        }
+        .insert(&db)
+        .await
+        .unwrap();
+
+        histories::ActiveModel {
+            date: ActiveValue::Set(date.clone()),
+            session: ActiveValue::Set(session.to_owned()),
+            submit_history: ActiveValue::Set(Some(submit_history)),
+            solution: ActiveValue::Set(solution.clone()),
+            is_completed: ActiveValue::Set(is_completed),
+            game_state: ActiveValue::Set(game_state),
+            hints: ActiveValue::Set(session.ends_with("hinted").then(|| {
+                Hints(vec![Hint {
+                    index: 0,
+                    letter: 'R',
+                }])
+            })),
+            flags: ActiveValue::Set(is_flagged.then(|| CheatFlags(vec![CheatFlag::FastSolve]))),
+            uploaded_at: ActiveValue::Set(NaiveDateTime::default()),
+            ..Default::default()
+        }
+        .insert(&db)
+        .await
+        .unwrap();
+    }
+
+    // reruns the backfill over the histories
+    Migrator::down(&db, Some(2)).await.unwrap();
+    Migrator::up(&db, None).await.unwrap();
+
+    let results = ResultCounts::find()
+        .order_by_asc(result_counts::Column::IsWon)
+        .order_by_asc(result_counts::Column::Tries)
+        .all(&db)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|count| (count.is_won, count.tries, count.count))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        results,
+        [(false, 6, 1), (true, 1, 1), (true, 2, 2), (true, 3, 1)]
+    );
+
+    let first_guesses = FirstGuessCounts::find()
+        .order_by_asc(first_guess_counts::Column::Word)
+        .all(&db)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|count| (count.word, count.count))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        first_guesses,
+        [("CRANE".to_owned(), 3), ("RUSTY".to_owned(), 2)]
+    );
+}
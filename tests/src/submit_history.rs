@@ -61,6 +61,7 @@ async fn seed_data(db: &DatabaseConnection) {
     let active_puzzle = puzzles::ActiveModel {
         date: ActiveValue::Set(date.clone()),
         solution: ActiveValue::Set(solution.clone()),
+        ..Default::default()
     };
 
     let active_session = sessions::ActiveModel {